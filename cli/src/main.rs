@@ -38,6 +38,7 @@ use debug::init_boa_debug_object;
 use rustyline::{EditMode, Editor, config::Config, error::ReadlineError};
 use std::time::{Duration, Instant};
 use std::{
+    cell::RefCell,
     fs::OpenOptions,
     io::{self, IsTerminal, Read, Write},
     path::{Path, PathBuf},
@@ -177,6 +178,11 @@ struct Opt {
     /// Suppress the welcome banner when starting the REPL.
     #[arg(long, short = 'q')]
     quiet: bool,
+
+    /// Reset the `console` groups, counters and timers when an evaluation throws an
+    /// uncaught error.
+    #[arg(long)]
+    reset_console_on_error: bool,
 }
 
 impl Opt {
@@ -429,7 +435,12 @@ fn evaluate_expr(
                 };
                 match result {
                     Ok(v) => printer.print(format!("{}\n", v.display())),
-                    Err(ref v) => printer.print(uncaught_error(v)),
+                    Err(ref v) => {
+                        if args.reset_console_on_error {
+                            boa_runtime::Console::reset_state(context);
+                        }
+                        printer.print(uncaught_error(v));
+                    }
                 }
             }
             Err(ref v) => printer.print(uncaught_error(v)),
@@ -519,7 +530,12 @@ fn evaluate_file(
                 println!("{}", v.display());
             }
         }
-        Err(v) => printer.print(uncaught_error(&v)),
+        Err(v) => {
+            if args.reset_console_on_error {
+                boa_runtime::Console::reset_state(context);
+            }
+            printer.print(uncaught_error(&v));
+        }
     }
 
     Ok(())
@@ -639,6 +655,7 @@ fn main() -> Result<()> {
     // TODO: Replace the `__BOA_LOAD_FILE__` string sentinel with a `CliCommand` enum
     // (e.g. `Exec(String)` / `LoadFile(PathBuf)`) for type-safe cross-thread communication.
     let exec = executor.clone();
+    let reset_console = args.reset_console_on_error;
     let eval_loop = NativeAsyncJob::new(async move |context| {
         while let Ok(line) = receiver.recv().await {
             let printer_clone = printer.clone();
@@ -665,30 +682,7 @@ fn main() -> Result<()> {
             // schedule a new evaluation job that can run asynchronously
             // with the other evaluations.
             let eval_script = NativeAsyncJob::new(async move |context| {
-                let script =
-                    match Script::parse(Source::from_bytes(&line), None, &mut context.borrow_mut())
-                    {
-                        Ok(script) => script,
-                        Err(err) => {
-                            printer_clone.print(uncaught_error(&err));
-                            return Ok(JsValue::undefined());
-                        }
-                    };
-
-                // TODO: would be better to avoid blocking until the
-                // script finishes executing, but need to think about how
-                // to change the API of `evaluate_async` to enable that.
-                // (or I guess we could also implement web workers)
-                let value = match script.evaluate(&mut context.borrow_mut()) {
-                    Ok(value) => value,
-                    Err(err) => {
-                        printer_clone.print(uncaught_job_error(&err));
-                        return Ok(JsValue::undefined());
-                    }
-                };
-
-                printer_clone.print(format!("{}\n", value.display()));
-
+                evaluate_repl_line(&line, context, reset_console, &printer_clone);
                 Ok(JsValue::undefined())
             });
             context.borrow_mut().enqueue_job(eval_script.into());
@@ -707,6 +701,39 @@ fn main() -> Result<()> {
     Ok(result?)
 }
 
+/// Evaluates a line entered in the REPL, printing its value or the uncaught error.
+fn evaluate_repl_line(
+    line: &str,
+    context: &RefCell<&mut Context>,
+    reset_console: bool,
+    printer: &SharedExternalPrinterLogger,
+) {
+    // Bind the results first, so the context is no longer mutably borrowed when handling them.
+    let script = Script::parse(Source::from_bytes(line), None, &mut context.borrow_mut());
+    let script = match script {
+        Ok(script) => script,
+        Err(err) => {
+            printer.print(uncaught_error(&err));
+            return;
+        }
+    };
+
+    // TODO: would be better to avoid blocking until the
+    // script finishes executing, but need to think about how
+    // to change the API of `evaluate_async` to enable that.
+    // (or I guess we could also implement web workers)
+    let result = script.evaluate(&mut context.borrow_mut());
+    match result {
+        Ok(value) => printer.print(format!("{}\n", value.display())),
+        Err(err) => {
+            if reset_console {
+                boa_runtime::Console::reset_state(&context.borrow());
+            }
+            printer.print(uncaught_job_error(&err));
+        }
+    }
+}
+
 fn readline_thread_main(
     sender: &Sender<String>,
     printer_out: &SharedExternalPrinterLogger,
@@ -831,3 +858,53 @@ fn add_runtime(printer: SharedExternalPrinterLogger, context: &mut Context) {
     )
     .expect("should not fail while registering the runtime");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{SharedExternalPrinterLogger, evaluate_repl_line};
+    use boa_engine::Context;
+    use rustyline::ExternalPrinter;
+    use std::{
+        cell::RefCell,
+        sync::{Arc, Mutex},
+    };
+
+    /// Collects the printed messages.
+    struct CapturePrinter(Arc<Mutex<Vec<String>>>);
+
+    impl ExternalPrinter for CapturePrinter {
+        fn print(&mut self, msg: String) -> rustyline::Result<()> {
+            self.0.lock().unwrap().push(msg);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn repl_resets_console_after_uncaught_error() {
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let printer = SharedExternalPrinterLogger::new();
+        printer.set(CapturePrinter(output.clone()));
+
+        let context = &mut Context::default();
+        boa_runtime::register(
+            boa_runtime::extensions::ConsoleExtension(printer.clone()),
+            None,
+            context,
+        )
+        .unwrap();
+        let context = RefCell::new(context);
+
+        evaluate_repl_line(
+            "console.count('x'); throw new Error('boom');",
+            &context,
+            true,
+            &printer,
+        );
+        evaluate_repl_line("console.count('x');", &context, true, &printer);
+
+        let output = output.lock().unwrap();
+        assert_eq!(output[0], "count x: 1\n");
+        assert!(output[1].contains("boom"));
+        assert_eq!(output[2], "count x: 1\n");
+    }
+}
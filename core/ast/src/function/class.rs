@@ -28,6 +28,7 @@ pub struct ClassDeclaration {
     pub(crate) super_ref: Option<Expression>,
     pub(crate) constructor: Option<FunctionExpression>,
     pub(crate) elements: Box<[ClassElement]>,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) name_scope: Scope,
@@ -48,10 +49,19 @@ impl ClassDeclaration {
             super_ref,
             constructor,
             elements,
+            decorators: Box::default(),
             name_scope: Scope::default(),
         }
     }
 
+    /// Sets the decorators applied to the class declaration.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
     /// Returns the name of the class declaration.
     #[inline]
     #[must_use]
@@ -80,6 +90,13 @@ impl ClassDeclaration {
         &self.elements
     }
 
+    /// Gets the decorators applied to the class declaration.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Gets the scope containing the class name binding.
     #[inline]
     #[must_use]
@@ -90,7 +107,8 @@ impl ClassDeclaration {
 
 impl ToIndentedString for ClassDeclaration {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
-        let mut buf = format!("class {}", interner.resolve_expect(self.name.sym()));
+        let mut buf = decorators_to_string(&self.decorators, interner);
+        let _ = write!(buf, "class {}", interner.resolve_expect(self.name.sym()));
         if let Some(super_ref) = self.super_ref.as_ref() {
             let _ = write!(buf, " extends {}", super_ref.to_interned_string(interner));
         }
@@ -121,6 +139,9 @@ impl VisitWith for ClassDeclaration {
     where
         V: Visitor<'a>,
     {
+        for decorator in &*self.decorators {
+            visitor.visit_decorator(decorator)?;
+        }
         visitor.visit_identifier(&self.name)?;
         if let Some(expr) = &self.super_ref {
             visitor.visit_expression(expr)?;
@@ -138,6 +159,9 @@ impl VisitWith for ClassDeclaration {
     where
         V: VisitorMut<'a>,
     {
+        for decorator in &mut *self.decorators {
+            visitor.visit_decorator_mut(decorator)?;
+        }
         visitor.visit_identifier_mut(&mut self.name)?;
         if let Some(expr) = &mut self.super_ref {
             visitor.visit_expression_mut(expr)?;
@@ -174,6 +198,7 @@ pub struct ClassExpression {
     pub(crate) super_ref: Option<Expression>,
     pub(crate) constructor: Option<FunctionExpression>,
     pub(crate) elements: Box<[ClassElement]>,
+    pub(crate) decorators: Box<[Decorator]>,
 
     span: Span,
 
@@ -203,11 +228,20 @@ impl ClassExpression {
             super_ref,
             constructor,
             elements,
+            decorators: Box::default(),
             span,
             name_scope,
         }
     }
 
+    /// Sets the decorators applied to the class expression.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
     /// Returns the name of the class expression.
     #[inline]
     #[must_use]
@@ -236,6 +270,13 @@ impl ClassExpression {
         &self.elements
     }
 
+    /// Gets the decorators applied to the class expression.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Gets the scope containing the class name binding if it exists.
     #[inline]
     #[must_use]
@@ -253,7 +294,8 @@ impl Spanned for ClassExpression {
 
 impl ToIndentedString for ClassExpression {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
-        let mut buf = "class".to_string();
+        let mut buf = decorators_to_string(&self.decorators, interner);
+        buf.push_str("class");
         if self.name_scope.is_some()
             && let Some(name) = self.name
         {
@@ -295,6 +337,9 @@ impl VisitWith for ClassExpression {
     where
         V: Visitor<'a>,
     {
+        for decorator in &*self.decorators {
+            visitor.visit_decorator(decorator)?;
+        }
         if let Some(ident) = &self.name {
             visitor.visit_identifier(ident)?;
         }
@@ -314,6 +359,9 @@ impl VisitWith for ClassExpression {
     where
         V: VisitorMut<'a>,
     {
+        for decorator in &mut *self.decorators {
+            visitor.visit_decorator_mut(decorator)?;
+        }
         if let Some(ident) = &mut self.name {
             visitor.visit_identifier_mut(ident)?;
         }
@@ -413,6 +461,7 @@ pub enum ClassElement {
 pub struct ClassFieldDefinition {
     pub(crate) name: PropertyName,
     pub(crate) initializer: Option<Expression>,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
@@ -426,10 +475,19 @@ impl ClassFieldDefinition {
         Self {
             name,
             initializer,
+            decorators: Box::default(),
             scope: Scope::default(),
         }
     }
 
+    /// Sets the decorators applied to the class field definition.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
     /// Returns the name of the class field definition.
     #[inline]
     #[must_use]
@@ -444,6 +502,13 @@ impl ClassFieldDefinition {
         self.initializer.as_ref()
     }
 
+    /// Returns the decorators applied to the class field definition.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Returns the scope of the class field definition.
     #[inline]
     #[must_use]
@@ -464,6 +529,7 @@ impl ClassFieldDefinition {
 pub struct PrivateFieldDefinition {
     pub(crate) name: PrivateName,
     pub(crate) initializer: Option<Expression>,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
@@ -477,10 +543,19 @@ impl PrivateFieldDefinition {
         Self {
             name,
             initializer,
+            decorators: Box::default(),
             scope: Scope::default(),
         }
     }
 
+    /// Sets the decorators applied to the private field definition.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
     /// Returns the name of the private field definition.
     #[inline]
     #[must_use]
@@ -495,6 +570,13 @@ impl PrivateFieldDefinition {
         self.initializer.as_ref()
     }
 
+    /// Returns the decorators applied to the private field definition.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Returns the scope of the private field definition.
     #[inline]
     #[must_use]
@@ -505,7 +587,18 @@ impl PrivateFieldDefinition {
 
 impl ToIndentedString for ClassElement {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
-        let indentation = "    ".repeat(indent_n + 1);
+        let decorators: &[Decorator] = match self {
+            Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => &field.decorators,
+            Self::PrivateFieldDefinition(field) | Self::PrivateStaticFieldDefinition(field) => {
+                &field.decorators
+            }
+            Self::MethodDefinition(_) | Self::StaticBlock(_) => &[],
+        };
+        let indentation = format!(
+            "{}{}",
+            "    ".repeat(indent_n + 1),
+            decorators_to_string(decorators, interner)
+        );
         match self {
             Self::MethodDefinition(m) => m.to_indented_string(interner, indent_n),
             Self::FieldDefinition(field) => match &field.initializer {
@@ -591,6 +684,9 @@ impl VisitWith for ClassElement {
     {
         match self {
             Self::MethodDefinition(m) => {
+                for decorator in &*m.decorators {
+                    visitor.visit_decorator(decorator)?;
+                }
                 match &m.name {
                     ClassElementName::PropertyName(pn) => {
                         visitor.visit_property_name(pn)?;
@@ -603,6 +699,9 @@ impl VisitWith for ClassElement {
                 visitor.visit_function_body(&m.body)
             }
             Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => {
+                for decorator in &*field.decorators {
+                    visitor.visit_decorator(decorator)?;
+                }
                 visitor.visit_property_name(&field.name)?;
                 if let Some(expr) = &field.initializer {
                     visitor.visit_expression(expr)
@@ -611,13 +710,20 @@ impl VisitWith for ClassElement {
                 }
             }
             Self::PrivateFieldDefinition(PrivateFieldDefinition {
-                name, initializer, ..
+                name,
+                initializer,
+                decorators,
+                ..
             })
             | Self::PrivateStaticFieldDefinition(PrivateFieldDefinition {
                 name,
                 initializer,
+                decorators,
                 ..
             }) => {
+                for decorator in &**decorators {
+                    visitor.visit_decorator(decorator)?;
+                }
                 visitor.visit_private_name(name)?;
                 if let Some(expr) = initializer {
                     visitor.visit_expression(expr)
@@ -635,6 +741,9 @@ impl VisitWith for ClassElement {
     {
         match self {
             Self::MethodDefinition(m) => {
+                for decorator in &mut *m.decorators {
+                    visitor.visit_decorator_mut(decorator)?;
+                }
                 match m.name {
                     ClassElementName::PropertyName(ref mut pn) => {
                         visitor.visit_property_name_mut(pn)?;
@@ -647,6 +756,9 @@ impl VisitWith for ClassElement {
                 visitor.visit_function_body_mut(&mut m.body)
             }
             Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    visitor.visit_decorator_mut(decorator)?;
+                }
                 visitor.visit_property_name_mut(&mut field.name)?;
                 if let Some(expr) = &mut field.initializer {
                     visitor.visit_expression_mut(expr)
@@ -655,13 +767,20 @@ impl VisitWith for ClassElement {
                 }
             }
             Self::PrivateFieldDefinition(PrivateFieldDefinition {
-                name, initializer, ..
+                name,
+                initializer,
+                decorators,
+                ..
             })
             | Self::PrivateStaticFieldDefinition(PrivateFieldDefinition {
                 name,
                 initializer,
+                decorators,
                 ..
             }) => {
+                for decorator in &mut **decorators {
+                    visitor.visit_decorator_mut(decorator)?;
+                }
                 visitor.visit_private_name_mut(name)?;
                 if let Some(expr) = initializer {
                     visitor.visit_expression_mut(expr)
@@ -693,6 +812,7 @@ pub struct ClassMethodDefinition {
     pub(crate) contains_direct_eval: bool,
     kind: MethodDefinitionKind,
     is_static: bool,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
@@ -723,11 +843,20 @@ impl ClassMethodDefinition {
             contains_direct_eval,
             kind,
             is_static,
+            decorators: Box::default(),
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
         }
     }
 
+    /// Sets the decorators applied to the class method definition.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
     /// Returns the name of the class method definition.
     #[inline]
     #[must_use]
@@ -770,6 +899,13 @@ impl ClassMethodDefinition {
         self.name.is_private()
     }
 
+    /// Returns the decorators applied to the class method definition.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Gets the scopes of the class method definition.
     #[inline]
     #[must_use]
@@ -795,6 +931,7 @@ impl ClassMethodDefinition {
 impl ToIndentedString for ClassMethodDefinition {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
         let indentation = "    ".repeat(indent_n + 1);
+        let decorators = decorators_to_string(&self.decorators, interner);
         let prefix = match (self.is_static, &self.kind) {
             (true, MethodDefinitionKind::Get) => "static get ",
             (true, MethodDefinitionKind::Set) => "static set ",
@@ -812,7 +949,7 @@ impl ToIndentedString for ClassMethodDefinition {
         let name = self.name.to_interned_string(interner);
         let parameters = join_nodes(interner, self.parameters.as_ref());
         let body = block_to_string(&self.body.statements, interner, indent_n + 1);
-        format!("{indentation}{prefix}{name}({parameters}) {body}\n")
+        format!("{indentation}{decorators}{prefix}{name}({parameters}) {body}\n")
     }
 }
 
@@ -901,3 +1038,67 @@ impl VisitWith for PrivateName {
         visitor.visit_sym_mut(&mut self.description)
     }
 }
+
+/// A decorator applied to a class or a class element.
+///
+/// Decorators are only parsed for now; the engine rejects code using them with a `SyntaxError`.
+///
+/// More information:
+///  - [TC39 proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-decorators/#prod-Decorator
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Decorator {
+    expression: Expression,
+}
+
+impl Decorator {
+    /// Creates a new decorator.
+    #[inline]
+    #[must_use]
+    pub const fn new(expression: Expression) -> Self {
+        Self { expression }
+    }
+
+    /// Returns the expression of the decorator.
+    #[inline]
+    #[must_use]
+    pub const fn expression(&self) -> &Expression {
+        &self.expression
+    }
+}
+
+impl ToInternedString for Decorator {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        match &self.expression {
+            Expression::Identifier(ident) => format!("@{}", interner.resolve_expect(ident.sym())),
+            expr => format!("@({})", expr.to_interned_string(interner)),
+        }
+    }
+}
+
+impl VisitWith for Decorator {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_expression(&self.expression)
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        visitor.visit_expression_mut(&mut self.expression)
+    }
+}
+
+/// Formats a list of decorators, each followed by a space.
+fn decorators_to_string(decorators: &[Decorator], interner: &Interner) -> String {
+    decorators.iter().fold(String::new(), |mut buf, decorator| {
+        let _ = write!(buf, "{} ", decorator.to_interned_string(interner));
+        buf
+    })
+}
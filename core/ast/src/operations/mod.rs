@@ -876,6 +876,7 @@ impl<'ast> Visitor<'ast> for VarDeclaredNamesVisitor<'_> {
         match node {
             Statement::Empty
             | Statement::Debugger
+            | Statement::Error
             | Statement::Expression(_)
             | Statement::Continue(_)
             | Statement::Break(_)
@@ -1453,6 +1454,7 @@ where
                 Statement::Var(_)
                 | Statement::Empty
                 | Statement::Debugger
+                | Statement::Error
                 | Statement::Expression(_)
                 | Statement::Return(_)
                 | Statement::Throw(_) => ControlFlow::Continue(()),
@@ -2094,6 +2096,7 @@ impl<'ast> Visitor<'ast> for VarScopedDeclarationsVisitor<'_> {
             Statement::With(s) => self.visit(s),
             Statement::Empty
             | Statement::Debugger
+            | Statement::Error
             | Statement::Expression(_)
             | Statement::Continue(_)
             | Statement::Break(_)
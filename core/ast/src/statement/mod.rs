@@ -117,6 +117,12 @@ pub enum Statement {
     /// [spec]: https://tc39.es/ecma262/#sec-debugger-statement
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/debugger
     Debugger,
+
+    /// A statement that could not be parsed.
+    ///
+    /// This is only produced when parsing with error recovery, in place of the invalid
+    /// statement, and cannot be executed.
+    Error,
 }
 
 impl Statement {
@@ -147,6 +153,7 @@ impl Statement {
             Self::Try(try_catch) => return try_catch.to_indented_string(interner, indentation),
             Self::With(with) => return with.to_interned_string(interner),
             Self::Debugger => "debugger".to_owned(),
+            Self::Error => return "/* error */".to_owned(),
         };
         s.push(';');
         s
@@ -208,7 +215,7 @@ impl VisitWith for Statement {
         match self {
             Self::Block(b) => visitor.visit_block(b),
             Self::Var(v) => visitor.visit_var_declaration(v),
-            Self::Empty | Self::Debugger => {
+            Self::Empty | Self::Debugger | Self::Error => {
                 // do nothing; there is nothing to visit here
                 ControlFlow::Continue(())
             }
@@ -237,7 +244,7 @@ impl VisitWith for Statement {
         match self {
             Self::Block(b) => visitor.visit_block_mut(b),
             Self::Var(v) => visitor.visit_var_declaration_mut(v),
-            Self::Empty | Self::Debugger => {
+            Self::Empty | Self::Debugger | Self::Error => {
                 // do nothing; there is nothing to visit here
                 ControlFlow::Continue(())
             }
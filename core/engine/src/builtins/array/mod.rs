@@ -3178,7 +3178,7 @@ impl Array {
             args.get_or_undefined(0).to_integer_or_infinity(context)?
         else {
             return Err(JsNativeError::range()
-                .with_message("invalid integer index for TypedArray operation")
+                .with_message("Array.prototype.with: index out of range")
                 .into());
         };
 
@@ -3192,8 +3192,7 @@ impl Array {
             .filter(|&rel| rel < len)
             .ok_or_else(|| {
                 // 6. If actualIndex ≥ len or actualIndex < 0, throw a RangeError exception.
-                JsNativeError::range()
-                    .with_message("invalid integer index for TypedArray operation")
+                JsNativeError::range().with_message("Array.prototype.with: index out of range")
             })?;
 
         // 7. Let A be ? ArrayCreate(len).
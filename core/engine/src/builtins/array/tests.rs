@@ -130,6 +130,40 @@ fn find_index() {
     ]);
 }
 
+#[test]
+fn find_last() {
+    run_test_actions([
+        TestAction::assert_eq("[1, 2, 3, 4].findLast(x => x % 2 === 0)", 4),
+        TestAction::assert_eq("[1, 3, 5].findLast(x => x % 2 === 0)", JsValue::undefined()),
+        TestAction::assert_eq("[].findLast(x => true)", JsValue::undefined()),
+        TestAction::assert_eq(
+            "Array.prototype.findLast.call({ length: 3, 0: 'a', 1: 'b', 2: 'c' }, x => x < 'c')",
+            js_str!("b"),
+        ),
+        TestAction::assert_eq(
+            "[1, 2, 3].findLast(function (x) { return x === this.target; }, { target: 2 })",
+            2,
+        ),
+    ]);
+}
+
+#[test]
+fn find_last_index() {
+    run_test_actions([
+        TestAction::assert_eq("[1, 2, 3, 4].findLastIndex(x => x % 2 === 0)", 3),
+        TestAction::assert_eq("[1, 3, 5].findLastIndex(x => x % 2 === 0)", -1),
+        TestAction::assert_eq("[].findLastIndex(x => true)", -1),
+        TestAction::assert_eq(
+            "Array.prototype.findLastIndex.call({ length: 3, 0: 'a', 1: 'b', 2: 'c' }, x => x < 'c')",
+            1,
+        ),
+        TestAction::assert_eq(
+            "var visited = []; [1, 2, 3].findLastIndex((x, i) => { visited.push(i); }); visited.join()",
+            js_str!("2,1,0"),
+        ),
+    ]);
+}
+
 #[test]
 fn flat() {
     run_test_actions([
@@ -148,6 +182,10 @@ fn flat() {
                 )
             "#}),
         TestAction::assert("arrayEquals( [[[[[['a']]]]]].flat(Infinity), ['a'] )"),
+        TestAction::assert("arrayEquals( [1, [2, [3]]].flat(Infinity), [1, 2, 3] )"),
+        TestAction::assert("arrayEquals( [[1, , 2], , [3]].flat(), [1, 2, 3] )"),
+        TestAction::assert("arrayEquals( [[1, [2]]].flat(-1), [[1, [2]]] )"),
+        TestAction::assert_eq("[{ length: 1, 0: 'a' }].flat()[0].length", 1),
     ]);
 }
 
@@ -161,6 +199,12 @@ fn flat_map() {
                     [2, 4, 6]
                 )
             "#}),
+        TestAction::assert("arrayEquals( [1, , 3].flatMap(x => [x, x]), [1, 1, 3, 3] )"),
+        TestAction::assert_eq("[1, , 3].flatMap(x => [x, x]).length", 4),
+        TestAction::assert(indoc! {r#"
+                var result = [1].flatMap(x => new Set([x]));
+                result.length === 1 && result[0] instanceof Set
+            "#}),
         TestAction::assert(indoc! {r#"
                 arrayEquals(
                     ["it's Sunny", "in Cali"].flatMap(x => x.split(" ")),
@@ -954,6 +998,65 @@ fn array_sort() {
     ]);
 }
 
+#[test]
+fn array_sort_is_stable() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                let records = [];
+                for (let i = 0; i < 50; i++) {
+                    records.push({ key: i % 3, order: i });
+                }
+                records.sort((a, b) => a.key - b.key);
+            "#}),
+        TestAction::assert(indoc! {r#"
+                records.every((r, i) =>
+                    i === 0 ||
+                    records[i - 1].key < r.key ||
+                    (records[i - 1].key === r.key && records[i - 1].order < r.order)
+                )
+            "#}),
+        TestAction::assert(indoc! {r#"
+                arrayEquals(
+                    ["b1", "a1", "b2", "a2", "c1"].sort((x, y) => x[1] - y[1]),
+                    ["b1", "a1", "c1", "b2", "a2"]
+                )
+            "#}),
+    ]);
+}
+
+#[test]
+fn array_sort_comparator_throws() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                let arr = [5, 3, 8, 1, 9, 2, , 7];
+                let calls = 0;
+                let error;
+                try {
+                    arr.sort((a, b) => {
+                        if (++calls === 3) {
+                            throw new Error("third");
+                        }
+                        return a - b;
+                    });
+                } catch (e) {
+                    error = e;
+                }
+            "#}),
+        TestAction::assert_eq("error.message", js_str!("third")),
+        TestAction::assert_eq("calls", 3),
+        TestAction::assert_eq("arr.length", 8),
+        TestAction::assert("!(6 in arr)"),
+        TestAction::assert(indoc! {r#"
+                arrayEquals(
+                    arr.filter(() => true).sort((a, b) => a - b),
+                    [1, 2, 3, 5, 7, 8, 9]
+                )
+            "#}),
+    ]);
+}
+
 #[test]
 fn array_of_neg_zero() {
     run_test_actions([
@@ -985,3 +1088,119 @@ fn array_prototype_find_edge_cases() {
         "#}),
     ]);
 }
+
+#[test]
+#[cfg(feature = "experimental")]
+fn array_from_async_async_generator() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            var result = null;
+            async function* gen() {
+                yield 1;
+                yield Promise.resolve(2);
+                yield 3;
+            }
+            Array.fromAsync(gen(), (x, i) => x * 10 + i).then(arr => { result = arr; });
+        "#}),
+        TestAction::inspect_context(|ctx| ctx.run_jobs().unwrap()),
+        TestAction::assert("Array.isArray(result)"),
+        TestAction::assert("arrayEquals(result, [10, 21, 32])"),
+    ]);
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+fn array_from_async_sync_iterable_of_promises() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            var result = null;
+            var mapped = null;
+            var items = [Promise.resolve("a"), "b", Promise.resolve("c")];
+            Array.fromAsync(items).then(arr => { result = arr; });
+            Array.fromAsync(items, function (x) { return this.prefix + x; }, { prefix: "-" })
+                .then(arr => { mapped = arr; });
+        "#}),
+        TestAction::inspect_context(|ctx| ctx.run_jobs().unwrap()),
+        TestAction::assert("arrayEquals(result, ['a', 'b', 'c'])"),
+        TestAction::assert("arrayEquals(mapped, ['-a', '-b', '-c'])"),
+    ]);
+}
+
+#[test]
+fn array_change_by_copy() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run("var arr = [3, 1, 2];"),
+        TestAction::assert("arrayEquals(arr.toReversed(), [2, 1, 3])"),
+        TestAction::assert("arrayEquals(arr.toSorted(), [1, 2, 3])"),
+        TestAction::assert("arrayEquals(arr.toSorted((a, b) => b - a), [3, 2, 1])"),
+        TestAction::assert("arrayEquals(arr.toSpliced(1, 1, 'a', 'b'), [3, 'a', 'b', 2])"),
+        TestAction::assert("arrayEquals(arr.with(0, 'x'), ['x', 1, 2])"),
+        TestAction::assert("arrayEquals(arr.with(-1, 'x'), [3, 1, 'x'])"),
+        // The receiver is never mutated.
+        TestAction::assert("arrayEquals(arr, [3, 1, 2])"),
+        TestAction::assert("arr.toReversed() !== arr"),
+        TestAction::assert("arr.toSorted() !== arr"),
+    ]);
+}
+
+#[test]
+fn array_change_by_copy_array_like() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run("var arrayLike = { length: 3, 0: 'c', 1: 'a', 2: 'b' };"),
+        TestAction::assert(
+            "arrayEquals(Array.prototype.toReversed.call(arrayLike), ['b', 'a', 'c'])",
+        ),
+        TestAction::assert(
+            "arrayEquals(Array.prototype.toSorted.call(arrayLike), ['a', 'b', 'c'])",
+        ),
+        TestAction::assert("arrayEquals(Array.prototype.toSpliced.call(arrayLike, 0, 2), ['b'])"),
+        TestAction::assert(
+            "arrayEquals(Array.prototype.with.call(arrayLike, 1, 'z'), ['c', 'z', 'b'])",
+        ),
+        TestAction::assert("arrayLike[0] === 'c' && arrayLike[1] === 'a' && arrayLike[2] === 'b'"),
+    ]);
+}
+
+#[test]
+fn array_with_out_of_bounds() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "[1, 2, 3].with(3, 0)",
+            JsNativeErrorKind::Range,
+            "Array.prototype.with: index out of range",
+        ),
+        TestAction::assert_native_error(
+            "[1, 2, 3].with(-4, 0)",
+            JsNativeErrorKind::Range,
+            "Array.prototype.with: index out of range",
+        ),
+        TestAction::assert_native_error(
+            "[1, 2, 3].with(Infinity, 0)",
+            JsNativeErrorKind::Range,
+            "Array.prototype.with: index out of range",
+        ),
+    ]);
+}
+
+#[test]
+fn at() {
+    run_test_actions([
+        TestAction::assert_eq("[1, 2, 3].at(0)", 1),
+        TestAction::assert_eq("[1, 2, 3].at(-1)", 3),
+        TestAction::assert_eq("[1, 2, 3].at(-3)", 1),
+        TestAction::assert_eq("[1, 2, 3].at(1.7)", 2),
+        TestAction::assert_eq("[1, 2, 3].at('-2')", 2),
+        TestAction::assert_eq("[1, 2, 3].at(NaN)", 1),
+        TestAction::assert_eq("[1, 2, 3].at(3)", JsValue::undefined()),
+        TestAction::assert_eq("[1, 2, 3].at(-4)", JsValue::undefined()),
+        TestAction::assert_eq("[].at(0)", JsValue::undefined()),
+        TestAction::assert_eq(
+            "Array.prototype.at.call({ length: 2, 0: 'a', 1: 'b' }, -1)",
+            js_str!("b"),
+        ),
+    ]);
+}
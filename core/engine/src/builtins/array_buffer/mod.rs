@@ -346,12 +346,11 @@ impl IntrinsicObject for ArrayBuffer {
             .name(js_string!("get maxByteLength"))
             .build();
 
-        #[cfg(feature = "experimental")]
         let get_detached = BuiltInBuilder::callable(realm, Self::get_detached)
             .name(js_string!("get detached"))
             .build();
 
-        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .static_accessor(
                 JsSymbol::species(),
                 Some(get_species),
@@ -377,30 +376,26 @@ impl IntrinsicObject for ArrayBuffer {
                 None,
                 flag_attributes,
             )
-            .method(Self::js_resize, js_string!("resize"), 1)
-            .method(Self::slice, js_string!("slice"), 2)
-            .property(
-                JsSymbol::to_string_tag(),
-                Self::NAME,
-                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-            );
-
-        #[cfg(feature = "experimental")]
-        let builder = builder
             .accessor(
                 js_string!("detached"),
                 Some(get_detached),
                 None,
                 flag_attributes,
             )
+            .method(Self::js_resize, js_string!("resize"), 1)
+            .method(Self::slice, js_string!("slice"), 2)
             .method(Self::transfer::<false>, js_string!("transfer"), 0)
             .method(
                 Self::transfer::<true>,
                 js_string!("transferToFixedLength"),
                 0,
-            );
-
-        builder.build();
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
@@ -571,8 +566,7 @@ impl ArrayBuffer {
 
     /// [`get ArrayBuffer.prototype.detached`][spec].
     ///
-    /// [spec]: https://tc39.es/proposal-arraybuffer-transfer/#sec-get-arraybuffer.prototype.detached
-    #[cfg(feature = "experimental")]
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.detached
     fn get_detached(
         this: &JsValue,
         _args: &[JsValue],
@@ -748,9 +742,8 @@ impl ArrayBuffer {
     /// [`ArrayBuffer.prototype.transfer ( [ newLength ] )`][transfer] and
     /// [`ArrayBuffer.prototype.transferToFixedLength ( [ newLength ] )`][transferFL]
     ///
-    /// [transfer]: https://tc39.es/proposal-arraybuffer-transfer/#sec-arraybuffer.prototype.transfer
-    /// [transferFL]: https://tc39.es/proposal-arraybuffer-transfer/#sec-arraybuffer.prototype.transfertofixedlength
-    #[cfg(feature = "experimental")]
+    /// [transfer]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.transfer
+    /// [transferFL]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.transfertofixedlength
     fn transfer<const TO_FIXED_LENGTH: bool>(
         this: &JsValue,
         args: &[JsValue],
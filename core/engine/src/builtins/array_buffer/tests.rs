@@ -1,5 +1,5 @@
 use crate::object::JsArrayBuffer;
-use crate::{TestAction, run_test_actions};
+use crate::{JsNativeErrorKind, TestAction, run_test_actions};
 
 #[test]
 fn create_byte_data_block() {
@@ -309,3 +309,63 @@ fn shared_array_buffer_slice_empty() {
         TestAction::assert("result.length === 0"),
     ]);
 }
+
+#[test]
+fn array_buffer_transfer() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(
+            r#"
+            var source = new ArrayBuffer(4);
+            new Uint8Array(source).set([1, 2, 3, 4]);
+            var moved = source.transfer(6);
+        "#,
+        ),
+        TestAction::assert("source.detached"),
+        TestAction::assert("source.byteLength === 0"),
+        TestAction::assert("!moved.detached"),
+        TestAction::assert("!moved.resizable"),
+        TestAction::assert("arrayEquals([...new Uint8Array(moved)], [1, 2, 3, 4, 0, 0])"),
+        TestAction::assert(
+            "arrayEquals([...new Uint8Array(moved.transferToFixedLength(2))], [1, 2])",
+        ),
+        TestAction::assert("moved.detached"),
+        TestAction::assert_native_error(
+            "source.slice(0)",
+            JsNativeErrorKind::Type,
+            "ArrayBuffer.slice called with detached buffer",
+        ),
+        TestAction::assert_native_error(
+            "source.transfer()",
+            JsNativeErrorKind::Type,
+            "cannot transfer a detached buffer",
+        ),
+    ]);
+}
+
+#[test]
+fn array_buffer_resizable() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(
+            r#"
+            var buffer = new ArrayBuffer(2, { maxByteLength: 8 });
+            new Uint8Array(buffer).set([7, 8]);
+        "#,
+        ),
+        TestAction::assert("buffer.resizable"),
+        TestAction::assert("buffer.maxByteLength === 8"),
+        TestAction::run("buffer.resize(5);"),
+        TestAction::assert("buffer.byteLength === 5"),
+        TestAction::assert("arrayEquals([...new Uint8Array(buffer)], [7, 8, 0, 0, 0])"),
+        TestAction::run("buffer.resize(1);"),
+        TestAction::assert("arrayEquals([...new Uint8Array(buffer)], [7])"),
+        TestAction::assert("buffer.transfer().resizable"),
+        TestAction::assert("!new ArrayBuffer(2).resizable"),
+        TestAction::assert_native_error(
+            "buffer.resize(1)",
+            JsNativeErrorKind::Type,
+            "ArrayBuffer.resize: cannot resize a detached buffer",
+        ),
+    ]);
+}
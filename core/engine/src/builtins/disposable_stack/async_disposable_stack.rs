@@ -0,0 +1,527 @@
+//! Boa's implementation of the `AsyncDisposableStack` builtin object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncDisposableStack
+
+use super::{DisposableResource, DisposeCapability, DisposeHint, adopt_closure, suppress};
+use crate::{
+    Context, JsArgs, JsError, JsResult, JsString, JsValue,
+    builtins::{
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+        promise::{Promise, ResolvingFunctions},
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    error::PanicError,
+    js_error, js_string,
+    native_function::NativeFunction,
+    object::{
+        FunctionObjectBuilder, JsObject, JsPromise,
+        internal_methods::get_prototype_from_constructor,
+    },
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+};
+use boa_gc::{Finalize, Gc, GcRefCell, Trace};
+use boa_macros::JsData;
+
+/// The `AsyncDisposableStack` builtin object.
+#[derive(Debug, Default, Trace, Finalize, JsData)]
+pub(crate) struct AsyncDisposableStack {
+    disposed: bool,
+    capability: DisposeCapability,
+}
+
+impl IntrinsicObject for AsyncDisposableStack {
+    fn init(realm: &Realm) {
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name(js_string!("get disposed"))
+            .build();
+
+        let dispose_async = BuiltInBuilder::callable(realm, Self::dispose_async)
+            .name(js_string!("disposeAsync"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .accessor(
+                js_string!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::r#use, js_string!("use"), 1)
+            .method(Self::adopt, js_string!("adopt"), 2)
+            .method(Self::defer, js_string!("defer"), 1)
+            .property(
+                js_string!("disposeAsync"),
+                dispose_async.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::r#move, js_string!("move"), 0)
+            .property(
+                JsSymbol::async_dispose(),
+                dispose_async,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for AsyncDisposableStack {
+    const NAME: JsString = StaticJsStrings::ASYNC_DISPOSABLE_STACK;
+}
+
+impl BuiltInConstructor for AsyncDisposableStack {
+    const CONSTRUCTOR_ARGUMENTS: usize = 0;
+    const PROTOTYPE_STORAGE_SLOTS: usize = 9;
+    const CONSTRUCTOR_STORAGE_SLOTS: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::async_disposable_stack;
+
+    /// [`AsyncDisposableStack ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(js_error!(
+                TypeError: "AsyncDisposableStack: cannot call constructor without `new`"
+            ));
+        }
+
+        // 2. Let asyncDisposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        // 3. Set asyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 4. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::async_disposable_stack,
+            context,
+        )?;
+        let stack = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self::default(),
+        );
+
+        // 5. Return asyncDisposableStack.
+        Ok(stack.upcast().into())
+    }
+}
+
+impl AsyncDisposableStack {
+    /// Returns `this` as an `AsyncDisposableStack`, throwing a `TypeError` naming `method`
+    /// otherwise.
+    fn this_stack(this: &JsValue, method: &str) -> JsResult<JsObject<Self>> {
+        this.as_object()
+            .and_then(|o| o.downcast::<Self>().ok())
+            .ok_or_else(|| {
+                js_error!(TypeError:
+                    "AsyncDisposableStack.prototype.{}: expected 'this' to be an AsyncDisposableStack object",
+                    method
+                )
+            })
+    }
+
+    /// Returns `this` as a pending `AsyncDisposableStack`, throwing a `ReferenceError` if it
+    /// was already disposed.
+    fn pending_stack(this: &JsValue, method: &str) -> JsResult<JsObject<Self>> {
+        let stack = Self::this_stack(this, method)?;
+        if stack.borrow().data().disposed {
+            return Err(js_error!(ReferenceError:
+                "AsyncDisposableStack.prototype.{}: the stack was already disposed",
+                method
+            ));
+        }
+        Ok(stack)
+    }
+
+    /// `get AsyncDisposableStack.prototype.disposed`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-asyncdisposablestack.prototype.disposed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncDisposableStack/disposed
+    fn get_disposed(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "disposed")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        Ok(stack.borrow().data().disposed.into())
+    }
+
+    /// `AsyncDisposableStack.prototype.disposeAsync ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.disposeAsync
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncDisposableStack/disposeAsync
+    fn dispose_async(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let (promise, resolvers) = JsPromise::new_pending(context);
+
+        // 3. If asyncDisposableStack does not have an [[AsyncDisposableState]] internal slot, then
+        let stack = match Self::this_stack(this, "disposeAsync") {
+            Ok(stack) => stack,
+            Err(err) => {
+                // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+                // b. Return promiseCapability.[[Promise]].
+                let err = err.into_opaque(context)?;
+                resolvers
+                    .reject
+                    .call(&JsValue::undefined(), &[err], context)?;
+                return Ok(promise.into());
+            }
+        };
+
+        let capability = {
+            let mut stack = stack.borrow_mut();
+            let stack = stack.data_mut();
+
+            // 4. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, then
+            // 5. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+            (!std::mem::replace(&mut stack.disposed, true))
+                .then(|| std::mem::take(&mut stack.capability))
+        };
+        let Some(capability) = capability else {
+            // a. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+            // b. Return promiseCapability.[[Promise]].
+            resolvers
+                .resolve
+                .call(&JsValue::undefined(), &[], context)?;
+            return Ok(promise.into());
+        };
+
+        // 6. Let result be Completion(DisposeResources(asyncDisposableStack.[[DisposeCapability]], NormalCompletion(undefined))).
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        // 8. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result »).
+        let disposal = Gc::new(GcRefCell::new(AsyncDisposal {
+            resources: capability.stack,
+            completion: Ok(()),
+            needs_await: false,
+            has_awaited: false,
+            resolvers,
+        }));
+        AsyncDisposal::resume(&disposal, context)?;
+
+        // 9. Return promiseCapability.[[Promise]].
+        Ok(promise.into())
+    }
+
+    /// `AsyncDisposableStack.prototype.use ( value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.use
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncDisposableStack/use
+    fn r#use(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        let stack = Self::pending_stack(this, "use")?;
+
+        // 4. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], value, async-dispose).
+        let value = args.get_or_undefined(0);
+        DisposeCapability::add_resource(
+            &stack,
+            |stack| &mut stack.capability,
+            value.clone(),
+            DisposeHint::Async,
+            None,
+            context,
+        )?;
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+
+    /// `AsyncDisposableStack.prototype.adopt ( value, onDisposeAsync )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.adopt
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncDisposableStack/adopt
+    fn adopt(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        let stack = Self::pending_stack(this, "adopt")?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let value = args.get_or_undefined(0);
+        let on_dispose = args.get_or_undefined(1).as_callable().ok_or_else(|| {
+            js_error!(TypeError:
+                "AsyncDisposableStack.prototype.adopt: onDisposeAsync is not callable")
+        })?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures value and onDisposeAsync and performs the following steps when called:
+        // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        let f = adopt_closure(value.clone(), on_dispose, context);
+
+        // 7. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, F).
+        DisposeCapability::add_resource(
+            &stack,
+            |stack| &mut stack.capability,
+            JsValue::undefined(),
+            DisposeHint::Async,
+            Some(f),
+            context,
+        )?;
+
+        // 8. Return value.
+        Ok(value.clone())
+    }
+
+    /// `AsyncDisposableStack.prototype.defer ( onDisposeAsync )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.defer
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncDisposableStack/defer
+    fn defer(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        let stack = Self::pending_stack(this, "defer")?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let on_dispose = args.get_or_undefined(0).as_callable().ok_or_else(|| {
+            js_error!(TypeError:
+                "AsyncDisposableStack.prototype.defer: onDisposeAsync is not callable")
+        })?;
+
+        // 5. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, onDisposeAsync).
+        DisposeCapability::add_resource(
+            &stack,
+            |stack| &mut stack.capability,
+            JsValue::undefined(),
+            DisposeHint::Async,
+            Some(on_dispose),
+            context,
+        )?;
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `AsyncDisposableStack.prototype.move ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.move
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncDisposableStack/move
+    fn r#move(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        let stack = Self::pending_stack(this, "move")?;
+
+        // 6. Set newAsyncDisposableStack.[[DisposeCapability]] to asyncDisposableStack.[[DisposeCapability]].
+        // 7. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 8. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+        let capability = {
+            let mut stack = stack.borrow_mut();
+            let stack = stack.data_mut();
+            stack.disposed = true;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 4. Let newAsyncDisposableStack be ? OrdinaryCreateFromConstructor(%AsyncDisposableStack%, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        // 5. Set newAsyncDisposableStack.[[AsyncDisposableState]] to pending.
+        let new_stack = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .async_disposable_stack()
+                .prototype(),
+            Self {
+                disposed: false,
+                capability,
+            },
+        );
+
+        // 9. Return newAsyncDisposableStack.
+        Ok(new_stack.upcast().into())
+    }
+}
+
+/// The state of a `DisposeResources` operation on an `AsyncDisposableStack`, which is
+/// resumed from promise reactions every time a dispose method result is awaited.
+#[derive(Trace, Finalize)]
+struct AsyncDisposal {
+    resources: Vec<DisposableResource>,
+    completion: JsResult<()>,
+    needs_await: bool,
+    has_awaited: bool,
+    resolvers: ResolvingFunctions,
+}
+
+impl AsyncDisposal {
+    /// Continues the [`DisposeResources ( disposeCapability, completion )`][spec] operation
+    /// until the next `Await`, or until all resources were disposed, settling the promise
+    /// returned by `disposeAsync`.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    fn resume(this: &Gc<GcRefCell<Self>>, context: &mut Context) -> JsResult<()> {
+        // 3. For each element resource of disposeCapability.[[DisposableResourceStack]], in reverse list order, do
+        loop {
+            let Some(resource) = this.borrow_mut().resources.pop() else {
+                break;
+            };
+            debug_assert_eq!(resource.hint, DisposeHint::Async);
+
+            // e. If method is not undefined, then
+            let Some(method) = resource.method else {
+                // f. Else,
+                //     i. Assert: hint is async-dispose.
+                //     ii. Set needsAwait to true.
+                //     iii. NOTE: This can only indicate a case where either null or undefined was
+                //          the initialized value of an await using declaration.
+                this.borrow_mut().needs_await = true;
+                continue;
+            };
+
+            // i. Let result be Completion(Call(method, value)).
+            // ii. If result is a normal completion and hint is async-dispose, then
+            //     1. Set result to Completion(Await(result.[[Value]])).
+            //     2. Set hasAwaited to true.
+            let result = method
+                .call(&resource.value, &[], context)
+                .and_then(|result| {
+                    this.borrow_mut().has_awaited = true;
+                    Self::await_value(this, result, context)
+                });
+
+            match result {
+                Ok(()) => return Ok(()),
+                // iii. If result is a throw completion, then
+                Err(err) => suppress(&mut this.borrow_mut().completion, err)?,
+            }
+        }
+
+        // 4. If needsAwait is true and hasAwaited is false, then
+        let needs_await = {
+            let mut state = this.borrow_mut();
+            let needs_await = state.needs_await && !state.has_awaited;
+            state.has_awaited |= needs_await;
+            needs_await
+        };
+        if needs_await {
+            // a. Perform ! Await(undefined).
+            return Self::await_value(this, JsValue::undefined(), context);
+        }
+
+        // 6. Set disposeCapability.[[DisposableResourceStack]] to a new empty List.
+        // 7. Return ? completion.
+        let (completion, resolvers) = {
+            let mut state = this.borrow_mut();
+            (
+                std::mem::replace(&mut state.completion, Ok(())),
+                state.resolvers.clone(),
+            )
+        };
+        match completion {
+            Ok(()) => resolvers
+                .resolve
+                .call(&JsValue::undefined(), &[], context)?,
+            Err(err) => {
+                let err = err.into_opaque(context)?;
+                resolvers
+                    .reject
+                    .call(&JsValue::undefined(), &[err], context)?
+            }
+        };
+        Ok(())
+    }
+
+    /// Awaits `value`, resuming the disposal once it settles.
+    ///
+    /// Returns an error if `value` could not be converted to a promise.
+    fn await_value(
+        this: &Gc<GcRefCell<Self>>,
+        value: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 2. Let promise be ? PromiseResolve(%Promise%, value).
+        let promise = Promise::promise_resolve(
+            &context.intrinsics().constructors().promise().constructor(),
+            value,
+            context,
+        )?
+        .downcast::<Promise>()
+        .map_err(|_| PanicError::new("%Promise% constructor must return a `Promise` object"))?;
+
+        let on_fulfilled = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, _, this, context| {
+                    Self::resume(this, context)?;
+                    Ok(JsValue::undefined())
+                },
+                this.clone(),
+            ),
+        )
+        .name(js_string!())
+        .length(1)
+        .build();
+
+        let on_rejected = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, this: &Gc<GcRefCell<Self>>, context| {
+                    let err = JsError::from_opaque(args.get_or_undefined(0).clone());
+                    suppress(&mut this.borrow_mut().completion, err)?;
+                    Self::resume(this, context)?;
+                    Ok(JsValue::undefined())
+                },
+                this.clone(),
+            ),
+        )
+        .name(js_string!())
+        .length(1)
+        .build();
+
+        Promise::perform_promise_then(
+            &promise,
+            Some(on_fulfilled),
+            Some(on_rejected),
+            None,
+            context,
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,614 @@
+//! Boa's implementation of the `DisposableStack` builtin object, along with the abstract
+//! operations shared with `AsyncDisposableStack`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack
+
+use crate::{
+    Context, JsArgs, JsError, JsNativeError, JsResult, JsString, JsValue,
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_error, js_string,
+    native_function::NativeFunction,
+    object::{
+        FunctionObjectBuilder, JsObject, JsPromise, NativeObject,
+        internal_methods::get_prototype_from_constructor,
+    },
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+};
+use boa_gc::{Finalize, Trace};
+use boa_macros::JsData;
+
+mod async_disposable_stack;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use async_disposable_stack::AsyncDisposableStack;
+
+/// The hint of a disposable resource, selecting which dispose method is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisposeHint {
+    /// The resource is disposed through its `@@dispose` method.
+    Sync,
+    /// The resource is disposed through its `@@asyncDispose` method, falling back to
+    /// `@@dispose`.
+    Async,
+}
+
+/// A `DisposableResource` Record.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposableresource-records
+#[derive(Debug, Clone, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+pub(crate) struct DisposableResource {
+    value: JsValue,
+    #[unsafe_ignore_trace]
+    hint: DisposeHint,
+    method: Option<JsObject>,
+}
+
+/// A `DisposeCapability` Record, holding the resources to dispose in the order they were added.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposecapability-records
+#[derive(Debug, Default, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+pub(crate) struct DisposeCapability {
+    stack: Vec<DisposableResource>,
+}
+
+impl DisposeCapability {
+    /// Abstract operation [`AddDisposableResource ( disposeCapability, V, hint [ , method ] )`][spec].
+    ///
+    /// The resource is created before borrowing `this`, since getting its dispose method can run
+    /// user code.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+    fn add_resource<T: NativeObject>(
+        this: &JsObject<T>,
+        capability: fn(&mut T) -> &mut Self,
+        value: JsValue,
+        hint: DisposeHint,
+        method: Option<JsObject>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. If method is not present, then
+        let resource = if method.is_none() {
+            // a. If V is either null or undefined and hint is sync-dispose, return unused.
+            if value.is_null_or_undefined() && hint == DisposeHint::Sync {
+                return Ok(());
+            }
+
+            // b. NOTE: When V is either null or undefined and hint is async-dispose, we record
+            //    that the resource was evaluated to ensure we will still perform an Await when
+            //    resources are later disposed.
+            // c. Let resource be ? CreateDisposableResource(V, hint).
+            DisposableResource::new(value, hint, None, context)?
+        }
+        // 2. Else,
+        else {
+            // a. Assert: V is undefined.
+            // b. Let resource be ? CreateDisposableResource(undefined, hint, method).
+            DisposableResource::new(JsValue::undefined(), hint, method, context)?
+        };
+
+        // 3. Append resource to disposeCapability.[[DisposableResourceStack]].
+        capability(this.borrow_mut().data_mut())
+            .stack
+            .push(resource);
+
+        // 4. Return unused.
+        Ok(())
+    }
+}
+
+impl DisposableResource {
+    /// Abstract operation [`CreateDisposableResource ( V, hint [ , method ] )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-createdisposableresource
+    fn new(
+        value: JsValue,
+        hint: DisposeHint,
+        method: Option<JsObject>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. If method is not present, then
+        let (value, method) = if let Some(method) = method {
+            // 2. Else,
+            //     a. If IsCallable(method) is false, throw a TypeError exception.
+            if !method.is_callable() {
+                return Err(js_error!(TypeError: "dispose method is not callable"));
+            }
+            (value, Some(method))
+        } else if value.is_null_or_undefined() {
+            // a. If V is either null or undefined, then
+            //     i. Set V to undefined.
+            //     ii. Set method to undefined.
+            (JsValue::undefined(), None)
+        } else {
+            // b. Else,
+            //     i. If V is not an Object, throw a TypeError exception.
+            if !value.is_object() {
+                return Err(js_error!(TypeError: "disposable resource must be an object"));
+            }
+
+            //     ii. Set method to ? GetDisposeMethod(V, hint).
+            //     iii. If method is undefined, throw a TypeError exception.
+            let Some(method) = get_dispose_method(&value, hint, context)? else {
+                return Err(js_error!(TypeError: "disposable resource has no dispose method"));
+            };
+            (value, Some(method))
+        };
+
+        // 3. Return the DisposableResource Record { [[ResourceValue]]: V, [[Hint]]: hint, [[DisposeMethod]]: method }.
+        Ok(Self {
+            value,
+            hint,
+            method,
+        })
+    }
+}
+
+/// Abstract operation [`GetDisposeMethod ( V, hint )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-getdisposemethod
+fn get_dispose_method(
+    value: &JsValue,
+    hint: DisposeHint,
+    context: &mut Context,
+) -> JsResult<Option<JsObject>> {
+    // 1. If hint is async-dispose, then
+    if hint == DisposeHint::Async {
+        // a. Let method be ? GetMethod(V, @@asyncDispose).
+        if let Some(method) = value.get_method(JsSymbol::async_dispose(), context)? {
+            return Ok(Some(method));
+        }
+
+        // b. If method is undefined, then
+        //     i. Set method to ? GetMethod(V, @@dispose).
+        //     ii. If method is not undefined, then
+        let Some(method) = value.get_method(JsSymbol::dispose(), context)? else {
+            return Ok(None);
+        };
+
+        // 1. Let closure be a new Abstract Closure with no parameters that captures method and
+        //    performs the following steps when called:
+        //     a. Let O be the this value.
+        //     b. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        //     c. Let result be Completion(Call(method, O)).
+        //     d. IfAbruptRejectPromise(result, promiseCapability).
+        //     e. Perform ? Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+        //     f. Return promiseCapability.[[Promise]].
+        // 2. NOTE: This function is not observable to user code. It is used to ensure that a
+        //    Promise returned from a synchronous @@dispose method will not be awaited.
+        // 3. Return CreateBuiltinFunction(closure, 0, "", « »).
+        let closure = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |this, _, method: &JsObject, context| {
+                    let promise = match method.call(this, &[], context) {
+                        Ok(_) => JsPromise::resolve(JsValue::undefined(), context)?,
+                        Err(err) if err.is_catchable() => JsPromise::reject(err, context)?,
+                        Err(err) => return Err(err),
+                    };
+                    Ok(promise.into())
+                },
+                method,
+            ),
+        )
+        .name(js_string!())
+        .length(0)
+        .build();
+
+        return Ok(Some(closure.into()));
+    }
+
+    // 2. Else,
+    //     a. Let method be ? GetMethod(V, @@dispose).
+    // 3. Return method.
+    value.get_method(JsSymbol::dispose(), context)
+}
+
+/// Creates the closure used by `adopt`, which calls `on_dispose` with `value`.
+fn adopt_closure(value: JsValue, on_dispose: JsObject, context: &mut Context) -> JsObject {
+    // a. Let closure be a new Abstract Closure with no parameters that captures value and
+    //    onDispose and performs the following steps when called:
+    //     i. Return ? Call(onDispose, undefined, « value »).
+    // b. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+    FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, _, (value, on_dispose): &(JsValue, JsObject), context| {
+                on_dispose.call(&JsValue::undefined(), std::slice::from_ref(value), context)
+            },
+            (value, on_dispose),
+        ),
+    )
+    .name(js_string!())
+    .length(0)
+    .build()
+    .into()
+}
+
+/// Updates the completion of a `DisposeResources` operation with the error thrown by a
+/// dispose method, wrapping it in a `SuppressedError` if an error was already thrown.
+///
+/// Uncatchable errors cannot be suppressed, so they are returned as is to stop the disposal.
+fn suppress(completion: &mut JsResult<()>, error: JsError) -> JsResult<()> {
+    if !error.is_catchable() {
+        return Err(error);
+    }
+
+    // iii. If result is a throw completion, then
+    *completion = match std::mem::replace(completion, Ok(())) {
+        // 1. If completion is a throw completion, then
+        //     a. Set result to result.[[Value]].
+        //     b. Let suppressed be completion.[[Value]].
+        //     c. Let error be a newly created SuppressedError object.
+        //     d. Perform CreateNonEnumerableDataPropertyOrThrow(error, "error", result).
+        //     e. Perform CreateNonEnumerableDataPropertyOrThrow(error, "suppressed", suppressed).
+        //     f. Set completion to ThrowCompletion(error).
+        Err(suppressed) => Err(JsNativeError::suppressed(error, suppressed).into()),
+        // 2. Else,
+        //     a. Set completion to result.
+        Ok(()) => Err(error),
+    };
+    Ok(())
+}
+
+/// Abstract operation [`DisposeResources ( disposeCapability, completion )`][spec], for
+/// capabilities holding only sync resources.
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+fn dispose_resources(
+    capability: DisposeCapability,
+    mut completion: JsResult<()>,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 3. For each element resource of disposeCapability.[[DisposableResourceStack]], in reverse list order, do
+    for resource in capability.stack.into_iter().rev() {
+        debug_assert_eq!(resource.hint, DisposeHint::Sync);
+
+        // e. If method is not undefined, then
+        //     i. Let result be Completion(Call(method, value)).
+        if let Some(method) = resource.method
+            && let Err(err) = method.call(&resource.value, &[], context)
+        {
+            suppress(&mut completion, err)?;
+        }
+    }
+
+    // 6. Set disposeCapability.[[DisposableResourceStack]] to a new empty List.
+    // 7. Return ? completion.
+    completion
+}
+
+/// The `DisposableStack` builtin object.
+#[derive(Debug, Default, Trace, Finalize, JsData)]
+pub(crate) struct DisposableStack {
+    disposed: bool,
+    capability: DisposeCapability,
+}
+
+impl IntrinsicObject for DisposableStack {
+    fn init(realm: &Realm) {
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name(js_string!("get disposed"))
+            .build();
+
+        let dispose = BuiltInBuilder::callable(realm, Self::dispose)
+            .name(js_string!("dispose"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .accessor(
+                js_string!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::r#use, js_string!("use"), 1)
+            .method(Self::adopt, js_string!("adopt"), 2)
+            .method(Self::defer, js_string!("defer"), 1)
+            .property(
+                js_string!("dispose"),
+                dispose.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::r#move, js_string!("move"), 0)
+            .property(
+                JsSymbol::dispose(),
+                dispose,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DisposableStack {
+    const NAME: JsString = StaticJsStrings::DISPOSABLE_STACK;
+}
+
+impl BuiltInConstructor for DisposableStack {
+    const CONSTRUCTOR_ARGUMENTS: usize = 0;
+    const PROTOTYPE_STORAGE_SLOTS: usize = 9;
+    const CONSTRUCTOR_STORAGE_SLOTS: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::disposable_stack;
+
+    /// [`DisposableStack ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(
+                js_error!(TypeError: "DisposableStack: cannot call constructor without `new`"),
+            );
+        }
+
+        // 2. Let disposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        // 3. Set disposableStack.[[DisposableState]] to pending.
+        // 4. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::disposable_stack,
+            context,
+        )?;
+        let stack = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self::default(),
+        );
+
+        // 5. Return disposableStack.
+        Ok(stack.upcast().into())
+    }
+}
+
+impl DisposableStack {
+    /// Returns `this` as a `DisposableStack`, throwing a `TypeError` naming `method` otherwise.
+    fn this_stack(this: &JsValue, method: &str) -> JsResult<JsObject<Self>> {
+        this.as_object()
+            .and_then(|o| o.downcast::<Self>().ok())
+            .ok_or_else(|| {
+                js_error!(TypeError:
+                    "DisposableStack.prototype.{}: expected 'this' to be a DisposableStack object",
+                    method
+                )
+            })
+    }
+
+    /// Returns `this` as a pending `DisposableStack`, throwing a `ReferenceError` if it was
+    /// already disposed.
+    fn pending_stack(this: &JsValue, method: &str) -> JsResult<JsObject<Self>> {
+        let stack = Self::this_stack(this, method)?;
+        if stack.borrow().data().disposed {
+            return Err(js_error!(ReferenceError:
+                "DisposableStack.prototype.{}: the stack was already disposed",
+                method
+            ));
+        }
+        Ok(stack)
+    }
+
+    /// `get DisposableStack.prototype.disposed`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-disposablestack.prototype.disposed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack/disposed
+    fn get_disposed(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "disposed")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        Ok(stack.borrow().data().disposed.into())
+    }
+
+    /// `DisposableStack.prototype.dispose ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.dispose
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack/dispose
+    fn dispose(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "dispose")?;
+
+        let capability = {
+            let mut stack = stack.borrow_mut();
+            let stack = stack.data_mut();
+
+            // 3. If disposableStack.[[DisposableState]] is disposed, return undefined.
+            if stack.disposed {
+                return Ok(JsValue::undefined());
+            }
+
+            // 4. Set disposableStack.[[DisposableState]] to disposed.
+            stack.disposed = true;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 5. Return ? DisposeResources(disposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        dispose_resources(capability, Ok(()), context)?;
+        Ok(JsValue::undefined())
+    }
+
+    /// `DisposableStack.prototype.use ( value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.use
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack/use
+    fn r#use(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        let stack = Self::pending_stack(this, "use")?;
+
+        // 4. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], value, sync-dispose).
+        let value = args.get_or_undefined(0);
+        DisposeCapability::add_resource(
+            &stack,
+            |stack| &mut stack.capability,
+            value.clone(),
+            DisposeHint::Sync,
+            None,
+            context,
+        )?;
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+
+    /// `DisposableStack.prototype.adopt ( value, onDispose )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.adopt
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack/adopt
+    fn adopt(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        let stack = Self::pending_stack(this, "adopt")?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let value = args.get_or_undefined(0);
+        let on_dispose = args.get_or_undefined(1).as_callable().ok_or_else(
+            || js_error!(TypeError: "DisposableStack.prototype.adopt: onDispose is not callable"),
+        )?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures value and onDispose and performs the following steps when called:
+        // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        let f = adopt_closure(value.clone(), on_dispose, context);
+
+        // 7. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, F).
+        DisposeCapability::add_resource(
+            &stack,
+            |stack| &mut stack.capability,
+            JsValue::undefined(),
+            DisposeHint::Sync,
+            Some(f),
+            context,
+        )?;
+
+        // 8. Return value.
+        Ok(value.clone())
+    }
+
+    /// `DisposableStack.prototype.defer ( onDispose )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.defer
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack/defer
+    fn defer(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        let stack = Self::pending_stack(this, "defer")?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let on_dispose = args.get_or_undefined(0).as_callable().ok_or_else(
+            || js_error!(TypeError: "DisposableStack.prototype.defer: onDispose is not callable"),
+        )?;
+
+        // 5. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, onDispose).
+        DisposeCapability::add_resource(
+            &stack,
+            |stack| &mut stack.capability,
+            JsValue::undefined(),
+            DisposeHint::Sync,
+            Some(on_dispose),
+            context,
+        )?;
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `DisposableStack.prototype.move ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.move
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack/move
+    fn r#move(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        let stack = Self::pending_stack(this, "move")?;
+
+        // 6. Set newDisposableStack.[[DisposeCapability]] to disposableStack.[[DisposeCapability]].
+        // 7. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 8. Set disposableStack.[[DisposableState]] to disposed.
+        let capability = {
+            let mut stack = stack.borrow_mut();
+            let stack = stack.data_mut();
+            stack.disposed = true;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 4. Let newDisposableStack be ? OrdinaryCreateFromConstructor(%DisposableStack%, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        // 5. Set newDisposableStack.[[DisposableState]] to pending.
+        let new_stack = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .disposable_stack()
+                .prototype(),
+            Self {
+                disposed: false,
+                capability,
+            },
+        );
+
+        // 9. Return newDisposableStack.
+        Ok(new_stack.upcast().into())
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{JsNativeErrorKind, JsValue, TestAction, run_test_actions};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn dispose_in_lifo_order() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            var stack = new DisposableStack();
+            stack.defer(() => log.push("defer"));
+            stack.use({ [Symbol.dispose]() { log.push("use"); } });
+            stack.use(null);
+            stack.adopt("adopt", value => log.push(value));
+            stack.dispose();
+        "#}),
+        TestAction::assert_eq("log.join()", js_str!("adopt,use,defer")),
+        TestAction::assert("stack.disposed"),
+        // Disposing twice is a no-op.
+        TestAction::assert_eq("stack.dispose()", JsValue::undefined()),
+        TestAction::assert_eq("log.length", 3),
+    ]);
+}
+
+#[test]
+fn dispose_errors_are_suppressed() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            var stack = new DisposableStack();
+            stack.defer(() => log.push(1));
+            stack.defer(() => { throw new Error("first"); });
+            stack.defer(() => log.push(2));
+            stack.defer(() => { throw new Error("second"); });
+            var error;
+            try {
+                stack[Symbol.dispose]();
+            } catch (e) {
+                error = e;
+            }
+        "#}),
+        TestAction::assert_eq("log.join()", js_str!("2,1")),
+        TestAction::assert("error instanceof SuppressedError"),
+        TestAction::assert_eq("error.error.message", js_str!("first")),
+        TestAction::assert_eq("error.suppressed.message", js_str!("second")),
+    ]);
+}
+
+#[test]
+fn move_transfers_resources() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            var stack = new DisposableStack();
+            stack.defer(() => log.push("moved"));
+            var moved = stack.move();
+            stack.dispose();
+        "#}),
+        TestAction::assert("stack.disposed && !moved.disposed"),
+        TestAction::assert_eq("log.length", 0),
+        TestAction::run("moved.dispose()"),
+        TestAction::assert_eq("log.join()", js_str!("moved")),
+    ]);
+}
+
+#[test]
+fn use_after_dispose_throws() {
+    run_test_actions([
+        TestAction::run("var stack = new DisposableStack(); stack.dispose();"),
+        TestAction::assert_native_error(
+            "stack.defer(() => {})",
+            JsNativeErrorKind::Reference,
+            "DisposableStack.prototype.defer: the stack was already disposed",
+        ),
+        TestAction::assert_native_error(
+            "new DisposableStack().use({})",
+            JsNativeErrorKind::Type,
+            "disposable resource has no dispose method",
+        ),
+    ]);
+}
+
+#[test]
+fn async_dispose_in_lifo_order() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            var error;
+            var stack = new AsyncDisposableStack();
+            stack.defer(async () => { await null; log.push(1); });
+            stack.use({ [Symbol.dispose]() { log.push(2); } });
+            stack.defer(() => { throw new Error("boom"); });
+            stack.use({ async [Symbol.asyncDispose]() { log.push(3); } });
+            stack.disposeAsync().catch(e => { error = e; });
+        "#}),
+        TestAction::inspect_context(|ctx| ctx.run_jobs().unwrap()),
+        TestAction::assert_eq("log.join()", js_str!("3,2,1")),
+        TestAction::assert_eq("error.message", js_str!("boom")),
+        TestAction::assert("stack.disposed"),
+    ]);
+}
+
+#[test]
+fn suppressed_error_constructor() {
+    run_test_actions([
+        TestAction::run(r#"var error = SuppressedError(1, 2, "message");"#),
+        TestAction::assert("error instanceof Error"),
+        TestAction::assert_eq("error.error", 1),
+        TestAction::assert_eq("error.suppressed", 2),
+        TestAction::assert_eq("error.message", js_str!("message")),
+        TestAction::assert_eq("error.name", js_str!("SuppressedError")),
+        TestAction::assert_eq("SuppressedError.length", 3),
+    ]);
+}
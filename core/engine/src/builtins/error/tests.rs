@@ -68,3 +68,30 @@ fn error_lengths() {
         TestAction::assert_eq("AggregateError.length", 2),
     ]);
 }
+
+#[test]
+fn error_cause() {
+    run_test_actions([
+        TestAction::run("var cause = new Error('inner');"),
+        TestAction::assert("new TypeError('x', { cause }).cause === cause"),
+        TestAction::assert("Object.hasOwn(new Error('x', { cause: undefined }), 'cause')"),
+        TestAction::assert("!Object.hasOwn(new Error('x'), 'cause')"),
+        TestAction::assert("!Object.hasOwn(new RangeError('x', {}), 'cause')"),
+        TestAction::assert(
+            "!Object.getOwnPropertyDescriptor(new Error('x', { cause }), 'cause').enumerable",
+        ),
+    ]);
+}
+
+#[test]
+fn aggregate_error() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run("var error = new AggregateError(new Set([1, 2]), 'many', { cause: 3 });"),
+        TestAction::assert("error instanceof Error"),
+        TestAction::assert("arrayEquals(error.errors, [1, 2])"),
+        TestAction::assert_eq("error.message", js_str!("many")),
+        TestAction::assert_eq("error.cause", 3),
+        TestAction::assert("!Object.getOwnPropertyDescriptor(error, 'errors').enumerable"),
+    ]);
+}
@@ -130,6 +130,11 @@ impl Eval {
             parser.set_strict();
         }
         let (mut body, source) = parser.parse_eval(direct, context.interner_mut())?;
+        if parser.has_decorators() {
+            return Err(JsNativeError::syntax()
+                .with_message("decorators are not supported yet")
+                .into());
+        }
 
         // 6. Let inFunction be false.
         // 7. Let inMethod be false.
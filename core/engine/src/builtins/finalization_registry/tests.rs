@@ -2,7 +2,7 @@ mod miri {
 
     use indoc::indoc;
 
-    use crate::{TestAction, run_test_actions};
+    use crate::{JsValue, TestAction, run_test_actions};
 
     #[test]
     fn finalization_registry_simple() {
@@ -86,4 +86,35 @@ mod miri {
             TestAction::assert_eq("counter", 1),
         ]);
     }
+
+    #[test]
+    fn finalization_registry_strongly_held_target() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+            let counter = 0;
+            const registry = new FinalizationRegistry(() => {
+                counter++;
+            });
+
+            var target = ["foo"];
+            const ref = new WeakRef(target);
+            registry.register(target);
+        "#}),
+            TestAction::inspect_context(|ctx| {
+                ctx.clear_kept_objects();
+                boa_gc::force_collect();
+            }),
+            // Target should still be alive while it is strongly held
+            TestAction::assert("ref.deref() === target"),
+            TestAction::run("target = undefined;"),
+            TestAction::inspect_context(|ctx| {
+                ctx.clear_kept_objects();
+                boa_gc::force_collect();
+            }),
+            TestAction::inspect_context(|ctx| ctx.run_jobs().unwrap()),
+            // Callback should run once the target has been collected
+            TestAction::assert_eq("counter", 1),
+            TestAction::assert_eq("ref.deref()", JsValue::undefined()),
+        ]);
+    }
 }
@@ -529,6 +529,12 @@ impl BuiltInFunctionObject {
                     .into());
             }
 
+            if parser.has_decorators() {
+                return Err(JsNativeError::syntax()
+                    .with_message("decorators are not supported yet")
+                    .into());
+            }
+
             parameters
         };
 
@@ -565,6 +571,12 @@ impl BuiltInFunctionObject {
                     .into());
             }
 
+            if parser.has_decorators() {
+                return Err(JsNativeError::syntax()
+                    .with_message("decorators are not supported yet")
+                    .into());
+            }
+
             // 21. NOTE: The parameters and body are parsed separately to ensure that each is valid alone. For example, new Function("/*", "*/ ) {") does not evaluate to a function.
             // 22. NOTE: If this step is reached, sourceText must have the syntax of exprSym (although the reverse implication does not hold). The purpose of the next two steps is to enforce any Early Error rules which apply to exprSym directly.
             // 23. Let expr be ParseText(sourceText, exprSym).
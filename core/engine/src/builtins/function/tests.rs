@@ -194,3 +194,25 @@ fn function_constructor_early_errors_super() {
         ),
     ]);
 }
+
+#[test]
+fn function_to_string_round_trips_literals() {
+    run_test_actions([
+        TestAction::run(indoc! {r"
+            function f() { return /a\/b[/]/g; }
+            var source = f.toString();
+            var re = eval('(' + source + ')')();
+        "}),
+        TestAction::assert_eq("source", js_str!(r"function f() { return /a\/b[/]/g; }")),
+        TestAction::assert_eq("re.source", js_str!(r"a\/b[/]")),
+        TestAction::assert_eq("re.flags", js_str!("g")),
+        TestAction::assert("re.test('xa/b/')"),
+        // Lone surrogates in string and regex literals are kept as is.
+        TestAction::run(indoc! {r#"
+            var g = eval("(function () { return ['\ud800', /\udc00/]; })");
+            var [string, regex] = eval('(' + g.toString() + ')')();
+        "#}),
+        TestAction::assert("string === '\\ud800'"),
+        TestAction::assert("regex.test('\\udc00')"),
+    ]);
+}
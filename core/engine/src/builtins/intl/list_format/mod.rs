@@ -1,4 +1,4 @@
-use std::fmt::Write;
+use std::{fmt::Write, rc::Rc};
 
 use boa_gc::{Finalize, Trace};
 use icu_list::{
@@ -7,6 +7,7 @@ use icu_list::{
     provider::{ListAndV1, ListFormatterPatterns},
 };
 use icu_locale::Locale;
+use writeable::{PartsWrite, Writeable};
 
 use crate::{
     Context, JsArgs, JsData, JsExpect, JsNativeError, JsResult, JsString, JsValue,
@@ -34,6 +35,9 @@ use super::{
 mod options;
 pub(crate) use options::*;
 
+#[cfg(all(test, feature = "intl_bundled"))]
+mod tests;
+
 #[derive(Debug, Trace, Finalize, JsData)]
 // Safety: `ListFormat` only contains non-traceable types.
 #[boa_gc(unsafe_empty_trace)]
@@ -41,7 +45,7 @@ pub(crate) struct ListFormat {
     locale: Locale,
     typ: ListFormatType,
     style: ListLength,
-    native: ListFormatter,
+    native: Rc<ListFormatter>,
 }
 
 impl Service for ListFormat {
@@ -148,26 +152,7 @@ impl BuiltInConstructor for ListFormat {
         // 16. Let dataLocaleData be localeData.[[<dataLocale>]].
         // 17. Let dataLocaleTypes be dataLocaleData.[[<type>]].
         // 18. Set listFormat.[[Templates]] to dataLocaleTypes.[[<style>]].
-        let prefs = ListFormatterPreferences::from(&locale);
-        let options = ListFormatterOptions::default().with_length(style);
-        let formatter = match typ {
-            ListFormatType::Conjunction => ListFormatter::try_new_and_with_buffer_provider(
-                context.intl_provider().erased_provider(),
-                prefs,
-                options,
-            ),
-            ListFormatType::Disjunction => ListFormatter::try_new_or_with_buffer_provider(
-                context.intl_provider().erased_provider(),
-                prefs,
-                options,
-            ),
-            ListFormatType::Unit => ListFormatter::try_new_unit_with_buffer_provider(
-                context.intl_provider().erased_provider(),
-                prefs,
-                options,
-            ),
-        }
-        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+        let formatter = Self::native_formatter(&locale, typ, style, context)?;
 
         // 2. Let listFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%ListFormat.prototype%", « [[InitializedListFormat]], [[Locale]], [[Type]], [[Style]], [[Templates]] »).
         let prototype =
@@ -188,6 +173,70 @@ impl BuiltInConstructor for ListFormat {
     }
 }
 
+impl ListFormat {
+    /// Abstract operation [`CreatePartsFromList ( listFormat, list )`][spec].
+    ///
+    /// The elements are only given to ICU4X to select the list patterns. The parts keep
+    /// the original strings, so that unpaired surrogates are preserved.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-createpartsfromlist
+    fn create_parts_from_list(&self, list: &[JsString]) -> JsResult<Vec<Part>> {
+        let mut parts = PartsCollector {
+            elements: list.iter(),
+            parts: Vec::new(),
+        };
+        self.native
+            .format(list.iter().map(JsString::to_std_string_lossy))
+            .write_to_parts(&mut parts)
+            .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+        Ok(parts.parts)
+    }
+
+    /// Gets the ICU4X formatter for lists of type `typ` and length `style` in `locale`.
+    ///
+    /// Formatters are cached by the context, since constructing them is expensive.
+    fn native_formatter(
+        locale: &Locale,
+        typ: ListFormatType,
+        style: ListLength,
+        context: &Context,
+    ) -> JsResult<Rc<ListFormatter>> {
+        let provider = context.intl_provider();
+        provider.list_formatter(locale, typ, style, || {
+            Self::create_native_formatter(locale, typ, style, context)
+        })
+    }
+
+    /// Creates the ICU4X formatter for lists of type `typ` and length `style` in `locale`.
+    fn create_native_formatter(
+        locale: &Locale,
+        typ: ListFormatType,
+        style: ListLength,
+        context: &Context,
+    ) -> JsResult<ListFormatter> {
+        let prefs = ListFormatterPreferences::from(locale);
+        let options = ListFormatterOptions::default().with_length(style);
+        match typ {
+            ListFormatType::Conjunction => ListFormatter::try_new_and_with_buffer_provider(
+                context.intl_provider().erased_provider(),
+                prefs,
+                options,
+            ),
+            ListFormatType::Disjunction => ListFormatter::try_new_or_with_buffer_provider(
+                context.intl_provider().erased_provider(),
+                prefs,
+                options,
+            ),
+            ListFormatType::Unit => ListFormatter::try_new_unit_with_buffer_provider(
+                context.intl_provider().erased_provider(),
+                prefs,
+                options,
+            ),
+        }
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()).into())
+    }
+}
+
 impl ListFormat {
     /// [`Intl.ListFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
     ///
@@ -237,15 +286,13 @@ impl ListFormat {
             })?;
 
         // 3. Let stringList be ? StringListFromIterable(list).
-        // TODO: support for UTF-16 unpaired surrogates formatting
         let strings = string_list_from_iterable(args.get_or_undefined(0), context)?;
 
-        let formatted = lf
-            .native
-            .format_to_string(strings.into_iter().map(|s| s.to_std_string_escaped()));
-
         // 4. Return ! FormatList(lf, stringList).
-        Ok(js_string!(formatted).into())
+        let parts = lf.create_parts_from_list(&strings)?;
+        let parts: Vec<_> = parts.iter().map(Part::value).collect();
+        let parts: Vec<_> = parts.iter().map(JsString::as_str).collect();
+        Ok(JsString::concat_array(&parts).into())
     }
 
     /// [`Intl.ListFormat.prototype.formatToParts ( list )`][spec].
@@ -262,87 +309,6 @@ impl ListFormat {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        // TODO: maybe try to move this into icu4x?
-        use writeable::{PartsWrite, Writeable};
-
-        #[derive(Debug, Clone)]
-        enum Part {
-            Literal(String),
-            Element(String),
-        }
-
-        impl Part {
-            const fn typ(&self) -> &'static str {
-                match self {
-                    Self::Literal(_) => "literal",
-                    Self::Element(_) => "element",
-                }
-            }
-
-            #[allow(clippy::missing_const_for_fn)]
-            fn value(self) -> String {
-                match self {
-                    Self::Literal(s) | Self::Element(s) => s,
-                }
-            }
-        }
-
-        #[derive(Debug, Clone)]
-        struct WriteString(String);
-
-        impl Write for WriteString {
-            fn write_str(&mut self, s: &str) -> std::fmt::Result {
-                self.0.write_str(s)
-            }
-
-            fn write_char(&mut self, c: char) -> std::fmt::Result {
-                self.0.write_char(c)
-            }
-        }
-
-        impl PartsWrite for WriteString {
-            type SubPartsWrite = Self;
-
-            fn with_part(
-                &mut self,
-                _part: writeable::Part,
-                mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
-            ) -> std::fmt::Result {
-                f(self)
-            }
-        }
-
-        #[derive(Debug, Clone)]
-        struct PartsCollector(Vec<Part>);
-
-        impl Write for PartsCollector {
-            fn write_str(&mut self, _: &str) -> std::fmt::Result {
-                Ok(())
-            }
-        }
-
-        impl PartsWrite for PartsCollector {
-            type SubPartsWrite = WriteString;
-
-            fn with_part(
-                &mut self,
-                part: writeable::Part,
-                mut f: impl FnMut(&mut Self::SubPartsWrite) -> core::fmt::Result,
-            ) -> core::fmt::Result {
-                assert_eq!(part.category, "list");
-                let mut string = WriteString(String::new());
-                f(&mut string)?;
-                if !string.0.is_empty() {
-                    match part.value {
-                        "element" => self.0.push(Part::Element(string.0)),
-                        "literal" => self.0.push(Part::Literal(string.0)),
-                        _ => unreachable!(),
-                    }
-                }
-                Ok(())
-            }
-        }
-
         // 1. Let lf be the this value.
         // 2. Perform ? RequireInternalSlot(lf, [[InitializedListFormat]]).
         let object = this.as_object();
@@ -355,10 +321,7 @@ impl ListFormat {
             })?;
 
         // 3. Let stringList be ? StringListFromIterable(list).
-        // TODO: support for UTF-16 unpaired surrogates formatting
-        let strings = string_list_from_iterable(args.get_or_undefined(0), context)?
-            .into_iter()
-            .map(|s| s.to_std_string_escaped());
+        let strings = string_list_from_iterable(args.get_or_undefined(0), context)?;
 
         // 4. Return ! FormatListToParts(lf, stringList).
 
@@ -366,11 +329,7 @@ impl ListFormat {
         // https://tc39.es/ecma402/#sec-formatlisttoparts
 
         // 1. Let parts be ! CreatePartsFromList(listFormat, list).
-        let mut parts = PartsCollector(Vec::new());
-        lf.native
-            .format(strings)
-            .write_to_parts(&mut parts)
-            .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+        let parts = lf.create_parts_from_list(&strings)?;
 
         // 2. Let result be ! ArrayCreate(0).
         let result = Array::array_create(0, None, context)
@@ -378,7 +337,7 @@ impl ListFormat {
 
         // 3. Let n be 0.
         // 4. For each Record { [[Type]], [[Value]] } part in parts, do
-        for (n, part) in parts.0.into_iter().enumerate() {
+        for (n, part) in parts.into_iter().enumerate() {
             // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
             let o = context
                 .intrinsics()
@@ -391,7 +350,7 @@ impl ListFormat {
                 .js_expect("operation must not fail per the spec")?;
 
             // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
-            o.create_data_property_or_throw(js_string!("value"), js_string!(part.value()), context)
+            o.create_data_property_or_throw(js_string!("value"), part.value(), context)
                 .js_expect("operation must not fail per the spec")?;
 
             // d. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), O).
@@ -476,6 +435,98 @@ impl ListFormat {
     }
 }
 
+/// A part of a formatted list.
+#[derive(Debug, Clone)]
+enum Part {
+    Literal(String),
+    Element(JsString),
+}
+
+impl Part {
+    const fn typ(&self) -> &'static str {
+        match self {
+            Self::Literal(_) => "literal",
+            Self::Element(_) => "element",
+        }
+    }
+
+    fn value(&self) -> JsString {
+        match self {
+            Self::Literal(s) => js_string!(s.as_str()),
+            Self::Element(s) => s.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct WriteString(String);
+
+impl Write for WriteString {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.0.write_str(s)
+    }
+
+    fn write_char(&mut self, c: char) -> std::fmt::Result {
+        self.0.write_char(c)
+    }
+}
+
+impl PartsWrite for WriteString {
+    type SubPartsWrite = Self;
+
+    fn with_part(
+        &mut self,
+        _part: writeable::Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+    ) -> std::fmt::Result {
+        f(self)
+    }
+}
+
+/// Collects the parts written by a [`ListFormatter`], using the strings of `elements`
+/// as the values of the element parts.
+// TODO: maybe try to move this into icu4x?
+#[derive(Debug, Clone)]
+struct PartsCollector<'a> {
+    elements: std::slice::Iter<'a, JsString>,
+    parts: Vec<Part>,
+}
+
+impl Write for PartsCollector<'_> {
+    fn write_str(&mut self, _: &str) -> std::fmt::Result {
+        Ok(())
+    }
+}
+
+impl PartsWrite for PartsCollector<'_> {
+    type SubPartsWrite = WriteString;
+
+    fn with_part(
+        &mut self,
+        part: writeable::Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+    ) -> std::fmt::Result {
+        assert_eq!(part.category, "list");
+        let mut string = WriteString(String::new());
+        f(&mut string)?;
+        match part.value {
+            "element" => {
+                let element = self.elements.next().ok_or(std::fmt::Error)?;
+                if !element.is_empty() {
+                    self.parts.push(Part::Element(element.clone()));
+                }
+            }
+            "literal" => {
+                if !string.0.is_empty() {
+                    self.parts.push(Part::Literal(string.0));
+                }
+            }
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
+
 /// Abstract operation [`StringListFromIterable ( iterable )`][spec]
 ///
 /// [spec]: https://tc39.es/ecma402/#sec-createstringlistfromiterable
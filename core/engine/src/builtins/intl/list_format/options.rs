@@ -7,7 +7,7 @@ use crate::{
     builtins::options::{OptionType, ParsableOptionType},
 };
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub(crate) enum ListFormatType {
     #[default]
     Conjunction,
@@ -0,0 +1,107 @@
+use std::{cell::Cell, rc::Rc};
+
+use icu_list::provider::ListUnitV1;
+use icu_provider::prelude::*;
+use indoc::indoc;
+
+use crate::{
+    TestAction, context::ContextBuilder, js_string, run_test_actions, run_test_actions_with,
+};
+
+#[test]
+fn lone_surrogates_are_preserved() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const lf = new Intl.ListFormat('en', { type: 'conjunction' });
+            const list = ['a\uD800', '\uDC00b', 'c'];
+            const formatted = lf.format(list);
+            const parts = lf.formatToParts(list);
+        "#}),
+        TestAction::assert_eq(r"formatted === 'a\uD800, \uDC00b, and c'", true),
+        TestAction::assert_eq(
+            "parts.map(p => p.type).join()",
+            js_string!("element,literal,element,literal,element"),
+        ),
+        TestAction::assert_eq(r"parts[0].value === 'a\uD800'", true),
+        TestAction::assert_eq(r"parts[2].value === '\uDC00b'", true),
+        TestAction::assert_eq("parts[3].value", js_string!(", and ")),
+    ]);
+}
+
+#[test]
+fn format_matches_format_to_parts() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const lf = new Intl.ListFormat('es', { type: 'conjunction' });
+            const list = ['agua', 'hijo'];
+        "#}),
+        // Spanish selects "e" instead of "y" before words starting with an "i" sound.
+        TestAction::assert_eq("lf.format(list)", js_string!("agua e hijo")),
+        TestAction::assert_eq(
+            "lf.formatToParts(list).map(p => p.value).join('')",
+            js_string!("agua e hijo"),
+        ),
+    ]);
+}
+
+/// A data provider that counts the loads of unit list patterns.
+struct CountingProvider {
+    inner: Box<dyn DynamicDryDataProvider<BufferMarker>>,
+    unit_loads: Rc<Cell<usize>>,
+}
+
+impl DynamicDataProvider<BufferMarker> for CountingProvider {
+    fn load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest<'_>,
+    ) -> Result<DataResponse<BufferMarker>, DataError> {
+        if marker == ListUnitV1::INFO {
+            self.unit_loads.set(self.unit_loads.get() + 1);
+        }
+        self.inner.load_data(marker, req)
+    }
+}
+
+impl DynamicDryDataProvider<BufferMarker> for CountingProvider {
+    fn dry_load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest<'_>,
+    ) -> Result<DataResponseMetadata, DataError> {
+        self.inner.dry_load_data(marker, req)
+    }
+}
+
+#[test]
+fn formatters_are_cached() {
+    let unit_loads = Rc::new(Cell::new(0));
+    let context = &mut ContextBuilder::default()
+        .icu_buffer_provider(CountingProvider {
+            inner: Box::new(boa_icu_provider::buffer()),
+            unit_loads: unit_loads.clone(),
+        })
+        .unwrap()
+        .build()
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                const formatters = [];
+                for (let i = 0; i < 100; i++) {
+                    formatters.push(new Intl.ListFormat('en', { type: 'unit', style: 'short' }));
+                }
+            "#}),
+            TestAction::assert("formatters.every(f => f.format(['a', 'b']) === 'a, b')"),
+            // Other locales and styles get their own formatter.
+            TestAction::assert_eq(
+                "new Intl.ListFormat('en', { type: 'unit', style: 'narrow' }).format(['a', 'b'])",
+                js_string!("a b"),
+            ),
+        ],
+        context,
+    );
+
+    assert_eq!(unit_loads.get(), 2);
+}
@@ -1,5 +1,8 @@
 mod options;
 
+#[cfg(all(test, feature = "intl_bundled"))]
+mod tests;
+
 use boa_gc::{Finalize, Trace};
 use fixed_decimal::{CompactDecimal, Decimal, SignedRoundingMode, UnsignedRoundingMode};
 use icu_locale::Locale;
@@ -0,0 +1,49 @@
+use crate::{TestAction, js_string, run_test_actions};
+
+#[test]
+fn select_cardinal() {
+    run_test_actions([
+        TestAction::run("const en = new Intl.PluralRules('en');"),
+        TestAction::assert_eq("en.select(1)", js_string!("one")),
+        TestAction::assert_eq("en.select(2)", js_string!("other")),
+        TestAction::assert_eq("en.select(0)", js_string!("other")),
+        TestAction::run("const pl = new Intl.PluralRules('pl');"),
+        TestAction::assert_eq("pl.select(1)", js_string!("one")),
+        TestAction::assert_eq("pl.select(2)", js_string!("few")),
+        TestAction::assert_eq("pl.select(5)", js_string!("many")),
+        TestAction::assert_eq("pl.select(1.5)", js_string!("other")),
+        TestAction::assert_eq("new Intl.PluralRules('ar').select(0)", js_string!("zero")),
+        TestAction::assert_eq("new Intl.PluralRules('ar').select(2)", js_string!("two")),
+    ]);
+}
+
+#[test]
+fn select_ordinal() {
+    run_test_actions([
+        TestAction::run("const en = new Intl.PluralRules('en', { type: 'ordinal' });"),
+        TestAction::assert_eq("en.select(1)", js_string!("one")),
+        TestAction::assert_eq("en.select(2)", js_string!("two")),
+        TestAction::assert_eq("en.select(3)", js_string!("few")),
+        TestAction::assert_eq("en.select(4)", js_string!("other")),
+        TestAction::assert_eq("en.select(11)", js_string!("other")),
+    ]);
+}
+
+#[test]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(
+            "const options = new Intl.PluralRules('en', { type: 'ordinal' }).resolvedOptions();",
+        ),
+        TestAction::assert_eq("options.locale", js_string!("en")),
+        TestAction::assert_eq("options.type", js_string!("ordinal")),
+        TestAction::assert_eq(
+            "options.pluralCategories.join()",
+            js_string!("one,two,few,other"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.PluralRules('en').resolvedOptions().type",
+            js_string!("cardinal"),
+        ),
+    ]);
+}
@@ -30,6 +30,9 @@ pub(crate) use iterator::*;
 pub(crate) use options::*;
 pub(crate) use segments::*;
 
+#[cfg(all(test, feature = "intl_bundled"))]
+mod tests;
+
 use super::{
     Service,
     locale::{canonicalize_locale_list, filter_locales, resolve_locale},
@@ -0,0 +1,55 @@
+use indoc::indoc;
+
+use crate::{TestAction, js_string, run_test_actions};
+
+#[test]
+fn segment_by_word() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const segmenter = new Intl.Segmenter('en', { granularity: 'word' });
+            const segments = [...segmenter.segment("Hello world")];
+        "#}),
+        TestAction::assert_eq("segments.length", 3),
+        TestAction::assert_eq(
+            "segments.map(s => s.segment).join('|')",
+            js_string!("Hello| |world"),
+        ),
+        TestAction::assert_eq("segments.map(s => s.index).join()", js_string!("0,5,6")),
+        TestAction::assert_eq(
+            "segments.map(s => s.isWordLike).join()",
+            js_string!("true,false,true"),
+        ),
+        TestAction::assert("segments.every(s => s.input === 'Hello world')"),
+        TestAction::assert_eq(
+            "segmenter.segment('Hello world').containing(7).segment",
+            js_string!("world"),
+        ),
+        TestAction::assert_eq(
+            "segmenter.resolvedOptions().granularity",
+            js_string!("word"),
+        ),
+        TestAction::assert_eq("segmenter.resolvedOptions().locale", js_string!("en")),
+    ]);
+}
+
+#[test]
+fn segment_by_grapheme_and_sentence() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const graphemes = [...new Intl.Segmenter('en').segment("e\u0301a")];
+            const sentences = [
+                ...new Intl.Segmenter('en', { granularity: 'sentence' }).segment("Hi. Bye."),
+            ];
+        "#}),
+        TestAction::assert_eq("graphemes.length", 2),
+        TestAction::assert("!('isWordLike' in graphemes[0])"),
+        TestAction::assert_eq(
+            "sentences.map(s => s.segment).join('|')",
+            js_string!("Hi. |Bye."),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Segmenter().resolvedOptions().granularity",
+            js_string!("grapheme"),
+        ),
+    ]);
+}
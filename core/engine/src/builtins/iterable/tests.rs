@@ -352,6 +352,30 @@ fn iterator_chaining() {
     )]);
 }
 
+#[test]
+fn iterator_chaining_generator_is_lazy() {
+    run_test_actions([
+        TestAction::run(
+            "let pulled = 0;
+             let finished = false;
+             function* gen() {
+               try {
+                 for (let i = 1; i <= 10; i++) { pulled++; yield i; }
+               } finally {
+                 finished = true;
+               }
+             }
+             const helper = gen().map(x => x * 2).filter(x => x > 4).take(2);",
+        ),
+        // Creating the helper chain does not pull any values.
+        TestAction::assert_eq("pulled", 0),
+        TestAction::assert_eq("helper.toArray().join(',')", js_str!("6,8")),
+        // Only the values needed by `take(2)` were pulled, and the generator was closed.
+        TestAction::assert_eq("pulled", 4),
+        TestAction::assert("finished"),
+    ]);
+}
+
 #[test]
 fn iterator_prototype_iterator_self() {
     run_test_actions([TestAction::assert(
@@ -342,3 +342,73 @@ fn json_stringify_cyclic_nested_object_throws_type_error() {
         "cyclic object value",
     )]);
 }
+
+#[test]
+fn json_parse_reviver_source_text() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const sources = [];
+            JSON.parse('[12345678901234567890, 1.50, "a\\u0041", {"k": null}]', (key, value, context) => {
+                sources.push(context.source);
+                return value;
+            });
+        "#}),
+        TestAction::assert_eq("sources[0]", js_str!("12345678901234567890")),
+        TestAction::assert_eq("sources[1]", js_str!("1.50")),
+        TestAction::assert_eq("sources[2]", js_str!(r#""a\u0041""#)),
+        TestAction::assert_eq("sources[3]", js_str!("null")),
+        // Objects and arrays do not expose a source.
+        TestAction::assert_eq("sources[4]", JsValue::undefined()),
+        TestAction::assert_eq("sources[5]", JsValue::undefined()),
+        TestAction::assert(
+            "JSON.parse('12345678901234567890', (k, v, { source }) => BigInt(source)) === 12345678901234567890n",
+        ),
+    ]);
+}
+
+#[test]
+fn json_raw_json_round_trip() {
+    run_test_actions([
+        TestAction::run("const raw = JSON.rawJSON('12345678901234567890');"),
+        TestAction::assert("JSON.isRawJSON(raw)"),
+        TestAction::assert("!JSON.isRawJSON({ rawJSON: '1' })"),
+        TestAction::assert("Object.isFrozen(raw)"),
+        TestAction::assert("Object.getPrototypeOf(raw) === null"),
+        TestAction::assert_eq(
+            "JSON.stringify({ n: raw, list: [raw] })",
+            js_str!(r#"{"n":12345678901234567890,"list":[12345678901234567890]}"#),
+        ),
+        TestAction::assert_eq(
+            "JSON.parse(JSON.stringify(raw), (k, v, { source }) => source)",
+            js_str!("12345678901234567890"),
+        ),
+        TestAction::assert_native_error(
+            "JSON.rawJSON('{}')",
+            JsNativeErrorKind::Syntax,
+            "JSON.rawJSON text must not be an object or array",
+        ),
+        TestAction::assert_native_error(
+            "JSON.rawJSON(' 1')",
+            JsNativeErrorKind::Syntax,
+            "JSON.rawJSON text must not start or end with whitespace",
+        ),
+    ]);
+}
+
+#[test]
+fn json_stringify_lone_surrogates() {
+    run_test_actions([
+        TestAction::assert_eq(r"JSON.stringify('\uD800')", js_str!(r#""\ud800""#)),
+        TestAction::assert_eq(r"JSON.stringify('\uDEAD')", js_str!(r#""\udead""#)),
+        TestAction::assert_eq(
+            r"JSON.stringify('\uDC00\uD800')",
+            js_str!(r#""\udc00\ud800""#),
+        ),
+        // Well-formed surrogate pairs are emitted unchanged.
+        TestAction::assert(r"JSON.stringify('😀') === '\x22😀\x22'"),
+        TestAction::assert_eq(
+            r"JSON.stringify({ '\uD800': '𝌆\uD834' })",
+            js_str!(r#"{"\ud800":"𝌆\ud834"}"#),
+        ),
+    ]);
+}
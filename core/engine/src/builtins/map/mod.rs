@@ -726,7 +726,7 @@ impl Map {
         let callback = callback.as_callable().ok_or_else(|| {
             js_error!(
                 TypeError:
-                    "method `Map.prototype.groupBy` called with non-callable callback function"
+                    "method `Map.groupBy` called with non-callable callback function"
             )
         })?;
 
@@ -396,3 +396,28 @@ fn get_or_insert_computed_this_is_undefined_and_key_canonicalized() {
         TestAction::assert_eq("m.get(0)", js_str!("ok")),
     ]);
 }
+
+#[test]
+fn group_by() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            const indices = [];
+            const odd = Symbol("odd");
+            const grouped = Map.groupBy([1, 2, 3, 4, 5], (value, index) => {
+                indices.push(index);
+                return value % 2 === 0 ? "even" : odd;
+            });
+        "#}),
+        TestAction::assert("grouped instanceof Map"),
+        TestAction::assert("arrayEquals(indices, [0, 1, 2, 3, 4])"),
+        TestAction::assert("arrayEquals([...grouped.keys()], [odd, 'even'])"),
+        TestAction::assert("arrayEquals(grouped.get(odd), [1, 3, 5])"),
+        TestAction::assert("arrayEquals(grouped.get('even'), [2, 4])"),
+        TestAction::assert_native_error(
+            "Map.groupBy([1], null)",
+            JsNativeErrorKind::Type,
+            "method `Map.groupBy` called with non-callable callback function",
+        ),
+    ]);
+}
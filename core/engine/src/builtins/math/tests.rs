@@ -329,3 +329,39 @@ fn trunc() {
         TestAction::assert_eq("Math.trunc(0.123)", 0.0),
     ]);
 }
+
+#[cfg(feature = "xsum")]
+#[test]
+fn sum_precise() {
+    use crate::JsNativeErrorKind;
+
+    run_test_actions([
+        TestAction::assert_eq("Math.sumPrecise([1e20, 0.1, -1e20])", 0.1),
+        TestAction::assert_eq("[1e20, 0.1, -1e20].reduce((a, b) => a + b, 0)", 0.0),
+        TestAction::assert_eq("Math.sumPrecise([0.1, 0.2, 0.3])", 0.6),
+        TestAction::assert_eq("Math.sumPrecise(new Set([1, 2, 3]))", 6.0),
+        TestAction::assert("Object.is(Math.sumPrecise([]), -0)"),
+        TestAction::assert("Object.is(Math.sumPrecise([-0, -0]), -0)"),
+        TestAction::assert("Object.is(Math.sumPrecise([-0, 0]), 0)"),
+        TestAction::assert("Object.is(Math.sumPrecise([1, -1]), 0)"),
+        TestAction::assert_eq("Math.sumPrecise([Infinity, 1])", f64::INFINITY),
+        TestAction::assert_eq("Math.sumPrecise([-Infinity, 1])", f64::NEG_INFINITY),
+        TestAction::assert_eq("Math.sumPrecise([Infinity, -Infinity])", f64::NAN),
+        TestAction::assert_eq("Math.sumPrecise([NaN, Infinity])", f64::NAN),
+        TestAction::assert_eq(
+            "Math.sumPrecise([1e308, 1e308, -Infinity])",
+            f64::NEG_INFINITY,
+        ),
+        TestAction::assert_eq("Math.sumPrecise([1e308, 1e308, -1e308])", 1e308),
+        TestAction::assert_native_error(
+            "Math.sumPrecise([1, '2'])",
+            JsNativeErrorKind::Type,
+            "sumPrecise can only be called on a number.",
+        ),
+        TestAction::assert_native_error(
+            "Math.sumPrecise(undefined)",
+            JsNativeErrorKind::Type,
+            "value must be object coercible.",
+        ),
+    ]);
+}
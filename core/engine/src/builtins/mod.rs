@@ -10,6 +10,7 @@ pub mod bigint;
 pub mod boolean;
 pub mod dataview;
 pub mod date;
+pub mod disposable_stack;
 pub mod error;
 pub mod eval;
 pub mod finalization_registry;
@@ -64,7 +65,8 @@ pub(crate) use self::{
     dataview::DataView,
     date::Date,
     error::{
-        AggregateError, EvalError, RangeError, ReferenceError, SyntaxError, TypeError, UriError,
+        AggregateError, EvalError, RangeError, ReferenceError, SuppressedError, SyntaxError,
+        TypeError, UriError,
     },
     eval::Eval,
     finalization_registry::FinalizationRegistry,
@@ -95,6 +97,7 @@ use crate::{
         async_generator::AsyncGenerator,
         async_generator_function::AsyncGeneratorFunction,
         atomics::Atomics,
+        disposable_stack::{AsyncDisposableStack, DisposableStack},
         error::r#type::ThrowTypeError,
         generator::Generator,
         generator_function::GeneratorFunction,
@@ -300,6 +303,7 @@ impl Realm {
         EvalError::init(self);
         UriError::init(self);
         AggregateError::init(self);
+        SuppressedError::init(self);
         Reflect::init(self);
         Generator::init(self);
         GeneratorFunction::init(self);
@@ -316,6 +320,8 @@ impl Realm {
         WeakSet::init(self);
         Atomics::init(self);
         FinalizationRegistry::init(self);
+        DisposableStack::init(self);
+        AsyncDisposableStack::init(self);
 
         #[cfg(feature = "annex-b")]
         {
@@ -433,6 +439,7 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<EvalError>(context)?;
     global_binding::<UriError>(context)?;
     global_binding::<AggregateError>(context)?;
+    global_binding::<SuppressedError>(context)?;
     global_binding::<Reflect>(context)?;
     global_binding::<Promise>(context)?;
     global_binding::<EncodeUri>(context)?;
@@ -445,6 +452,8 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<IteratorConstructor>(context)?;
     global_binding::<Atomics>(context)?;
     global_binding::<FinalizationRegistry>(context)?;
+    global_binding::<DisposableStack>(context)?;
+    global_binding::<AsyncDisposableStack>(context)?;
 
     #[cfg(feature = "annex-b")]
     {
@@ -109,6 +109,32 @@ fn object_has_own() {
         TestAction::assert("Object.hasOwn(x, symA)"),
         TestAction::assert("!Object.hasOwn(x, 1000)"),
         TestAction::assert("Object.hasOwn(x, 100)"),
+        TestAction::assert("!Object.hasOwn(Object.create(x), 'someProp')"),
+    ]);
+}
+
+#[test]
+fn object_has_own_primitive() {
+    run_test_actions([
+        TestAction::assert("Object.hasOwn('abc', 0)"),
+        TestAction::assert("Object.hasOwn('abc', 'length')"),
+        TestAction::assert("!Object.hasOwn('abc', 3)"),
+        TestAction::assert("!Object.hasOwn('abc', 'at')"),
+        TestAction::assert("!Object.hasOwn(42, 'toFixed')"),
+        TestAction::assert("Object.hasOwn([1], '0')"),
+        TestAction::assert_native_error(
+            "Object.hasOwn(null, 'a')",
+            JsNativeErrorKind::Type,
+            "cannot convert 'null' or 'undefined' to object",
+        ),
+        // The receiver is converted before the key.
+        TestAction::run(indoc! {r#"
+            let coerced = false;
+            try {
+                Object.hasOwn(undefined, { toString() { coerced = true; return 'a'; } });
+            } catch {}
+        "#}),
+        TestAction::assert("!coerced"),
     ]);
 }
 
@@ -439,3 +465,22 @@ fn object_prototype_proto_accessor_properties() {
         TestAction::assert_eq("desc.set.name", js_str!("set __proto__")),
     ]);
 }
+
+#[test]
+fn object_group_by() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            const indices = [];
+            const grouped = Object.groupBy([1, 2, 3, 4, 5], (value, index) => {
+                indices.push(index);
+                return value % 2 === 0 ? "even" : "odd";
+            });
+        "#}),
+        TestAction::assert("Object.getPrototypeOf(grouped) === null"),
+        TestAction::assert("arrayEquals(indices, [0, 1, 2, 3, 4])"),
+        TestAction::assert("arrayEquals(Object.keys(grouped), ['odd', 'even'])"),
+        TestAction::assert("arrayEquals(grouped.odd, [1, 3, 5])"),
+        TestAction::assert("arrayEquals(grouped.even, [2, 4])"),
+    ]);
+}
@@ -3,6 +3,10 @@
 #[cfg(test)]
 mod tests;
 
+mod rejections;
+
+pub(crate) use rejections::{RejectionTracker, notify_rejections};
+
 use super::{
     BuiltInBuilder, BuiltInConstructor, IntrinsicObject,
     iterable::{IteratorHint, IteratorRecord},
@@ -86,6 +90,9 @@ pub struct Promise {
     fulfill_reactions: Vec<ReactionRecord>,
     reject_reactions: Vec<ReactionRecord>,
     handled: bool,
+
+    /// Whether this promise was reported as an unhandled rejection.
+    rejection_reported: bool,
 }
 
 /// The operation type of the [`HostPromiseRejectionTracker`][fn] abstract operation.
@@ -466,6 +473,7 @@ impl Promise {
             fulfill_reactions: Vec::default(),
             reject_reactions: Vec::default(),
             handled: false,
+            rejection_reported: false,
         }
     }
 
@@ -2259,6 +2267,9 @@ impl Promise {
                         OperationType::Handle,
                         context,
                     );
+                    context
+                        .rejection_tracker_mut()
+                        .track(promise, OperationType::Handle);
                 }
 
                 //   d. Let rejectJob be NewPromiseReactionJob(rejectReaction, reason).
@@ -2437,6 +2448,9 @@ impl Promise {
                     OperationType::Reject,
                     context,
                 );
+                context
+                    .rejection_tracker_mut()
+                    .track(promise, OperationType::Reject);
             }
 
             // 9. Return unused.
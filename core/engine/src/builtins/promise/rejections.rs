@@ -0,0 +1,87 @@
+//! Tracking of unhandled promise rejections, reported through the callbacks registered with
+//! [`Context::on_unhandled_rejection`] and [`Context::on_rejection_handled`].
+
+use super::{OperationType, Promise};
+use crate::{Context, JsValue, object::JsObject};
+use std::{mem, rc::Rc};
+
+/// A callback notified about a promise rejection, receiving its rejection reason.
+pub(crate) type RejectionCallback = Rc<dyn Fn(JsValue, &mut Context)>;
+
+/// Tracks the promises rejected without a handler, until the jobs of the context are drained.
+#[derive(Default)]
+pub(crate) struct RejectionTracker {
+    on_unhandled: Option<RejectionCallback>,
+    on_handled: Option<RejectionCallback>,
+
+    /// Promises rejected without a handler since the last notification.
+    unhandled: Vec<JsObject<Promise>>,
+
+    /// Promises already reported as unhandled that got a handler since the last notification.
+    handled: Vec<JsObject<Promise>>,
+}
+
+impl RejectionTracker {
+    /// Sets the callback notified about unhandled rejections.
+    pub(crate) fn set_on_unhandled(&mut self, callback: RejectionCallback) {
+        self.on_unhandled = Some(callback);
+    }
+
+    /// Sets the callback notified about rejections handled after being reported.
+    pub(crate) fn set_on_handled(&mut self, callback: RejectionCallback) {
+        self.on_handled = Some(callback);
+    }
+
+    /// Records a [`HostPromiseRejectionTracker`][spec] operation performed on `promise`.
+    ///
+    /// Promises are only tracked if a callback was registered.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-promise-rejection-tracker
+    pub(crate) fn track(&mut self, promise: &JsObject<Promise>, operation: OperationType) {
+        if self.on_unhandled.is_none() && self.on_handled.is_none() {
+            return;
+        }
+
+        match operation {
+            OperationType::Reject => self.unhandled.push(promise.clone()),
+            OperationType::Handle => {
+                // A handler attached before the jobs are drained means the rejection
+                // was never unhandled.
+                if let Some(index) = self.unhandled.iter().position(|p| p == promise) {
+                    self.unhandled.remove(index);
+                } else if promise.borrow().data().rejection_reported {
+                    self.handled.push(promise.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Notifies the registered callbacks about the promises rejected without a handler, and about
+/// the previously reported promises that got a handler since the last notification.
+pub(crate) fn notify_rejections(context: &mut Context) {
+    let tracker = context.rejection_tracker_mut();
+    let unhandled = mem::take(&mut tracker.unhandled);
+    let handled = mem::take(&mut tracker.handled);
+    let on_unhandled = tracker.on_unhandled.clone();
+    let on_handled = tracker.on_handled.clone();
+
+    for promise in unhandled {
+        let reason = {
+            let mut promise = promise.borrow_mut();
+            let promise = promise.data_mut();
+            promise.rejection_reported = true;
+            promise.state.as_rejected().cloned()
+        };
+        if let (Some(callback), Some(reason)) = (&on_unhandled, reason) {
+            callback(reason, context);
+        }
+    }
+
+    for promise in handled {
+        let reason = promise.borrow().data().state.as_rejected().cloned();
+        if let (Some(callback), Some(reason)) = (&on_handled, reason) {
+            callback(reason, context);
+        }
+    }
+}
@@ -1,5 +1,15 @@
-use crate::{TestAction, run_test_actions};
+use crate::{
+    Context, JsNativeErrorKind, JsValue, Source, TestAction,
+    job::{JobExecutor, NativeJob, SimpleJobExecutor, TimeoutJob},
+    run_test_actions,
+};
+use boa_macros::js_str;
+use futures_lite::future;
 use indoc::indoc;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
 
 #[test]
 fn promise() {
@@ -98,3 +108,171 @@ fn promise_race_resolves_first() {
         TestAction::assert_eq("val", 10),
     ]);
 }
+
+#[test]
+fn promise_with_resolvers_resolve() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var value = null;
+            var { promise, resolve, reject } = Promise.withResolvers();
+            promise.then(v => { value = v; });
+            resolve(42);
+            reject(new Error("ignored"));
+        "#}),
+        TestAction::assert("promise instanceof Promise"),
+        TestAction::assert_eq("value", JsValue::null()),
+        TestAction::inspect_context(|ctx| ctx.run_jobs().unwrap()),
+        TestAction::assert_eq("value", 42),
+    ]);
+}
+
+#[test]
+fn promise_with_resolvers_subclass() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var reason = null;
+            class MyPromise extends Promise {}
+            var resolvers = MyPromise.withResolvers();
+            resolvers.promise.catch(e => { reason = e; });
+            resolvers.reject("boom");
+        "#}),
+        TestAction::assert("resolvers.promise instanceof MyPromise"),
+        TestAction::inspect_context(|ctx| ctx.run_jobs().unwrap()),
+        TestAction::assert_eq("reason", js_str!("boom")),
+        TestAction::assert_native_error(
+            "Promise.withResolvers.call(1)",
+            JsNativeErrorKind::Type,
+            "Promise.withResolvers() called on a non-object",
+        ),
+    ]);
+}
+
+#[test]
+fn promise_any_rejects_with_aggregate_error() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            var err = null;
+            var first = new Error("first");
+            Promise.any([Promise.reject(first), Promise.reject(2)]).catch(e => { err = e; });
+        "#}),
+        TestAction::inspect_context(|ctx| ctx.run_jobs().unwrap()),
+        TestAction::assert("err instanceof AggregateError"),
+        TestAction::assert("arrayEquals(err.errors, [first, 2])"),
+    ]);
+}
+
+#[test]
+fn unhandled_rejection_hook() {
+    let unhandled = Rc::new(RefCell::new(Vec::new()));
+    let handled = Rc::new(RefCell::new(Vec::new()));
+
+    let context = &mut Context::default();
+    context.on_unhandled_rejection({
+        let unhandled = unhandled.clone();
+        move |reason, _| unhandled.borrow_mut().push(reason)
+    });
+    context.on_rejection_handled({
+        let handled = handled.clone();
+        move |reason, _| handled.borrow_mut().push(reason)
+    });
+
+    let to_strings = |values: &RefCell<Vec<JsValue>>| {
+        values
+            .borrow()
+            .iter()
+            .map(|v| v.as_string().unwrap().to_std_string_escaped())
+            .collect::<Vec<_>>()
+    };
+
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+            var rejected = Promise.reject("unhandled");
+            Promise.reject("handled").catch(() => {});
+            (async () => { throw "async"; })();
+            new Promise((_, reject) => reject("chained")).then(() => {});
+        "#}))
+        .unwrap();
+    context.run_jobs().unwrap();
+    assert_eq!(to_strings(&unhandled), ["unhandled", "async", "chained"]);
+    assert!(handled.borrow().is_empty());
+
+    context
+        .eval(Source::from_bytes("rejected.catch(() => {});"))
+        .unwrap();
+    context.run_jobs().unwrap();
+    assert_eq!(to_strings(&unhandled), ["unhandled", "async", "chained"]);
+    assert_eq!(to_strings(&handled), ["unhandled"]);
+}
+
+#[test]
+fn unhandled_rejection_hook_waits_for_pending_jobs() {
+    let unhandled = Rc::new(RefCell::new(Vec::new()));
+    let handled = Rc::new(RefCell::new(Vec::new()));
+
+    let context = &mut Context::default();
+    context.on_unhandled_rejection({
+        let unhandled = unhandled.clone();
+        move |reason, _| unhandled.borrow_mut().push(reason)
+    });
+    context.on_rejection_handled({
+        let handled = handled.clone();
+        move |reason, _| handled.borrow_mut().push(reason)
+    });
+
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+            var rejected = Promise.reject("late");
+            Promise.resolve()
+                .then(() => {})
+                .then(() => rejected.catch(() => {}));
+        "#}))
+        .unwrap();
+
+    // The handler is still queued when the budget is spent, so nothing is reported yet.
+    assert!(context.run_jobs_to_completion(1).unwrap());
+    assert!(unhandled.borrow().is_empty());
+
+    assert!(!context.run_jobs_to_completion(10).unwrap());
+    assert!(unhandled.borrow().is_empty());
+    assert!(handled.borrow().is_empty());
+}
+
+#[test]
+fn unhandled_rejection_hook_after_microtask_checkpoint() {
+    let unhandled = Rc::new(RefCell::new(Vec::new()));
+    let reported_before_timeout = Rc::new(Cell::new(false));
+
+    let context = &mut Context::default();
+    context.on_unhandled_rejection({
+        let unhandled = unhandled.clone();
+        move |reason, _| unhandled.borrow_mut().push(reason)
+    });
+
+    context
+        .eval(Source::from_bytes(r#"Promise.reject("unhandled");"#))
+        .unwrap();
+    context.enqueue_job(
+        TimeoutJob::new(
+            NativeJob::new({
+                let unhandled = unhandled.clone();
+                let reported_before_timeout = reported_before_timeout.clone();
+                move |_| {
+                    reported_before_timeout.set(unhandled.borrow().len() == 1);
+                    Ok(JsValue::undefined())
+                }
+            }),
+            0,
+        )
+        .into(),
+    );
+
+    // Driving the executor directly still notifies about the rejection, right after
+    // the microtasks are drained.
+    let executor = context
+        .downcast_job_executor::<SimpleJobExecutor>()
+        .unwrap();
+    future::block_on(executor.run_jobs_async(&RefCell::new(context))).unwrap();
+    assert!(reported_before_timeout.get());
+    assert_eq!(unhandled.borrow().len(), 1);
+}
@@ -0,0 +1,155 @@
+//! A per-context cache of compiled `RegExp` matchers.
+
+use super::Matcher;
+use crate::{JsString, string::JsStrVariant};
+use rustc_hash::FxHashMap;
+use std::{ptr, rc::Rc};
+
+/// The maximum number of compiled matchers kept in a [`RegExpCache`].
+const REGEXP_CACHE_CAPACITY: usize = 64;
+
+/// The largest widened Latin1 buffer kept between matches, in code units (64 kibibytes).
+///
+/// Buffers grown past this by a longer input are shrunk back once the match is done, and
+/// the longer input is not retained, so a single large match does not keep its copy alive
+/// for the rest of the context.
+pub(crate) const MAX_RETAINED_LATIN1_WIDENED: usize = (64 * 1024) / size_of::<u16>();
+
+#[derive(Debug)]
+struct CacheEntry {
+    matcher: Rc<Matcher>,
+    last_used: u64,
+}
+
+/// A bounded, least-recently-used cache of compiled matchers, keyed by the
+/// original source and flags of a `RegExp`.
+///
+/// This avoids recompiling the same pattern when a `RegExp` is constructed
+/// repeatedly, e.g. a literal or a `new RegExp(p, f)` call in a loop.
+#[derive(Debug, Default)]
+pub(crate) struct RegExpCache {
+    entries: FxHashMap<(JsString, JsString), CacheEntry>,
+    tick: u64,
+
+    /// The last Latin1 string matched against, if no longer than [`MAX_RETAINED_LATIN1_WIDENED`].
+    widened_latin1_source: Option<JsString>,
+
+    /// The last Latin1 string matched against, widened to UCS-2.
+    widened_latin1: Vec<u16>,
+
+    /// The number of matchers compiled through this cache.
+    #[cfg(test)]
+    compilations: u64,
+
+    /// The number of Latin1 strings widened through this cache.
+    #[cfg(test)]
+    widenings: u64,
+}
+
+impl RegExpCache {
+    /// Returns the matcher for `source` and `flags`, compiling it with `compile`
+    /// if it isn't cached.
+    pub(crate) fn get_or_compile<E>(
+        &mut self,
+        source: &JsString,
+        flags: &JsString,
+        compile: impl FnOnce() -> Result<Matcher, E>,
+    ) -> Result<Rc<Matcher>, E> {
+        self.tick += 1;
+        let key = (source.clone(), flags.clone());
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.last_used = self.tick;
+            return Ok(entry.matcher.clone());
+        }
+
+        let matcher = Rc::new(compile()?);
+        #[cfg(test)]
+        {
+            self.compilations += 1;
+        }
+
+        if self.entries.len() >= REGEXP_CACHE_CAPACITY
+            && let Some(lru) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone())
+        {
+            self.entries.remove(&lru);
+        }
+        self.entries.insert(
+            key,
+            CacheEntry {
+                matcher: matcher.clone(),
+                last_used: self.tick,
+            },
+        );
+
+        Ok(matcher)
+    }
+
+    /// Calls `f` with `input`, whose code units are `latin1`, widened to UCS-2.
+    ///
+    /// regress does not support the Latin1 encoding, so Latin1 strings have to be widened
+    /// before matching. Short strings are kept with their widened copy until a different
+    /// string is matched, so repeated matches against the same string (e.g. a global `RegExp`
+    /// in a loop) only widen it once.
+    pub(crate) fn with_widened_latin1<R>(
+        &mut self,
+        input: &JsString,
+        latin1: &[u8],
+        f: impl FnOnce(&[u16]) -> R,
+    ) -> R {
+        // Strings are compared by identity. The retained source keeps its allocation alive,
+        // so another string cannot reuse its address.
+        let cached = self.widened_latin1_source.as_ref().is_some_and(|source| {
+            matches!(source.as_str().variant(), JsStrVariant::Latin1(source) if ptr::eq(source, latin1))
+        });
+        if !cached {
+            self.widened_latin1.clear();
+            self.widened_latin1
+                .extend(latin1.iter().copied().map(u16::from));
+            self.widened_latin1_source =
+                (latin1.len() <= MAX_RETAINED_LATIN1_WIDENED).then(|| input.clone());
+            #[cfg(test)]
+            {
+                self.widenings += 1;
+            }
+        }
+
+        let result = f(&self.widened_latin1);
+
+        // The capacity can exceed the limit even if the length doesn't, so the widened copy
+        // of a short string may be dropped here as well.
+        if self.widened_latin1.capacity() > MAX_RETAINED_LATIN1_WIDENED {
+            self.widened_latin1_source = None;
+            self.widened_latin1.clear();
+            self.widened_latin1.shrink_to(MAX_RETAINED_LATIN1_WIDENED);
+        }
+        result
+    }
+
+    /// Returns the number of matchers compiled through this cache.
+    #[cfg(test)]
+    pub(crate) const fn compilations(&self) -> u64 {
+        self.compilations
+    }
+
+    /// Returns the number of Latin1 strings widened through this cache.
+    #[cfg(test)]
+    pub(crate) const fn widenings(&self) -> u64 {
+        self.widenings
+    }
+
+    /// Returns the capacity of the buffer holding the widened Latin1 string.
+    #[cfg(test)]
+    pub(crate) fn widened_latin1_capacity(&self) -> usize {
+        self.widened_latin1.capacity()
+    }
+
+    /// Returns the number of cached matchers.
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
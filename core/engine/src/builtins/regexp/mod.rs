@@ -15,8 +15,12 @@ use crate::{
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     js_string,
-    object::{CONSTRUCTOR, JsObject, internal_methods::get_prototype_from_constructor},
-    property::Attribute,
+    object::{
+        CONSTRUCTOR, JsObject,
+        internal_methods::{InternalMethodPropertyContext, get_prototype_from_constructor},
+        shape::slot::SlotAttributes,
+    },
+    property::{Attribute, PropertyDescriptor},
     realm::Realm,
     string::{CodePoint, CommonJsStringBuilder, JsStrVariant, StaticJsStrings},
     symbol::JsSymbol,
@@ -26,22 +30,101 @@ use boa_gc::{Finalize, Trace};
 use boa_macros::{js_str, utf16};
 use boa_parser::lexer::regex::RegExpFlags;
 use regress::{Flags, Range, Regex};
+use std::rc::Rc;
 use std::str::FromStr;
 
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
+mod cache;
 mod regexp_string_iterator;
+pub(crate) use cache::RegExpCache;
 pub(crate) use regexp_string_iterator::RegExpStringIterator;
 #[cfg(test)]
 mod tests;
 
+/// The compiled matcher of a `RegExp`.
+#[derive(Debug)]
+pub(crate) enum Matcher {
+    /// Searches for the pattern from the start index onwards.
+    Search(Regex),
+
+    /// Only tries to match the pattern at the start index, for sticky patterns.
+    ///
+    /// regress doesn't support the sticky flag, so the pattern `P` is compiled both as
+    /// `(?:P)|` and as `(?:P)|[^]`. Their fallback alternatives always match at the start
+    /// index (except `[^]` at the end of the input), so neither searches past it. Both
+    /// give the same match if `P` matches, but if it doesn't, the first one matches the
+    /// empty string and the second one a single character.
+    ///
+    /// Unlike a trailing capture group, the fallbacks don't change the meaning of decimal
+    /// escapes such as `\1`, which are octal escapes in patterns without groups.
+    Sticky { empty: Regex, any: Regex },
+}
+
+impl Matcher {
+    /// Returns the first match found by `find`, which runs a compiled regex on the input.
+    fn find_with(&self, find: impl Fn(&Regex) -> Option<regress::Match>) -> Option<regress::Match> {
+        match self {
+            Self::Search(regex) => find(regex),
+            Self::Sticky { empty, any } => {
+                let m = find(empty)?;
+                if m.start() != m.end() {
+                    return Some(m);
+                }
+
+                // NOTE: The empty match is either a match of the pattern or of the fallback.
+                //       The pattern only failed if the other fallback consumed a character.
+                find(any).filter(|m| m.start() == m.end())
+            }
+        }
+    }
+
+    /// Finds the first match in the UTF-16 string `input`, starting at `start`.
+    fn find_from_utf16(&self, input: &[u16], start: usize) -> Option<regress::Match> {
+        self.find_with(|regex| regex.find_from_utf16(input, start).next())
+    }
+
+    /// Finds the first match in the UCS-2 string `input`, starting at `start`.
+    fn find_from_ucs2(&self, input: &[u16], start: usize) -> Option<regress::Match> {
+        self.find_with(|regex| regex.find_from_ucs2(input, start).next())
+    }
+}
+
+/// Returns the first Unicode property escape (`\p{...}` or `\P{...}`) in `pattern`
+/// that cannot be compiled, to report it in the `SyntaxError` thrown for the pattern.
+fn unsupported_property_escape(pattern: &JsString) -> Option<String> {
+    let pattern = pattern.to_std_string_lossy();
+    let mut chars = pattern.char_indices();
+    while let Some((start, c)) = chars.next() {
+        if c != '\\' {
+            continue;
+        }
+        let Some((_, 'p' | 'P')) = chars.next() else {
+            continue;
+        };
+        let rest = &pattern[start..];
+        let Some(end) = rest.find('}').filter(|_| rest[2..].starts_with('{')) else {
+            continue;
+        };
+        let escape = &rest[..=end];
+        let flags = Flags {
+            unicode: true,
+            ..Flags::default()
+        };
+        if Regex::from_unicode(escape.chars().map(u32::from), flags).is_err() {
+            return Some(escape.to_owned());
+        }
+    }
+    None
+}
+
 /// The internal representation of a `RegExp` object.
 #[derive(Debug, Clone, Trace, Finalize, JsData)]
 // Safety: `RegExp` does not contain any objects which needs to be traced, so this is safe.
 #[boa_gc(unsafe_empty_trace)]
 pub struct RegExp {
-    /// Regex matcher.
-    matcher: Regex,
+    /// Regex matcher, shared with the context's [`RegExpCache`].
+    matcher: Rc<Matcher>,
     flags: RegExpFlags,
     original_source: JsString,
     original_flags: JsString,
@@ -97,6 +180,15 @@ impl IntrinsicObject for RegExp {
         let get_source = BuiltInBuilder::callable(realm, Self::get_source)
             .name(js_string!("get source"))
             .build();
+        let exec_function = BuiltInBuilder::callable_with_object(
+            realm,
+            realm.intrinsics().objects().regexp_prototype_exec().into(),
+            Self::exec,
+        )
+        .name(js_string!("exec"))
+        .length(1)
+        .build();
+
         let regexp = BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .static_method(Self::escape, js_string!("escape"), 1)
             .static_accessor(
@@ -107,7 +199,11 @@ impl IntrinsicObject for RegExp {
             )
             .property(js_string!("lastIndex"), 0, Attribute::all())
             .method(Self::test, js_string!("test"), 1)
-            .method(Self::exec, js_string!("exec"), 1)
+            .property(
+                js_string!("exec"),
+                exec_function,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
             .method(Self::to_string, js_string!("toString"), 0)
             .method(Self::r#match, JsSymbol::r#match(), 1)
             .method(Self::match_all, JsSymbol::match_all(), 1)
@@ -355,34 +451,50 @@ impl RegExp {
         let full_unicode =
             flags.contains(RegExpFlags::UNICODE) || flags.contains(RegExpFlags::UNICODE_SETS);
 
-        let matcher = if full_unicode {
-            // Unicode mode (u/v flag) OR pattern has named groups:
-            // compile as full Unicode codepoints.
-            Regex::from_unicode(p.code_points().map(CodePoint::as_u32), Flags::from(flags))
-                .map_err(|error| {
-                    JsNativeError::syntax()
-                        .with_message(format!("failed to create matcher: {}", error.text))
-                })?
-        } else {
-            // Non-Unicode mode with no named groups:
-            // compile as raw UTF-16 code units so that surrogate pairs
-            // (e.g. 𠮷 = [0xD842, 0xDFB7]) are matched correctly by find_from_ucs2.
-            let utf16_units = p.code_points().flat_map(|cp| {
-                let mut buf = [0u16; 2];
-                match cp {
-                    CodePoint::Unicode(c) => c
-                        .encode_utf16(&mut buf)
-                        .iter()
-                        .map(|&u| u32::from(u))
-                        .collect::<Vec<_>>(),
-                    CodePoint::UnpairedSurrogate(s) => vec![u32::from(s)],
+        let matcher = context
+            .regexp_cache_mut()
+            .get_or_compile(&p, &f, || {
+                let units: Vec<u32> = if full_unicode {
+                    // Unicode mode (u/v flag) OR pattern has named groups:
+                    // compile as full Unicode codepoints.
+                    p.code_points().map(CodePoint::as_u32).collect()
+                } else {
+                    // Non-Unicode mode with no named groups:
+                    // compile as raw UTF-16 code units so that surrogate pairs
+                    // (e.g. 𠮷 = [0xD842, 0xDFB7]) are matched correctly by find_from_ucs2.
+                    p.to_vec().into_iter().map(u32::from).collect()
+                };
+                let matcher = Regex::from_unicode(units.iter().copied(), Flags::from(flags))?;
+                if !flags.contains(RegExpFlags::STICKY) {
+                    return Ok(Matcher::Search(matcher));
                 }
-            });
-            Regex::from_unicode(utf16_units, Flags::from(flags)).map_err(|error| {
-                JsNativeError::syntax()
-                    .with_message(format!("failed to create matcher: {}", error.text))
-            })?
-        };
+
+                // NOTE: The pattern is compiled on its own first, to reject patterns such as
+                //       `a)|(?:b` which are only valid when wrapped. See `Matcher::Sticky`.
+                let wrapped = |fallback: &str| {
+                    let pattern = "(?:"
+                        .chars()
+                        .map(u32::from)
+                        .chain(units.iter().copied())
+                        .chain(")|".chars().map(u32::from))
+                        .chain(fallback.chars().map(u32::from));
+                    Regex::from_unicode(pattern, Flags::from(flags))
+                };
+                Ok(Matcher::Sticky {
+                    empty: wrapped("")?,
+                    any: wrapped("[^]")?,
+                })
+            })
+            .map_err(|error: regress::Error| {
+                let message = match full_unicode
+                    .then(|| unsupported_property_escape(&p))
+                    .flatten()
+                {
+                    Some(escape) => format!("invalid Unicode property escape: {escape}"),
+                    None => format!("failed to create matcher: {}", error.text),
+                };
+                JsNativeError::syntax().with_message(message)
+            })?;
 
         // 15. Assert: parseResult is a Pattern Parse Node.
         // 16. Set obj.[[OriginalSource]] to P.
@@ -798,16 +910,48 @@ impl RegExp {
         } else {
             let mut s = Vec::with_capacity(src.len());
             let mut buf = [0; 2];
+            // A `/` only terminates a literal outside of character classes and escapes, so
+            // it is left as is there, keeping `source` identical to the original pattern.
+            let mut in_class = false;
+            let mut escaped = false;
             for c in src.code_points() {
+                // Line terminators are written as escapes; the backslash is reused if the
+                // terminator was already escaped in the pattern.
+                let prefix: &[u16] = if escaped { &[] } else { utf16!(r"\") };
                 match c {
-                    CodePoint::Unicode('/') => s.extend_from_slice(utf16!(r"\/")),
-                    CodePoint::Unicode('\n') => s.extend_from_slice(utf16!(r"\n")),
-                    CodePoint::Unicode('\r') => s.extend_from_slice(utf16!(r"\r")),
-                    CodePoint::Unicode('\u{2028}') => s.extend_from_slice(utf16!(r"\u2028")),
-                    CodePoint::Unicode('\u{2029}') => s.extend_from_slice(utf16!(r"\u2029")),
+                    CodePoint::Unicode('/') if !escaped && !in_class => {
+                        s.extend_from_slice(utf16!(r"\/"));
+                    }
+                    CodePoint::Unicode('\n') => {
+                        s.extend_from_slice(prefix);
+                        s.extend_from_slice(utf16!("n"));
+                    }
+                    CodePoint::Unicode('\r') => {
+                        s.extend_from_slice(prefix);
+                        s.extend_from_slice(utf16!("r"));
+                    }
+                    CodePoint::Unicode('\u{2028}') => {
+                        s.extend_from_slice(prefix);
+                        s.extend_from_slice(utf16!("u2028"));
+                    }
+                    CodePoint::Unicode('\u{2029}') => {
+                        s.extend_from_slice(prefix);
+                        s.extend_from_slice(utf16!("u2029"));
+                    }
                     CodePoint::Unicode(c) => s.extend_from_slice(c.encode_utf16(&mut buf)),
                     CodePoint::UnpairedSurrogate(surr) => s.push(surr),
                 }
+
+                if escaped {
+                    escaped = false;
+                } else {
+                    match c {
+                        CodePoint::Unicode('\\') => escaped = true,
+                        CodePoint::Unicode('[') => in_class = true,
+                        CodePoint::Unicode(']') => in_class = false,
+                        _ => {}
+                    }
+                }
             }
 
             JsValue::new(js_string!(&s[..]))
@@ -1003,14 +1147,23 @@ impl RegExp {
             .to_string(context)?;
 
         // 4. Let match be ? RegExpExec(R, string).
-        let m = Self::abstract_exec(&this, arg_str, context)?;
-
         // 5. If match is not null, return true; else return false.
-        if m.is_some() {
-            Ok(JsValue::new(true))
-        } else {
-            Ok(JsValue::new(false))
+        let exec = this.get(js_string!("exec"), context)?;
+        if let Some(exec_object) = exec.as_object()
+            && JsObject::equals(
+                &exec_object,
+                &context.intrinsics().objects().regexp_prototype_exec(),
+            )
+            && let Ok(rx) = this.clone().downcast::<RegExp>()
+        {
+            // NOTE: `exec` is the built-in `%RegExp.prototype.exec%`, so we can skip
+            //       creating the result array, which is not observable.
+            return Self::abstract_builtin_test(rx, &arg_str, context).map(JsValue::new);
         }
+
+        Ok(JsValue::new(
+            Self::exec_with(&this, &exec, arg_str, context)?.is_some(),
+        ))
     }
 
     /// `RegExp.prototype.exec( string )`
@@ -1064,6 +1217,19 @@ impl RegExp {
         // 3. Let exec be ? Get(R, "exec").
         let exec = this.get(js_string!("exec"), context)?;
 
+        Self::exec_with(this, &exec, input, context)
+    }
+
+    /// Steps 4 to 6 of [`RegExpExec ( R, S )`][spec], with `exec` being the value of
+    /// the `exec` property of `R`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexpexec
+    fn exec_with(
+        this: &JsObject,
+        exec: &JsValue,
+        input: JsString,
+        context: &mut Context,
+    ) -> JsResult<Option<JsObject>> {
         // 4. If IsCallable(exec) is true, then
         if let Some(exec) = exec.as_callable() {
             // a. Let result be ? Call(exec, R, « S »).
@@ -1091,27 +1257,68 @@ impl RegExp {
         Self::abstract_builtin_exec(this, &input, context)
     }
 
-    /// `22.2.7.2 RegExpBuiltinExec ( R, S )`
+    /// Returns the storage slot of the `lastIndex` property of `this`, if it is still a
+    /// writable own data property.
+    ///
+    /// `lastIndex` is non-configurable, so for `RegExp` instances this only fails if it was
+    /// made non-writable. In that case the generic property machinery must be used, so
+    /// that writes throw.
+    fn last_index_slot(this: &JsObject) -> Option<usize> {
+        let slot = this
+            .borrow()
+            .properties()
+            .shape
+            .lookup(&js_string!("lastIndex").into())?;
+        (!slot.attributes.is_accessor_descriptor()
+            && slot.attributes.contains(SlotAttributes::WRITABLE))
+        .then_some(slot.index as usize)
+    }
+
+    /// Performs `? ToLength(? Get(R, "lastIndex"))`, reading the property storage
+    /// directly when possible.
+    fn get_last_index(this: &JsObject, context: &mut Context) -> JsResult<u64> {
+        let value = match Self::last_index_slot(this) {
+            Some(index) => this.borrow().properties().storage[index].clone(),
+            None => this.get(js_string!("lastIndex"), context)?,
+        };
+        value.to_length(context)
+    }
+
+    /// Performs `? Set(R, "lastIndex", value, true)`, writing the property storage
+    /// directly when possible.
+    fn set_last_index<V: Into<JsValue>>(
+        this: &JsObject,
+        value: V,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        match Self::last_index_slot(this) {
+            Some(index) => {
+                this.borrow_mut().properties_mut().storage[index] = value.into();
+            }
+            None => {
+                this.set(js_string!("lastIndex"), value, true, context)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Steps 1 to 16 of [`RegExpBuiltinExec ( R, S )`][spec]: runs the matcher of `rx`
+    /// on `input`, updating the `lastIndex` property of `this`.
     ///
-    /// More information:
-    ///  - [ECMAScript reference][spec]
+    /// Returns the match without building the result array.
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-regexpbuiltinexec
-    pub(crate) fn abstract_builtin_exec(
-        this: JsObject<RegExp>,
+    fn builtin_match(
+        this: &JsObject,
+        rx: &RegExp,
         input: &JsString,
         context: &mut Context,
-    ) -> JsResult<Option<JsObject>> {
-        let rx = this.borrow().data().clone();
-        let this = this.upcast();
-
+    ) -> JsResult<Option<regress::Match>> {
         // 1. Let length be the length of S.
         let length = input.len() as u64;
 
         // 2. Let lastIndex be ℝ(? ToLength(? Get(R, "lastIndex"))).
-        let mut last_index = this
-            .get(js_string!("lastIndex"), context)?
-            .to_length(context)?;
+        let mut last_index = Self::get_last_index(this, context)?;
 
         // 3. Let flags be R.[[OriginalFlags]].
         let flags = &rx.original_flags;
@@ -1122,8 +1329,7 @@ impl RegExp {
         // 5. If flags contains "y", let sticky be true; else let sticky be false.
         let sticky = flags.contains(b'y');
 
-        // 6. If flags contains "d", let hasIndices be true; else let hasIndices be false.
-        let has_indices = flags.contains(b'd');
+        // SKIP: 6. If flags contains "d", let hasIndices be true; else let hasIndices be false.
 
         // 7. If global is false and sticky is false, set lastIndex to 0.
         if !global && !sticky {
@@ -1148,7 +1354,7 @@ impl RegExp {
             // i. If global is true or sticky is true, then
             if global || sticky {
                 // 1. Perform ? Set(R, "lastIndex", +0𝔽, true).
-                this.set(js_string!("lastIndex"), 0, true, context)?;
+                Self::set_last_index(this, 0, context)?;
             }
 
             // ii. Return null.
@@ -1158,18 +1364,19 @@ impl RegExp {
         // 13.b. Let inputIndex be the index into input of the character that was obtained from element lastIndex of S.
         // 13.c. Let r be matcher(input, inputIndex).
         let r: Option<regress::Match> = match (full_unicode, input.as_str().variant()) {
-            (true | false, JsStrVariant::Latin1(_)) => {
-                // TODO: Currently regress does not support latin1 encoding.
-                let input = input.to_vec();
-
+            (true | false, JsStrVariant::Latin1(latin1)) => {
                 // NOTE: We can use the faster ucs2 variant since there will never be two byte unicode.
-                matcher.find_from_ucs2(&input, last_index as usize).next()
+                context
+                    .regexp_cache_mut()
+                    .with_widened_latin1(input, latin1, |input| {
+                        matcher.find_from_ucs2(input, last_index as usize)
+                    })
             }
             (true, JsStrVariant::Utf16(input)) => {
-                matcher.find_from_utf16(input, last_index as usize).next()
+                matcher.find_from_utf16(input, last_index as usize)
             }
             (false, JsStrVariant::Utf16(input)) => {
-                matcher.find_from_ucs2(input, last_index as usize).next()
+                matcher.find_from_ucs2(input, last_index as usize)
             }
         };
 
@@ -1181,7 +1388,7 @@ impl RegExp {
             //       13.a.i. If global is true or sticky is true, then
             if global || sticky {
                 // 1. Perform ? Set(R, "lastIndex", +0𝔽, true).
-                this.set(js_string!("lastIndex"), 0, true, context)?;
+                Self::set_last_index(this, 0, context)?;
             }
 
             // MOVE: ii. Set lastIndex to AdvanceStringIndex(S, lastIndex, fullUnicode).
@@ -1197,18 +1404,8 @@ impl RegExp {
         // SKIP: i. Assert: r is a MatchState.
         // SKIP: ii. Set matchSucceeded to true.
 
-        // NOTE: regress currently doesn't support the sticky flag so we have to emulate it.
-        if sticky && match_value.start() != last_index as usize {
-            // 1. Perform ? Set(R, "lastIndex", +0𝔽, true).
-            this.set(js_string!("lastIndex"), 0, true, context)?;
-
-            // 2. Return null.
-            return Ok(None);
-        }
-
         // 13.d.ii. Set lastIndex to AdvanceStringIndex(S, lastIndex, fullUnicode).
-        // NOTE: Calculation of last_index is done in regress.
-        last_index = match_value.start() as u64;
+        // NOTE: Calculation of last_index is done in regress, it is the start of the match.
 
         // 14. Let e be r's endIndex value.
         // 15. If fullUnicode is true, set e to GetStringIndex(S, e).
@@ -1218,9 +1415,51 @@ impl RegExp {
         // 16. If global is true or sticky is true, then
         if global || sticky {
             // a. Perform ? Set(R, "lastIndex", 𝔽(e), true).
-            this.set(js_string!("lastIndex"), e, true, context)?;
+            Self::set_last_index(this, e, context)?;
         }
 
+        Ok(Some(match_value))
+    }
+
+    /// Returns whether [`RegExpBuiltinExec ( R, S )`][spec] would return a match,
+    /// without building the result array.
+    ///
+    /// The `lastIndex` property is updated as in `RegExpBuiltinExec`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexpbuiltinexec
+    pub(crate) fn abstract_builtin_test(
+        this: JsObject<RegExp>,
+        input: &JsString,
+        context: &mut Context,
+    ) -> JsResult<bool> {
+        let rx = this.borrow().data().clone();
+        Ok(Self::builtin_match(&this.upcast(), &rx, input, context)?.is_some())
+    }
+
+    /// `22.2.7.2 RegExpBuiltinExec ( R, S )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexpbuiltinexec
+    pub(crate) fn abstract_builtin_exec(
+        this: JsObject<RegExp>,
+        input: &JsString,
+        context: &mut Context,
+    ) -> JsResult<Option<JsObject>> {
+        let rx = this.borrow().data().clone();
+        let this = this.upcast();
+
+        // NOTE: Steps 1 to 16 are done in `builtin_match`.
+        let Some(match_value) = Self::builtin_match(&this, &rx, input, context)? else {
+            return Ok(None);
+        };
+        let last_index = match_value.start() as u64;
+        let e = match_value.end();
+
+        // 6. If flags contains "d", let hasIndices be true; else let hasIndices be false.
+        let has_indices = rx.original_flags.contains(b'd');
+
         // 17. Let n be the number of elements in r's captures List.
         let n = match_value.captures.len() as u64;
         // 18. Assert: n = R.[[RegExpRecord]].[[CapturingGroupsCount]].
@@ -1258,17 +1497,68 @@ impl RegExp {
         // 29. Perform ! CreateDataPropertyOrThrow(A, "0", matchedSubstr).
         a.create_data_property_or_throw(0, matched_substr, context)?;
 
+        // 27. For each integer i such that i ≥ 1 and i ≤ n, in ascending order, do
+        let mut index_pairs = Vec::with_capacity(if has_indices { n as usize } else { 0 });
+        for i in 1..=n {
+            // a. Let captureI be ith element of r's captures List.
+            let capture = match_value.group(i as usize);
+
+            // b. If captureI is undefined, let capturedValue be undefined.
+            // c. Else if fullUnicode is true, then
+            // d. Else,
+            let captured_value = capture.clone().map_or_else(JsValue::undefined, |range| {
+                js_string!(input.get_expect(range)).into()
+            });
+
+            // e. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), capturedValue).
+            a.create_data_property_or_throw(i, captured_value, context)?;
+
+            // 22.2.7.8 MakeMatchIndicesIndexPairArray ( S, indices, groupNames, hasGroups )
+            if has_indices {
+                // b. If matchIndices is not undefined, then
+                // i. Let matchIndexPair be GetMatchIndexPair(S, matchIndices).
+                // c. Else,
+                // i. Let matchIndexPair be undefined.
+                let index_pair = capture.clone().map_or_else(JsValue::undefined, |range| {
+                    Array::create_array_from_list([range.start.into(), range.end.into()], context)
+                        .into()
+                });
+
+                // d. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), matchIndexPair).
+                indices.create_data_property_or_throw(i, index_pair.clone(), context)?;
+                index_pairs.push(index_pair);
+            }
+        }
+
         let named_groups = match_value
             .named_groups()
             .collect::<Vec<(&str, Option<Range>)>>();
 
+        // `regress` only reports the range of each named group, so the capture index of
+        // each group is recovered by replacing every participating capture `j` with the
+        // empty range `j..j` and reading the named groups again.
+        let group_indices = if has_indices && !named_groups.is_empty() {
+            let mut probe = match_value.clone();
+            for (j, capture) in probe.captures.iter_mut().enumerate() {
+                if capture.is_some() {
+                    *capture = Some(j..j);
+                }
+            }
+            probe
+                .named_groups()
+                .map(|(_, range)| range.map(|range| range.start))
+                .collect::<Vec<_>>()
+        } else {
+            Vec::new()
+        };
+
         // Combines:
         // 26. Let groupNames be a new empty List.
         // 30. If R contains any GroupName, then
         // 31. Else,
         // 33. For each integer i such that 1 ≤ i ≤ n, in ascending order, do
         #[allow(clippy::if_not_else)]
-        let (groups, group_names) = if !named_groups.clone().is_empty() {
+        let (groups, group_names) = if !named_groups.is_empty() {
             // a. Let groups be OrdinaryObjectCreate(null).
             let groups = JsObject::with_null_proto();
             let group_names = JsObject::with_null_proto();
@@ -1277,43 +1567,27 @@ impl RegExp {
             // i. Let s be the CapturingGroupName of that GroupName.
             // ii. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
             // iii. Append s to groupNames.
-            for (name, range) in named_groups {
+            for (k, (name, range)) in named_groups.into_iter().enumerate() {
                 let name = js_string!(name);
-                if let Some(range) = range {
-                    let value = input.get_expect(range.clone());
-
-                    groups.create_data_property_or_throw(name.clone(), value, context)?;
-
-                    // 22.2.7.8 MakeMatchIndicesIndexPairArray ( S, indices, groupNames, hasGroups )
-                    // a. Let matchIndices be indices[i].
-                    // b. If matchIndices is not undefined, then
-                    // i. Let matchIndexPair be GetMatchIndexPair(S, matchIndices).
-                    // d. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), matchIndexPair).
-                    group_names.create_data_property_or_throw(
-                        name.clone(),
-                        Array::create_array_from_list(
-                            [range.start.into(), range.end.into()],
-                            context,
-                        ),
-                        context,
-                    )?;
-                } else {
-                    groups.create_data_property_or_throw(
-                        name.clone(),
-                        JsValue::undefined(),
-                        context,
-                    )?;
+                let value = range.clone().map_or_else(JsValue::undefined, |range| {
+                    js_string!(input.get_expect(range)).into()
+                });
+                groups.create_data_property_or_throw(name.clone(), value, context)?;
 
-                    // 22.2.7.8 MakeMatchIndicesIndexPairArray ( S, indices, groupNames, hasGroups )
-                    // c. Else,
-                    // i. Let matchIndexPair be undefined.
-                    // d. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), matchIndexPair).
-                    group_names.create_data_property_or_throw(
-                        name,
-                        JsValue::undefined(),
-                        context,
-                    )?;
+                if !has_indices {
+                    continue;
                 }
+
+                // 22.2.7.8 MakeMatchIndicesIndexPairArray ( S, indices, groupNames, hasGroups )
+                // e. If i > 0 and groupNames[i - 1] is not undefined, then
+                // i. Assert: groups is not undefined.
+                // ii. Perform ! CreateDataPropertyOrThrow(groups, groupNames[i - 1], matchIndexPair).
+                //
+                // The group's index pair must be the same object stored in `indices[i]`.
+                let index_pair = group_indices[k]
+                    .and_then(|j| index_pairs.get(j).cloned())
+                    .unwrap_or_default();
+                group_names.create_data_property_or_throw(name, index_pair, context)?;
             }
 
             (groups.into(), group_names.into())
@@ -1329,37 +1603,6 @@ impl RegExp {
         // 32. Perform ! CreateDataPropertyOrThrow(A, "groups", groups).
         a.create_data_property_or_throw(js_string!("groups"), groups, context)?;
 
-        // 27. For each integer i such that i ≥ 1 and i ≤ n, in ascending order, do
-        for i in 1..=n {
-            // a. Let captureI be ith element of r's captures List.
-            let capture = match_value.group(i as usize);
-
-            // b. If captureI is undefined, let capturedValue be undefined.
-            // c. Else if fullUnicode is true, then
-            // d. Else,
-            let captured_value = capture.clone().map_or_else(JsValue::undefined, |range| {
-                js_string!(input.get_expect(range)).into()
-            });
-
-            // e. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), capturedValue).
-            a.create_data_property_or_throw(i, captured_value.clone(), context)?;
-
-            // 22.2.7.8 MakeMatchIndicesIndexPairArray ( S, indices, groupNames, hasGroups )
-            if has_indices {
-                // b. If matchIndices is not undefined, then
-                // i. Let matchIndexPair be GetMatchIndexPair(S, matchIndices).
-                // c. Else,
-                // i. Let matchIndexPair be undefined.
-                let indices_range = capture.map_or_else(JsValue::undefined, |range| {
-                    Array::create_array_from_list([range.start.into(), range.end.into()], context)
-                        .into()
-                });
-
-                // d. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), matchIndexPair).
-                indices.create_data_property_or_throw(i, indices_range, context)?;
-            }
-        }
-
         // 34. If hasIndices is true, then
         // a. Let indicesArray be MakeMatchIndicesIndexPairArray(S, indices, groupNames, hasGroups).
         // b. Perform ! CreateDataPropertyOrThrow(A, "indices", indicesArray).
@@ -1962,6 +2205,20 @@ impl RegExp {
             return Ok(a.into());
         }
 
+        // NOTE: If `splitter` was created by `%RegExp%` and its `exec` is the built-in
+        //       `%RegExp.prototype.exec%`, neither the result arrays of `RegExpExec` nor the
+        //       `lastIndex` updates of `splitter` are observable, so the matcher can be run
+        //       directly.
+        if JsObject::equals(
+            &constructor,
+            &context.intrinsics().constructors().regexp().constructor(),
+        ) && Self::has_builtin_exec(context)?
+            && let Ok(splitter) = splitter.clone().downcast::<RegExp>()
+        {
+            let rx = splitter.borrow().data().clone();
+            return Ok(Self::split_with_matcher(&rx, &arg_str, unicode, lim, context).into());
+        }
+
         // 17. Let p be 0.
         // 18. Let q be p.
         let mut p = 0;
@@ -2050,6 +2307,121 @@ impl RegExp {
         Ok(a.into())
     }
 
+    /// Returns whether the `exec` property of `%RegExp.prototype%` is still the built-in
+    /// `%RegExp.prototype.exec%` data property.
+    fn has_builtin_exec(context: &mut Context) -> JsResult<bool> {
+        let prototype = context.intrinsics().constructors().regexp().prototype();
+        let exec = prototype.__get_own_property__(
+            &js_string!("exec").into(),
+            &mut InternalMethodPropertyContext::new(context),
+        )?;
+        Ok(exec
+            .as_ref()
+            .and_then(PropertyDescriptor::value)
+            .and_then(JsValue::as_object)
+            .is_some_and(|exec| {
+                JsObject::equals(
+                    &exec,
+                    &context.intrinsics().objects().regexp_prototype_exec(),
+                )
+            }))
+    }
+
+    /// Steps 17 to 22 of [`RegExp.prototype [ @@split ] ( string, limit )`][spec], running the
+    /// matcher of the sticky `splitter` directly instead of calling `RegExpExec`.
+    ///
+    /// `input` must not be empty.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@split
+    fn split_with_matcher(
+        splitter: &RegExp,
+        input: &JsString,
+        unicode: bool,
+        lim: u32,
+        context: &mut Context,
+    ) -> JsObject {
+        let flags = &splitter.original_flags;
+        debug_assert!(flags.contains(b'y'), "the splitter must be sticky");
+        let full_unicode = flags.contains(b'u') || flags.contains(b'v');
+
+        // NOTE: Latin1 strings are widened once, instead of on every match.
+        let widened;
+        let code_units: &[u16] = match input.as_str().variant() {
+            JsStrVariant::Latin1(input) => {
+                widened = input.iter().copied().map(u16::from).collect::<Vec<_>>();
+                &widened
+            }
+            JsStrVariant::Utf16(input) => input,
+        };
+
+        let size = input.len();
+        let mut values = Vec::new();
+        let mut p = 0;
+        let mut q = p;
+
+        // 19. Repeat, while q < size,
+        while q < size {
+            // a. Perform ? Set(splitter, "lastIndex", 𝔽(q), true).
+            // b. Let z be ? RegExpExec(splitter, S).
+            let z = if full_unicode {
+                splitter.matcher.find_from_utf16(code_units, q)
+            } else {
+                splitter.matcher.find_from_ucs2(code_units, q)
+            };
+
+            let Some(z) = z else {
+                // c. If z is null, set q to AdvanceStringIndex(S, q, unicodeMatching).
+                q = advance_string_index(input, q as u64, unicode) as usize;
+                continue;
+            };
+
+            // d. Else,
+            //    i. Let e be ℝ(? ToLength(? Get(splitter, "lastIndex"))).
+            //    ii. Set e to min(e, size).
+            let e = z.end().min(size);
+
+            // iii. If e = p, set q to AdvanceStringIndex(S, q, unicodeMatching).
+            if e == p {
+                q = advance_string_index(input, q as u64, unicode) as usize;
+                continue;
+            }
+
+            // iv. Else,
+            //     1. Let T be the substring of S from p to q.
+            //     2. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
+            //     3. Set lengthA to lengthA + 1.
+            values.push(input.get_expect(p..q).into());
+
+            //     4. If lengthA = lim, return A.
+            if values.len() == lim as usize {
+                return Array::create_array_from_list(values, context);
+            }
+
+            //     5. Set p to e.
+            p = e;
+
+            //     6-9. Append each capture of z to A, returning A if lengthA = lim.
+            for capture in z.captures {
+                values.push(
+                    capture.map_or_else(JsValue::undefined, |range| input.get_expect(range).into()),
+                );
+                if values.len() == lim as usize {
+                    return Array::create_array_from_list(values, context);
+                }
+            }
+
+            //     10. Set q to p.
+            q = p;
+        }
+
+        // 20. Let T be the substring of S from p to size.
+        // 21. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
+        values.push(input.get_expect(p..size).into());
+
+        // 22. Return A.
+        Array::create_array_from_list(values, context)
+    }
+
     /// [`RegExp.prototype.compile ( pattern, flags )`][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype.compile
@@ -24,6 +24,16 @@ use crate::{
 use boa_gc::{Finalize, Trace};
 use regexp::{RegExp, advance_string_index};
 
+/// The state of the closure of a `RegExp String Iterator`, as in [`GeneratorState`][spec].
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-generator-instances
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IteratorState {
+    Suspended,
+    Executing,
+    Completed,
+}
+
 /// The `RegExp String Iterator` object.
 ///
 /// More information:
@@ -36,7 +46,8 @@ pub(crate) struct RegExpStringIterator {
     string: JsString,
     global: bool,
     unicode: bool,
-    completed: bool,
+    #[unsafe_ignore_trace]
+    state: IteratorState,
 }
 
 impl IntrinsicObject for RegExpStringIterator {
@@ -65,7 +76,7 @@ impl RegExpStringIterator {
             string,
             global,
             unicode,
-            completed: false,
+            state: IteratorState::Suspended,
         }
     }
 
@@ -115,65 +126,122 @@ impl RegExpStringIterator {
     /// [spec]: https://tc39.es/ecma262/#sec-%regexpstringiteratorprototype%.next
     pub(crate) fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         let object = this.as_object();
+        let object = object.as_ref().filter(|o| o.is::<Self>()).ok_or_else(|| {
+            JsNativeError::typ().with_message("`this` is not a RegExpStringIterator")
+        })?;
+
+        // NOTE: The iterator must not stay borrowed while calling into user code, since `exec`
+        //       and `lastIndex` accessors are observable and could call `next` again.
+        //
+        // `GeneratorValidate ( generator, generatorBrand )`
+        // <https://tc39.es/ecma262/#sec-generatorvalidate>
+        let (matcher, string, global, unicode) = {
+            let mut iterator = object
+                .downcast_mut::<Self>()
+                .expect("already checked the object type");
+
+            match iterator.state {
+                // 6. If state is executing, throw a TypeError exception.
+                IteratorState::Executing => {
+                    return Err(JsNativeError::typ()
+                        .with_message("RegExp String Iterator is already executing")
+                        .into());
+                }
+                // `GeneratorResume ( generator, value, generatorBrand )`
+                // 2. If state is completed, return CreateIterResultObject(undefined, true).
+                IteratorState::Completed => {
+                    return Ok(create_iter_result_object(
+                        JsValue::undefined(),
+                        true,
+                        context,
+                    ));
+                }
+                // 7. Set generator.[[GeneratorState]] to executing.
+                IteratorState::Suspended => iterator.state = IteratorState::Executing,
+            }
+            (
+                iterator.matcher.clone(),
+                iterator.string.clone(),
+                iterator.global,
+                iterator.unicode,
+            )
+        };
+
+        let result = Self::step(&matcher, &string, global, unicode, context);
+
+        // NOTE: The closure is completed once it returns or throws.
         let mut iterator = object
-            .as_ref()
-            .and_then(JsObject::downcast_mut::<Self>)
-            .ok_or_else(|| {
-                JsNativeError::typ().with_message("`this` is not a RegExpStringIterator")
-            })?;
-        if iterator.completed {
-            return Ok(create_iter_result_object(
-                JsValue::undefined(),
-                true,
-                context,
-            ));
-        }
+            .downcast_mut::<Self>()
+            .expect("already checked the object type");
+        let (value, done) = match result {
+            Ok(Some((m, returned))) => {
+                iterator.state = if returned {
+                    IteratorState::Completed
+                } else {
+                    IteratorState::Suspended
+                };
+                (m.into(), false)
+            }
+            Ok(None) => {
+                iterator.state = IteratorState::Completed;
+                (JsValue::undefined(), true)
+            }
+            Err(error) => {
+                iterator.state = IteratorState::Completed;
+                return Err(error);
+            }
+        };
+        drop(iterator);
 
+        Ok(create_iter_result_object(value, done, context))
+    }
+
+    /// Runs the closure of [`CreateRegExpStringIterator`][spec] until its next `Yield`.
+    ///
+    /// Returns the yielded match and whether the closure returned after yielding it, or `None`
+    /// if it returned without yielding.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createregexpstringiterator
+    fn step(
+        matcher: &JsObject,
+        string: &JsString,
+        global: bool,
+        unicode: bool,
+        context: &mut Context,
+    ) -> JsResult<Option<(JsObject, bool)>> {
         // TODO: This is the code that should be created as a closure in create_regexp_string_iterator.
 
         // i. Let match be ? RegExpExec(R, S).
-        let m = RegExp::abstract_exec(&iterator.matcher, iterator.string.clone(), context)?;
-
-        if let Some(m) = m {
-            // iii. If global is false, then
-            if !iterator.global {
-                // 1. Perform ? Yield(match).
-                // 2. Return undefined.
-                iterator.completed = true;
-                return Ok(create_iter_result_object(m.into(), false, context));
-            }
+        let Some(m) = RegExp::abstract_exec(matcher, string.clone(), context)? else {
+            // ii. If match is null, return undefined.
+            return Ok(None);
+        };
+
+        // iii. If global is false, then
+        if !global {
+            // 1. Perform ? Yield(match).
+            // 2. Return undefined.
+            return Ok(Some((m, true)));
+        }
 
-            // iv. Let matchStr be ? ToString(? Get(match, "0")).
-            let m_str = m.get(0, context)?.to_string(context)?;
-
-            // v. If matchStr is the empty String, then
-            if m_str.is_empty() {
-                // 1. Let thisIndex be ℝ(? ToLength(? Get(R, "lastIndex"))).
-                let this_index = iterator
-                    .matcher
-                    .get(js_string!("lastIndex"), context)?
-                    .to_length(context)?;
-
-                // 2. Let nextIndex be ! AdvanceStringIndex(S, thisIndex, fullUnicode).
-                let next_index =
-                    advance_string_index(&iterator.string, this_index, iterator.unicode);
-
-                // 3. Perform ? Set(R, "lastIndex", 𝔽(nextIndex), true).
-                iterator
-                    .matcher
-                    .set(js_string!("lastIndex"), next_index, true, context)?;
-            }
+        // iv. Let matchStr be ? ToString(? Get(match, "0")).
+        let m_str = m.get(0, context)?.to_string(context)?;
 
-            // vi. Perform ? Yield(match).
-            Ok(create_iter_result_object(m.into(), false, context))
-        } else {
-            // ii. If match is null, return undefined.
-            iterator.completed = true;
-            Ok(create_iter_result_object(
-                JsValue::undefined(),
-                true,
-                context,
-            ))
+        // v. If matchStr is the empty String, then
+        if m_str.is_empty() {
+            // 1. Let thisIndex be ℝ(? ToLength(? Get(R, "lastIndex"))).
+            let this_index = matcher
+                .get(js_string!("lastIndex"), context)?
+                .to_length(context)?;
+
+            // 2. Let nextIndex be ! AdvanceStringIndex(S, thisIndex, fullUnicode).
+            let next_index = advance_string_index(string, this_index, unicode);
+
+            // 3. Perform ? Set(R, "lastIndex", 𝔽(nextIndex), true).
+            matcher.set(js_string!("lastIndex"), next_index, true, context)?;
         }
+
+        // vi. Perform ? Yield(match).
+        Ok(Some((m, false)))
     }
 }
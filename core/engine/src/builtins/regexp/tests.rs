@@ -1,6 +1,7 @@
+use super::cache::MAX_RETAINED_LATIN1_WIDENED;
 use crate::{
-    JsNativeErrorKind, JsValue, TestAction, js_string, native_function::NativeFunctionObject,
-    run_test_actions,
+    JsNativeErrorKind, JsValue, Source, TestAction, js_string,
+    native_function::NativeFunctionObject, run_test_actions,
 };
 use boa_macros::js_str;
 use indoc::indoc;
@@ -97,6 +98,13 @@ fn escape() {
             r"RegExp.escape('Buy it. use it. break it. fix it.')",
             js_str!("\\x42uy\\x20it\\.\\x20use\\x20it\\.\\x20break\\x20it\\.\\x20fix\\x20it\\."),
         ),
+        TestAction::assert_eq(r"RegExp.escape('a.b*c')", js_str!("\\x61\\.b\\*c")),
+        TestAction::assert_eq(r"RegExp.escape('.b*c')", js_str!("\\.b\\*c")),
+        TestAction::assert(r"new RegExp(RegExp.escape('a.b*c')).test('xa.b*cx')"),
+        TestAction::assert(r"!new RegExp(RegExp.escape('a.b*c')).test('aXbbbc')"),
+        TestAction::assert(
+            r"new RegExp('^' + RegExp.escape('1+1=2 [ok] $5 (a|b) \\ ?') + '$', 'u').test('1+1=2 [ok] $5 (a|b) \\ ?')",
+        ),
         TestAction::assert_eq(r"RegExp.escape('(*.*)')", js_str!("\\(\\*\\.\\*\\)")),
         TestAction::assert_eq(r"RegExp.escape('｡^･ｪ･^｡')", js_str!("｡\\^･ｪ･\\^｡")),
         TestAction::assert_eq(
@@ -175,6 +183,22 @@ fn to_string() {
         TestAction::assert_eq(r"/,\;/.toString()", js_string!(r"/,\;/")),
     ]);
 }
+
+#[test]
+fn source() {
+    run_test_actions([
+        TestAction::assert_eq("new RegExp('').source", js_str!("(?:)")),
+        TestAction::assert_eq("/[/]/.source", js_str!("[/]")),
+        TestAction::assert_eq("new RegExp('[/]').source", js_str!("[/]")),
+        TestAction::assert_eq("new RegExp('/').source", js_string!(r"\/")),
+        TestAction::assert_eq(r"/a\/b/.source", js_string!(r"a\/b")),
+        TestAction::assert_eq(r"/[\]/]\//.source", js_string!(r"[\]/]\/")),
+        TestAction::assert_eq(r"new RegExp('a\nb').source", js_string!(r"a\nb")),
+        TestAction::assert_eq(r"new RegExp('\\\n').source", js_string!(r"\n")),
+        // The escaped source reparses to an equivalent pattern.
+        TestAction::assert(r"new RegExp(new RegExp('a\n/[/]').source).test('a\n//')"),
+    ]);
+}
 #[test]
 fn search() {
     const ERROR: &str = "RegExp.prototype[Symbol.search] method called on incompatible value";
@@ -262,3 +286,410 @@ fn regexp_no_panic_on_empty_class_quantifier() {
     // It should return null without panicking.
     run_test_actions([TestAction::assert_eq("/[]*1/u.exec()", JsValue::null())]);
 }
+
+#[test]
+fn exec_large_latin1_input() {
+    // Matching a Latin1 string must give the same results as matching the same
+    // text stored as UTF-16.
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var filler = "café ".repeat(1 << 19);
+            var latin1 = filler + "needle=ÿ42;" + filler;
+            var utf16 = (latin1 + "☃").slice(0, -1);
+            var re = /needle=(.)(\d+);/g;
+            var a = re.exec(latin1);
+            re.lastIndex = 0;
+            var b = re.exec(utf16);
+        "#}),
+        TestAction::assert("latin1.length > 4000000"),
+        TestAction::assert("a.index === filler.length"),
+        TestAction::assert("a.index === b.index && a[0] === b[0]"),
+        TestAction::assert_eq("a[1]", js_str!("\u{ff}")),
+        TestAction::assert_eq("a[2]", js_str!("42")),
+        TestAction::assert("re.lastIndex === a.index + a[0].length"),
+        TestAction::assert("latin1.search(/\\u00ff/) === filler.length + 7"),
+        TestAction::assert("/caf\\u00e9 $/.exec(latin1).index === latin1.length - 5"),
+        // The buffer used to widen the input is not kept at its full size.
+        TestAction::inspect_context(|ctx| {
+            assert!(
+                ctx.regexp_cache_mut().widened_latin1_capacity() <= MAX_RETAINED_LATIN1_WIDENED
+            );
+        }),
+        // Repeated matches against the same short string only widen it once.
+        TestAction::run("/x/.exec('another string')"),
+        TestAction::inspect_context(|ctx| {
+            let before = ctx.regexp_cache_mut().widenings();
+            ctx.eval(Source::from_bytes(indoc! {r#"
+                var short = "café ".repeat(1000);
+                var words = /caf(.)/g;
+                var count = 0;
+                while (words.exec(short) !== null) {
+                    count++;
+                }
+            "#}))
+                .unwrap();
+            assert_eq!(ctx.regexp_cache_mut().widenings() - before, 1);
+        }),
+        TestAction::assert_eq("count", 1000),
+    ]);
+}
+
+#[test]
+fn exec_latin1_after_widened_buffer_shrinks() {
+    // Growing the widened buffer can leave its capacity over the retained limit even if
+    // the input itself is short enough to be kept, which must not leave a stale cache.
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            /x/.test("a".repeat(20000) + "é");
+            var input = "b".repeat(30000) + "é";
+            var re = /b+/g;
+            var first = re.exec(input);
+            re.lastIndex = 0;
+            var second = re.exec(input);
+        "#}),
+        TestAction::assert("input.length <= 32768"),
+        TestAction::assert_eq("first[0].length", 30000),
+        TestAction::assert("second !== null && second[0] === first[0]"),
+    ]);
+}
+
+#[test]
+fn matcher_cache() {
+    run_test_actions([TestAction::inspect_context(|ctx| {
+        let before = ctx.regexp_cache_mut().compilations();
+        ctx.eval(Source::from_bytes(indoc! {r#"
+            for (let i = 0; i < 1000; i++) {
+                /a+b/g.test("aab");
+                new RegExp("a+b", "g");
+            }
+        "#}))
+            .unwrap();
+        assert_eq!(ctx.regexp_cache_mut().compilations() - before, 1);
+
+        // Different flags need a different matcher.
+        ctx.eval(Source::from_bytes(r#"new RegExp("a+b", "gi")"#))
+            .unwrap();
+        assert_eq!(ctx.regexp_cache_mut().compilations() - before, 2);
+
+        // The cache is bounded.
+        ctx.eval(Source::from_bytes(
+            "for (let i = 0; i < 1000; i++) new RegExp('x' + i);",
+        ))
+        .unwrap();
+        assert!(ctx.regexp_cache_mut().len() <= 64);
+        assert!(
+            ctx.eval(Source::from_bytes("/a+b/g.test('ab')"))
+                .unwrap()
+                .to_boolean()
+        );
+    })]);
+}
+
+#[test]
+fn unicode_property_escapes() {
+    run_test_actions([
+        TestAction::assert(r"/\p{L}/u.test('a')"),
+        TestAction::assert(r"!/\p{L}/u.test('1')"),
+        TestAction::assert(r"/\P{L}/u.test('1')"),
+        TestAction::assert(r"!/\P{L}/u.test('a')"),
+        TestAction::assert(r"/^\p{Letter}+$/u.test('héllo')"),
+        TestAction::assert(r"/\p{Script=Greek}/u.test('α')"),
+        TestAction::assert(r"/[\p{Lu}\d]/v.test('A')"),
+        // Without the `u` flag, `\p` is an identity escape.
+        TestAction::assert(r"/\p{L}/.test('p{L}')"),
+        TestAction::assert_native_error(
+            r"new RegExp('\\p{Foo}', 'u')",
+            JsNativeErrorKind::Syntax,
+            r"invalid Unicode property escape: \p{Foo}",
+        ),
+        TestAction::assert_native_error(
+            r"new RegExp('[a\\P{Script=Foo}]', 'u')",
+            JsNativeErrorKind::Syntax,
+            r"invalid Unicode property escape: \P{Script=Foo}",
+        ),
+    ]);
+}
+
+#[test]
+fn test_matches_exec() {
+    // `test` must behave exactly like `exec(...) !== null`, including updates to `lastIndex`.
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var mismatches = [];
+            var patterns = [
+                /a/, /a/g, /a/y, /a/gy, /(?:)/, /(?:)/g, /(?:)/y, /^$/gm, /b*/g, /\u{1F600}/gu,
+            ];
+            var inputs = ["", "a", "aa", "baab", "xyz", "\u{1F600}\u{1F600}", "aa\nb"];
+            for (var pattern of patterns) {
+                for (var input of inputs) {
+                    for (var start of [0, 1, 2, 10]) {
+                        var a = new RegExp(pattern);
+                        var b = new RegExp(pattern);
+                        a.lastIndex = start;
+                        b.lastIndex = start;
+                        for (var i = 0; i < 4; i++) {
+                            var tested = a.test(input);
+                            var executed = b.exec(input) !== null;
+                            if (tested !== executed || a.lastIndex !== b.lastIndex) {
+                                mismatches.push(pattern + " " + JSON.stringify(input) + " " + start);
+                            }
+                        }
+                    }
+                }
+            }
+        "#}),
+        TestAction::assert_eq("mismatches.length", 0),
+        // An overridden `exec` is still called.
+        TestAction::run(indoc! {r#"
+            var re = /a/;
+            var calls = 0;
+            re.exec = function () { calls++; return null; };
+        "#}),
+        TestAction::assert("!re.test('a')"),
+        TestAction::assert_eq("calls", 1),
+        TestAction::assert_eq(
+            "RegExp.prototype.exec === Object.getOwnPropertyDescriptor(RegExp.prototype, 'exec').value",
+            true,
+        ),
+        TestAction::assert_eq("RegExp.prototype.exec.length", 1),
+        TestAction::assert_eq("RegExp.prototype.exec.name", js_str!("exec")),
+    ]);
+}
+
+#[test]
+fn sticky_matches_only_at_last_index() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var re = /foo/y;
+            re.lastIndex = 1;
+        "#}),
+        // The pattern appears later in the input, but not at `lastIndex`.
+        TestAction::assert_eq("re.exec('xxfoo')", JsValue::null()),
+        TestAction::assert_eq("re.lastIndex", 0),
+        TestAction::run("re.lastIndex = 2"),
+        TestAction::assert_eq("re.exec('xxfoo')[0]", js_str!("foo")),
+        TestAction::assert_eq("re.lastIndex", 5),
+        // Consecutive sticky matches advance `lastIndex`.
+        TestAction::run(indoc! {r#"
+            var digits = /(?<d>\d)/y;
+            var found = [];
+            var m;
+            while ((m = digits.exec("123a4")) !== null) {
+                found.push(m.groups.d + "@" + digits.lastIndex + ":" + m.length);
+            }
+        "#}),
+        TestAction::assert_eq("found.join()", js_str!("1@1:2,2@2:2,3@3:2")),
+        TestAction::assert_eq("digits.lastIndex", 0),
+        // Sticky patterns with alternatives, empty matches and lookbehinds.
+        TestAction::assert_eq("'ab'.replace(/a|b/gy, 'x')", js_str!("xx")),
+        TestAction::assert_eq("'bab'.replace(/a|b/gy, 'x')", js_str!("xxx")),
+        TestAction::assert_eq("'cab'.replace(/a|b/gy, 'x')", js_str!("cab")),
+        TestAction::assert_eq("'aaa'.match(/a*?/gy).length", 4),
+        TestAction::run("var lb = /(?<=a)b/y; lb.lastIndex = 1;"),
+        TestAction::assert("lb.test('ab')"),
+        TestAction::assert_eq("'a,b'.split(/,/y).join('|')", js_str!("a|b")),
+        // Anchoring doesn't add a capture group, so `\1` is still an octal escape.
+        TestAction::assert_eq("/a\\1/y.exec('a\\x01')[0]", js_str!("a\u{1}")),
+        TestAction::assert_eq("/a\\1/y.exec('a\\x01').length", 1),
+        TestAction::assert_eq("/(a)\\1/y.exec('aa')[0]", js_str!("aa")),
+        // Patterns matching the empty string at `lastIndex` still match.
+        TestAction::run("var opt = /a?/y; opt.lastIndex = 1;"),
+        TestAction::assert_eq("opt.exec('ab')[0]", js_str!("")),
+        TestAction::assert_eq("opt.lastIndex", 1),
+        TestAction::run("var end = /$/y; end.lastIndex = 2;"),
+        TestAction::assert_eq("end.exec('ab').index", 2),
+        TestAction::run("var none = /x/y; none.lastIndex = 2;"),
+        TestAction::assert_eq("none.exec('ab')", JsValue::null()),
+        // Patterns that would only be valid once wrapped are still rejected.
+        TestAction::assert_native_error(
+            "new RegExp('a)|(?:b', 'y')",
+            JsNativeErrorKind::Syntax,
+            "failed to create matcher: Unbalanced parenthesis",
+        ),
+    ]);
+}
+
+#[test]
+fn last_index_property_access() {
+    run_test_actions([
+        // `lastIndex` values are still coerced with `ToLength`.
+        TestAction::run(indoc! {r#"
+            var re = /a/g;
+            var coerced = 0;
+            re.lastIndex = { valueOf() { coerced++; return 2; } };
+        "#}),
+        TestAction::assert_eq("re.exec('aaaa').index", 2),
+        TestAction::assert_eq("coerced", 1),
+        TestAction::assert_eq("re.lastIndex", 3),
+        // `lastIndex` is non-configurable, so it cannot be turned into an accessor.
+        TestAction::assert_native_error(
+            "Object.defineProperty(re, 'lastIndex', { get() { return 0; } })",
+            JsNativeErrorKind::Type,
+            "cannot redefine property: lastIndex",
+        ),
+        TestAction::assert_eq("re.exec('aaaa').index", 3),
+        TestAction::assert_eq("re.lastIndex", 4),
+        // A non-writable `lastIndex` is still honored, and writing to it throws.
+        TestAction::run(indoc! {r#"
+            var frozen = /a/g;
+            frozen.lastIndex = 1;
+            Object.defineProperty(frozen, 'lastIndex', { writable: false });
+        "#}),
+        TestAction::assert_native_error(
+            "frozen.exec('aa')",
+            JsNativeErrorKind::Type,
+            "cannot set non-writable property: lastIndex",
+        ),
+        TestAction::assert_eq("frozen.lastIndex", 1),
+        TestAction::assert_native_error(
+            "frozen.test('b')",
+            JsNativeErrorKind::Type,
+            "cannot set non-writable property: lastIndex",
+        ),
+        // Non-global, non-sticky patterns never write `lastIndex`.
+        TestAction::run(indoc! {r#"
+            var plain = /a/;
+            Object.defineProperty(plain, 'lastIndex', { value: 5, writable: false });
+        "#}),
+        TestAction::assert_eq("plain.exec('ba').index", 1),
+        TestAction::assert_eq("plain.lastIndex", 5),
+    ]);
+}
+
+#[test]
+fn match_all_iterator_is_lazy() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let calls = 0;
+            let matcher;
+            class CountingRegExp extends RegExp {
+                exec(s) {
+                    calls++;
+                    matcher = this;
+                    return super.exec(s);
+                }
+            }
+            const it = "a".repeat(10000).matchAll(new CountingRegExp("a", "g"));
+        "#}),
+        TestAction::assert_eq("calls", 0),
+        TestAction::assert_eq("it.next().value.index", 0),
+        TestAction::assert_eq("it.next().value.index", 1),
+        TestAction::assert_eq("calls", 2),
+        // `lastIndex` changes on the matcher between steps are observed.
+        TestAction::run("matcher.lastIndex = 0;"),
+        TestAction::assert_eq("it.next().value.index", 0),
+        TestAction::assert_eq("calls", 3),
+    ]);
+}
+
+#[test]
+fn match_all_iterator_reentrant_next() {
+    // The iterator behaves like a generator, so `next` throws while it is executing.
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let it;
+            let inner;
+            let reentered = false;
+            class ReentrantRegExp extends RegExp {
+                exec(s) {
+                    if (!reentered) {
+                        reentered = true;
+                        try {
+                            it.next();
+                        } catch (e) {
+                            inner = e;
+                        }
+                    }
+                    return super.exec(s);
+                }
+            }
+            it = "aaa".matchAll(new ReentrantRegExp("a", "g"));
+            const outer = it.next();
+        "#}),
+        TestAction::assert("inner instanceof TypeError"),
+        TestAction::assert_eq("outer.value.index", 0),
+        TestAction::assert_eq("it.next().value.index", 1),
+        TestAction::assert_eq("it.next().value.index", 2),
+        TestAction::assert("it.next().done"),
+        // An exception thrown by `exec` completes the iterator.
+        TestAction::run(indoc! {r#"
+            class ThrowingRegExp extends RegExp {
+                exec(s) {
+                    throw new Error("exec");
+                }
+            }
+            const throwing = "aaa".matchAll(new ThrowingRegExp("a", "g"));
+        "#}),
+        TestAction::assert_native_error("throwing.next()", JsNativeErrorKind::Error, "exec"),
+        TestAction::assert("throwing.next().done"),
+    ]);
+}
+
+#[test]
+fn has_indices_named_groups() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const m = /(?<a>x)?(?<b>y)(z)?(?<c>w)?/d.exec("yw");
+            const d = /(?:(?<a>x)|(?<a>y))/d.exec("y");
+            const n = /(?<a>(x))/d.exec("x");
+            const o = /((?<a>x))/d.exec("x");
+            const e = /()(?<b>)/d.exec("");
+        "#}),
+        TestAction::assert("m.indices[1] === undefined"),
+        TestAction::assert("'a' in m.indices.groups"),
+        TestAction::assert("m.indices.groups.a === undefined"),
+        TestAction::assert("m.indices[2] === m.indices.groups.b"),
+        TestAction::assert("m.indices[4] === m.indices.groups.c"),
+        TestAction::assert_eq("m.indices.groups.c.join()", js_str!("1,2")),
+        TestAction::assert("d.indices[1] === undefined"),
+        TestAction::assert("d.indices[2] === d.indices.groups.a"),
+        TestAction::assert("n.indices[1] === n.indices.groups.a"),
+        TestAction::assert("n.indices[2] !== n.indices.groups.a"),
+        TestAction::assert("o.indices[2] === o.indices.groups.a"),
+        TestAction::assert("o.indices[1] !== o.indices.groups.a"),
+        TestAction::assert("e.indices[2] === e.indices.groups.b"),
+        TestAction::assert("e.indices[1] !== e.indices.groups.b"),
+        TestAction::assert("/(?<a>x)/.exec('x').indices === undefined"),
+    ]);
+}
+
+#[test]
+fn split_matches_generic_path() {
+    // Subclass instances go through `RegExpExec` instead of running the matcher directly.
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            class Generic extends RegExp {}
+            function check(re, string, limit) {
+                const fast = string.split(re, limit);
+                const slow = string.split(new Generic(re), limit);
+                return JSON.stringify(fast) === JSON.stringify(slow) ? "ok" : JSON.stringify(fast);
+            }
+        "#}),
+        TestAction::assert_eq("check(/,/, 'a,b,,c')", js_str!("ok")),
+        TestAction::assert_eq("check(/,/, 'a,b,,c', 2)", js_str!("ok")),
+        TestAction::assert_eq("check(/(,)/, 'a,b,,c')", js_str!("ok")),
+        TestAction::assert_eq("check(/(-)|(,)/, 'a-b,c')", js_str!("ok")),
+        TestAction::assert_eq("check(/(-)|(,)/, 'a-b,c', 3)", js_str!("ok")),
+        TestAction::assert_eq("check(/(?:)/, 'abc')", js_str!("ok")),
+        TestAction::assert_eq("check(/x*/, 'axbxxc')", js_str!("ok")),
+        TestAction::assert_eq("check(/(?:)/u, 'a\\u{1F600}b')", js_str!("ok")),
+        TestAction::assert_eq("'a,b'.split(/(,)|(x)/).length", 4),
+        TestAction::assert_eq("'a\\u{1F600}b'.split(/(?:)/u).length", 3),
+    ]);
+}
+
+#[test]
+fn split_uses_modified_exec() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let calls = 0;
+            const exec = RegExp.prototype.exec;
+            RegExp.prototype.exec = function (s) {
+                calls++;
+                return exec.call(this, s);
+            };
+        "#}),
+        TestAction::assert_eq("'a,b'.split(/,/).join('|')", js_str!("a|b")),
+        TestAction::assert_eq("calls", 3),
+    ]);
+}
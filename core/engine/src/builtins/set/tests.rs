@@ -1,4 +1,4 @@
-use crate::{JsNativeErrorKind, TestAction, run_test_actions};
+use crate::{JsNativeErrorKind, TestAction, js_str, run_test_actions};
 use indoc::indoc;
 
 #[test]
@@ -223,6 +223,54 @@ fn difference_empty() {
     ]);
 }
 
+#[test]
+fn intersection_dispatches_on_size() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let small = new Set([500]);
+            let large = new Set(Array.from({ length: 1000 }, (_, i) => 999 - i));
+            let calls = [];
+            function setLike(set) {
+                return {
+                    size: set.size,
+                    has(v) { calls.push("has"); return set.has(v); },
+                    keys() { calls.push("keys"); return set.keys(); },
+                };
+            }
+        "#}),
+        TestAction::assert_with_op("small.intersection(large)", |v, _| {
+            v.display().to_string() == "Set { 500 }"
+        }),
+        TestAction::assert_with_op("large.intersection(small)", |v, _| {
+            v.display().to_string() == "Set { 500 }"
+        }),
+        // The smaller set is iterated, checking membership in the larger one.
+        TestAction::run("small.intersection(setLike(large))"),
+        TestAction::assert_eq("calls.join()", js_str!("has")),
+        TestAction::run("calls = []; large.intersection(setLike(small))"),
+        TestAction::assert_eq("calls.join()", js_str!("keys")),
+    ]);
+}
+
+#[test]
+fn intersection_preserves_this_order() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let setA = new Set([5, 3, 1, 4, 2]);
+            let setB = new Set(Array.from({ length: 1000 }, (_, i) => i));
+        "#}),
+        TestAction::assert_with_op("setA.intersection(setB)", |v, _| {
+            v.display().to_string() == "Set { 5, 3, 1, 4, 2 }"
+        }),
+        TestAction::assert_with_op("setA.difference(setB)", |v, _| {
+            v.display().to_string() == "Set(0)"
+        }),
+        TestAction::assert_with_op("new Set([7, 1000, 3, 1001]).difference(setB)", |v, _| {
+            v.display().to_string() == "Set { 1000, 1001 }"
+        }),
+    ]);
+}
+
 #[test]
 fn intersection() {
     run_test_actions([
@@ -398,6 +446,33 @@ fn union() {
     ]);
 }
 
+#[test]
+fn union_and_is_disjoint_from_set_like() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            const calls = [];
+            const setLike = {
+                size: 2,
+                has(value) { calls.push("has"); return value === 5 || value === 6; },
+                keys() { calls.push("keys"); return [5, 6][Symbol.iterator](); },
+            };
+            "#}),
+        TestAction::assert("arrayEquals([...new Set([1, 2]).union(new Set([2, 3]))], [1, 2, 3])"),
+        TestAction::assert("new Set([1, 2]).isDisjointFrom(new Set([3, 4]))"),
+        TestAction::assert("!new Set([1, 2]).isDisjointFrom(new Set([2, 3]))"),
+        // Set-like arguments are read through `size`, `has` and `keys`, not iterated.
+        TestAction::assert("arrayEquals([...new Set([1, 2]).union(setLike)], [1, 2, 5, 6])"),
+        TestAction::assert("arrayEquals(calls, ['keys'])"),
+        TestAction::assert("new Set([1, 2]).isDisjointFrom(setLike)"),
+        TestAction::assert_native_error(
+            "new Set([1]).union([1, 2])",
+            JsNativeErrorKind::Type,
+            "size is undefined",
+        ),
+    ]);
+}
+
 #[test]
 fn union_same_set() {
     run_test_actions([
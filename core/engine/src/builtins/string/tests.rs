@@ -217,6 +217,29 @@ fn replace_with_tenth_capture_group() {
     )]);
 }
 
+#[test]
+fn replace_with_named_capture_groups() {
+    run_test_actions([
+        TestAction::assert_eq(
+            r"'2024-01'.replace(/(?<y>\d+)-(?<m>\d+)/, '$<m>/$<y>')",
+            js_str!("01/2024"),
+        ),
+        // Unknown group names are replaced with the empty string.
+        TestAction::assert_eq(
+            r"'2024-01'.replace(/(?<y>\d+)-(?<m>\d+)/, '[$<z>]')",
+            js_str!("[]"),
+        ),
+        // Without named groups, `$<` is not a substitution.
+        TestAction::assert_eq(r"'2024-01'.replace(/(\d+)-(\d+)/, '$<m>')", js_str!("$<m>")),
+        // An unterminated group name is not a substitution.
+        TestAction::assert_eq(r"'ab'.replace(/(?<x>a)/, '$<x')", js_str!("$<xb")),
+        TestAction::assert_eq(
+            r"'2024-01'.replace(/(?<n>\d+)/g, '<$<n>>')",
+            js_str!("<2024>-<01>"),
+        ),
+    ]);
+}
+
 #[test]
 fn replace_substitutions() {
     run_test_actions([
@@ -975,3 +998,48 @@ fn match_with_overridden_exec() {
         js_str!("fake"),
     )]);
 }
+
+#[test]
+fn well_formed() {
+    run_test_actions([
+        TestAction::assert("'abc'.isWellFormed()"),
+        TestAction::assert("'a\\uD83D\\uDE00b'.isWellFormed()"),
+        TestAction::assert("!'a\\uD83Db'.isWellFormed()"),
+        TestAction::assert("!'a\\uDE00b'.isWellFormed()"),
+        TestAction::assert_eq("'a\\uD83Db'.toWellFormed()", js_string!("a\u{FFFD}b")),
+        TestAction::assert_eq(
+            "'\\uDE00\\uD83D'.toWellFormed()",
+            js_string!("\u{FFFD}\u{FFFD}"),
+        ),
+        TestAction::assert_eq(
+            "'a\\uD83D\\uDE00b'.toWellFormed()",
+            js_string!("a\u{1F600}b"),
+        ),
+        TestAction::assert("'a\\uD83Db'.toWellFormed().isWellFormed()"),
+        TestAction::assert_native_error(
+            "String.prototype.isWellFormed.call(null)",
+            JsNativeErrorKind::Type,
+            "cannot convert null or undefined to Object",
+        ),
+    ]);
+}
+
+#[test]
+fn at() {
+    run_test_actions([
+        TestAction::assert_eq("'abc'.at(0)", js_str!("a")),
+        TestAction::assert_eq("'abc'.at(-1)", js_str!("c")),
+        TestAction::assert_eq("'abc'.at(-2)", js_str!("b")),
+        TestAction::assert_eq("'abc'.at(3)", JsValue::undefined()),
+        TestAction::assert_eq("'abc'.at(-4)", JsValue::undefined()),
+        TestAction::assert_eq("''.at(0)", JsValue::undefined()),
+        // Indexing is done by UTF-16 code units.
+        TestAction::assert_eq("'\\u{1F600}'.at(-1)", js_string!(&[0xDE00u16][..])),
+        TestAction::assert_eq("String.prototype.at.call(123, -1)", js_str!("3")),
+        TestAction::assert_native_error(
+            "String.prototype.at.call(undefined, 0)",
+            JsNativeErrorKind::Type,
+            "cannot convert null or undefined to Object",
+        ),
+    ]);
+}
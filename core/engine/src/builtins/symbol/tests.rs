@@ -32,3 +32,24 @@ fn symbol_access() {
         TestAction::assert_eq("x['Symbol(Hello)']", JsValue::undefined()),
     ]);
 }
+
+#[test]
+fn dispose_well_known_symbols() {
+    run_test_actions([
+        TestAction::assert_eq("typeof Symbol.dispose", js_str!("symbol")),
+        TestAction::assert_eq("typeof Symbol.asyncDispose", js_str!("symbol")),
+        TestAction::assert("Symbol.dispose === Symbol.dispose"),
+        TestAction::assert("Symbol.asyncDispose === Symbol.asyncDispose"),
+        TestAction::assert("Symbol.dispose !== Symbol.asyncDispose"),
+        TestAction::assert_eq("Symbol.dispose.description", js_str!("Symbol.dispose")),
+        TestAction::assert_eq(
+            "Symbol.asyncDispose.description",
+            js_str!("Symbol.asyncDispose"),
+        ),
+        TestAction::assert_eq("Symbol.keyFor(Symbol.dispose)", JsValue::undefined()),
+        TestAction::assert(indoc! {r#"
+                var desc = Object.getOwnPropertyDescriptor(Symbol, "dispose");
+                !desc.writable && !desc.enumerable && !desc.configurable
+            "#}),
+    ]);
+}
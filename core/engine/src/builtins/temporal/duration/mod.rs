@@ -24,6 +24,7 @@ use temporal_rs::{
     Duration as InnerDuration,
     options::{RoundingIncrement, RoundingMode, RoundingOptions, ToStringRoundingOptions, Unit},
     partial::PartialDuration,
+    provider::NeverProvider,
 };
 
 #[cfg(test)]
@@ -195,6 +196,7 @@ impl IntrinsicObject for Duration {
             .method(Self::subtract, js_string!("subtract"), 1)
             .method(Self::round, js_string!("round"), 1)
             .method(Self::total, js_string!("total"), 1)
+            .method(Self::equals, js_string!("equals"), 1)
             .method(Self::to_string, js_string!("toString"), 0)
             .method(Self::to_locale_string, js_string!("toLocaleString"), 0)
             .method(Self::to_json, js_string!("toJSON"), 0)
@@ -209,7 +211,7 @@ impl IntrinsicObject for Duration {
 
 impl BuiltInConstructor for Duration {
     const CONSTRUCTOR_ARGUMENTS: usize = 0;
-    const PROTOTYPE_STORAGE_SLOTS: usize = 36;
+    const PROTOTYPE_STORAGE_SLOTS: usize = 37;
     const CONSTRUCTOR_STORAGE_SLOTS: usize = 2;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -962,11 +964,19 @@ impl Duration {
         // NOTE: execute step 21 earlier before initial values are shadowed.
         // 21. If smallestUnitPresent is false and largestUnitPresent is false, then
 
-        let rounded_duration = duration.inner.round_with_provider(
-            options,
-            relative_to,
-            context.timezone_provider(),
-        )?;
+        // NOTE: Without `relativeTo`, only time units (and days of 24 hours) can be rounded,
+        //       which never requires time zone data. Skip the context provider in that case,
+        //       so embedders that don't configure one can still round durations.
+        let rounded_duration = match relative_to {
+            None => duration
+                .inner
+                .round_with_provider(options, None, &NeverProvider::default())?,
+            relative_to => duration.inner.round_with_provider(
+                options,
+                relative_to,
+                context.timezone_provider(),
+            )?,
+        };
         create_temporal_duration(rounded_duration, None, context).map(Into::into)
     }
 
@@ -1048,6 +1058,38 @@ impl Duration {
             .into())
     }
 
+    /// `Temporal.Duration.prototype.equals ( other )`
+    ///
+    /// Returns whether `other` has the same field values as this duration. Durations
+    /// that only have the same total length, like `PT1H` and `PT60M`, are not equal.
+    ///
+    /// More information:
+    ///
+    /// - [`temporal_rs` documentation][temporal_rs-docs]
+    ///
+    /// [temporal_rs-docs]: https://docs.rs/temporal_rs/latest/temporal_rs/struct.Duration.html#impl-PartialEq-for-Duration
+    pub(crate) fn equals(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let object = this.as_object();
+        let duration = object
+            .as_ref()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("this value must be a Duration object.")
+            })?;
+
+        // 3. Set other to ? ToTemporalDuration(other).
+        let other = to_temporal_duration(args.get_or_undefined(0), context)?;
+
+        // 4. Return whether all the fields of duration and other are equal.
+        Ok((*duration.inner == other).into())
+    }
+
     /// 7.3.22 `Temporal.Duration.prototype.toString ( [ options ] )`
     ///
     /// More information:
@@ -1112,7 +1154,10 @@ impl Duration {
         Ok(JsString::from(result).into())
     }
 
-    /// 7.3.24 `Temporal.Duration.prototype.toLocaleString ( )`
+    /// 7.3.24 `Temporal.Duration.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    ///
+    /// ICU4X does not provide unit formatting yet, so this returns the ISO 8601 string. With
+    /// the `intl` feature, the `locales` and `options` arguments are still validated.
     ///
     /// More information:
     ///
@@ -1121,10 +1166,14 @@ impl Duration {
     ///
     /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration/toLocaleString
+    #[allow(
+        unused_variables,
+        reason = "`args` and `context` are used when the `intl` feature is enabled"
+    )]
     pub(crate) fn to_locale_string(
         this: &JsValue,
-        _: &[JsValue],
-        _: &mut Context,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
         // TODO: Update for ECMA-402 compliance
         let object = this.as_object();
@@ -1135,6 +1184,14 @@ impl Duration {
                 JsNativeError::typ().with_message("this value must be a Duration object.")
             })?;
 
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::locale::canonicalize_locale_list;
+
+            canonicalize_locale_list(args.get_or_undefined(0), context)?;
+            get_options_object(args.get_or_undefined(1))?;
+        }
+
         let result = duration
             .inner
             .as_temporal_string(ToStringRoundingOptions::default())?;
@@ -1,4 +1,6 @@
-use crate::{TestAction, run_test_actions};
+use crate::{TestAction, context::ContextBuilder, run_test_actions, run_test_actions_with};
+use boa_macros::js_str;
+use temporal_rs::provider::NeverProvider;
 
 #[test]
 fn duration_constructor() {
@@ -70,3 +72,124 @@ fn basic() {
         TestAction::assert_eq("dur.nanoseconds", 0),
     ]);
 }
+
+#[test]
+fn duration_equals() {
+    run_test_actions([
+        TestAction::run("let dur = Temporal.Duration.from('P1Y2M3DT4H5M6S')"),
+        TestAction::assert("dur.equals(dur)"),
+        TestAction::assert("dur.equals('P1Y2M3DT4H5M6S')"),
+        TestAction::assert(
+            "dur.equals({ years: 1, months: 2, days: 3, hours: 4, minutes: 5, seconds: 6 })",
+        ),
+        TestAction::assert("!dur.equals(dur.negated())"),
+        // Equal totals with different representations are not equal.
+        TestAction::assert("!Temporal.Duration.from('PT1H').equals('PT60M')"),
+        TestAction::assert(
+            "Temporal.Duration.from('PT1H').equals(new Temporal.Duration(0, 0, 0, 0, 1))",
+        ),
+        TestAction::assert("new Temporal.Duration().equals('PT0S')"),
+        TestAction::assert_native_error(
+            "dur.equals(1)",
+            crate::JsNativeErrorKind::Type,
+            "Invalid TemporalDurationLike value.",
+        ),
+    ]);
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn duration_to_locale_string_intl() {
+    run_test_actions([
+        TestAction::run("let dur = Temporal.Duration.from('PT1H30M')"),
+        TestAction::assert_eq("dur.toLocaleString('en')", js_str!("PT1H30M")),
+        TestAction::assert_eq(
+            "dur.toLocaleString('de', { style: 'digital' })",
+            js_str!("PT1H30M"),
+        ),
+        TestAction::assert_native_error(
+            "dur.toLocaleString('not a locale')",
+            crate::JsNativeErrorKind::Range,
+            "locale is not a structurally valid language tag",
+        ),
+        TestAction::assert_native_error(
+            "dur.toLocaleString('en', 1)",
+            crate::JsNativeErrorKind::Type,
+            "GetOptionsObject: provided options is not an object",
+        ),
+    ]);
+}
+
+#[test]
+fn duration_round_without_time_zone_data() {
+    // `NeverProvider` panics if it is ever consulted.
+    let context = &mut ContextBuilder::default()
+        .timezone_provider(NeverProvider::default())
+        .build()
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run("let dur = Temporal.Duration.from('PT90M')"),
+            TestAction::assert_eq("dur.round('hours').toString()", js_str!("PT2H")),
+            TestAction::assert_eq(
+                "dur.round({ largestUnit: 'hours', smallestUnit: 'minutes' }).toString()",
+                js_str!("PT1H30M"),
+            ),
+            TestAction::assert_eq(
+                "Temporal.Duration.from('PT25H').round({ largestUnit: 'days' }).toString()",
+                js_str!("P1DT1H"),
+            ),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn duration_to_string_subsecond_precision() {
+    run_test_actions([
+        TestAction::run("const dur = Temporal.Duration.from('PT1.23456789S')"),
+        TestAction::assert_eq(
+            "dur.toString({ fractionalSecondDigits: 2 })",
+            js_str!("PT1.23S"),
+        ),
+        TestAction::assert_eq(
+            "dur.toString({ fractionalSecondDigits: 4 })",
+            js_str!("PT1.2345S"),
+        ),
+        TestAction::assert_eq(
+            "dur.toString({ fractionalSecondDigits: 9 })",
+            js_str!("PT1.234567890S"),
+        ),
+        TestAction::assert_eq(
+            "dur.toString({ fractionalSecondDigits: 'auto' })",
+            js_str!("PT1.23456789S"),
+        ),
+        TestAction::assert_eq(
+            "Temporal.Duration.from('PT1.5S').toString({ fractionalSecondDigits: 3 })",
+            js_str!("PT1.500S"),
+        ),
+        TestAction::assert_eq("dur.toString({ smallestUnit: 'second' })", js_str!("PT1S")),
+        TestAction::assert_eq(
+            "dur.toString({ smallestUnit: 'millisecond' })",
+            js_str!("PT1.234S"),
+        ),
+        TestAction::assert_eq(
+            "dur.toString({ smallestUnit: 'millisecond', roundingMode: 'ceil' })",
+            js_str!("PT1.235S"),
+        ),
+        TestAction::assert_eq(
+            "dur.toString({ smallestUnit: 'second', roundingMode: 'halfExpand' })",
+            js_str!("PT1S"),
+        ),
+        TestAction::assert_eq(
+            "dur.toString({ smallestUnit: 'second', roundingMode: 'ceil' })",
+            js_str!("PT2S"),
+        ),
+        // `smallestUnit` takes precedence over `fractionalSecondDigits`.
+        TestAction::assert_eq(
+            "dur.toString({ smallestUnit: 'millisecond', fractionalSecondDigits: 6 })",
+            js_str!("PT1.234S"),
+        ),
+    ]);
+}
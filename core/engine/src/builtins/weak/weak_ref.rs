@@ -167,6 +167,22 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn weak_ref_strongly_held() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var obj = {a: 5};
+                var ptr = new WeakRef(obj);
+            "#}),
+            TestAction::inspect_context(|context| {
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+            }),
+            TestAction::assert("ptr.deref() === obj"),
+            TestAction::assert_eq("ptr.deref().a", 5),
+        ]);
+    }
+
     #[test]
     fn weak_ref_no_new() {
         run_test_actions([TestAction::assert_native_error(
@@ -99,6 +99,9 @@ impl ByteCompiler<'_> {
             }
             Statement::With(with) => self.compile_with(with, use_expr),
             Statement::Empty | Statement::Debugger => {}
+            Statement::Error => {
+                unreachable!("error statements are only produced when parsing with error recovery")
+            }
         }
     }
 
@@ -1,15 +1,24 @@
-use std::{cell::OnceCell, fmt::Debug};
+use std::{
+    cell::{OnceCell, RefCell},
+    fmt::Debug,
+    rc::Rc,
+};
 
 use icu_casemap::CaseMapper;
-use icu_locale::{LocaleCanonicalizer, LocaleExpander};
+use icu_list::{ListFormatter, options::ListLength};
+use icu_locale::{Locale, LocaleCanonicalizer, LocaleExpander};
 use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
 use icu_provider::prelude::*;
+use rustc_hash::FxHashMap;
 use serde::Deserialize;
 use thiserror::Error;
 use yoke::Yokeable;
 use zerofrom::ZeroFrom;
 
-use crate::{JsError, JsNativeError, builtins::string::StringNormalizers};
+use crate::{
+    JsError, JsNativeError,
+    builtins::{intl::list_format::ListFormatType, string::StringNormalizers},
+};
 
 /// Error thrown when the engine cannot initialize the ICU4X utilities from a data provider.
 #[derive(Debug, Error, Copy, Clone)]
@@ -40,6 +49,7 @@ pub(crate) struct IntlProvider {
     locale_expander: OnceCell<LocaleExpander>,
     string_normalizers: OnceCell<StringNormalizers>,
     case_mapper: OnceCell<CaseMapper>,
+    list_formatters: RefCell<FxHashMap<(Locale, ListFormatType, ListLength), Rc<ListFormatter>>>,
 }
 
 impl<M> DataProvider<M> for IntlProvider
@@ -91,6 +101,7 @@ impl IntlProvider {
             locale_expander: OnceCell::new(),
             string_normalizers: OnceCell::new(),
             case_mapper: OnceCell::new(),
+            list_formatters: RefCell::default(),
             inner_provider: Box::new(provider),
         }
     }
@@ -142,6 +153,26 @@ impl IntlProvider {
         Ok(self.case_mapper.get_or_init(|| cm))
     }
 
+    /// Gets the [`ListFormatter`] for lists of type `typ` and length `style` in `locale`,
+    /// calling `create` to construct it if it isn't cached yet.
+    pub(crate) fn list_formatter<E>(
+        &self,
+        locale: &Locale,
+        typ: ListFormatType,
+        style: ListLength,
+        create: impl FnOnce() -> Result<ListFormatter, E>,
+    ) -> Result<Rc<ListFormatter>, E> {
+        let key = (locale.clone(), typ, style);
+        if let Some(formatter) = self.list_formatters.borrow().get(&key) {
+            return Ok(formatter.clone());
+        }
+        let formatter = Rc::new(create()?);
+        self.list_formatters
+            .borrow_mut()
+            .insert(key, formatter.clone());
+        Ok(formatter)
+    }
+
     /// Gets the inner provider.
     pub(crate) fn erased_provider(&self) -> &dyn DynamicDryDataProvider<BufferMarker> {
         &self.inner_provider
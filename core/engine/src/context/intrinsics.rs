@@ -146,6 +146,7 @@ pub struct StandardConstructors {
     eval_error: StandardConstructor,
     uri_error: StandardConstructor,
     aggregate_error: StandardConstructor,
+    suppressed_error: StandardConstructor,
     map: StandardConstructor,
     set: StandardConstructor,
     typed_array: StandardConstructor,
@@ -172,6 +173,8 @@ pub struct StandardConstructors {
     weak_set: StandardConstructor,
     iterator: StandardConstructor,
     finalization_registry: StandardConstructor,
+    disposable_stack: StandardConstructor,
+    async_disposable_stack: StandardConstructor,
     #[cfg(feature = "intl")]
     collator: StandardConstructor,
     #[cfg(feature = "intl")]
@@ -242,6 +245,7 @@ impl Default for StandardConstructors {
             eval_error: StandardConstructor::default(),
             uri_error: StandardConstructor::default(),
             aggregate_error: StandardConstructor::default(),
+            suppressed_error: StandardConstructor::default(),
             map: StandardConstructor::default(),
             set: StandardConstructor::default(),
             typed_array: StandardConstructor::default(),
@@ -268,6 +272,8 @@ impl Default for StandardConstructors {
             weak_set: StandardConstructor::default(),
             iterator: StandardConstructor::default(),
             finalization_registry: StandardConstructor::default(),
+            disposable_stack: StandardConstructor::default(),
+            async_disposable_stack: StandardConstructor::default(),
             #[cfg(feature = "intl")]
             collator: StandardConstructor::default(),
             #[cfg(feature = "intl")]
@@ -569,6 +575,18 @@ impl StandardConstructors {
         &self.aggregate_error
     }
 
+    /// Returns the `SuppressedError` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-constructor
+    #[inline]
+    #[must_use]
+    pub const fn suppressed_error(&self) -> &StandardConstructor {
+        &self.suppressed_error
+    }
+
     /// Returns the `Map` constructor.
     ///
     /// More information:
@@ -870,6 +888,30 @@ impl StandardConstructors {
         &self.finalization_registry
     }
 
+    /// Returns the `DisposableStack` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-constructor
+    #[inline]
+    #[must_use]
+    pub const fn disposable_stack(&self) -> &StandardConstructor {
+        &self.disposable_stack
+    }
+
+    /// Returns the `AsyncDisposableStack` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-constructor
+    #[inline]
+    #[must_use]
+    pub const fn async_disposable_stack(&self) -> &StandardConstructor {
+        &self.async_disposable_stack
+    }
+
     /// Returns the `Intl.Collator` constructor.
     ///
     /// More information:
@@ -1100,6 +1142,9 @@ pub struct IntrinsicObjects {
     /// [`%Array.prototype.toString%`](https://tc39.es/ecma262/#sec-array.prototype.tostring)
     array_prototype_to_string: JsFunction,
 
+    /// [`%RegExp.prototype.exec%`](https://tc39.es/ecma262/#sec-regexp.prototype.exec)
+    regexp_prototype_exec: JsFunction,
+
     /// Cached iterator prototypes.
     iterator_prototypes: IteratorPrototypes,
 
@@ -1172,6 +1217,7 @@ impl IntrinsicObjects {
             throw_type_error: JsFunction::empty_intrinsic_function(false),
             array_prototype_values: JsFunction::empty_intrinsic_function(false),
             array_prototype_to_string: JsFunction::empty_intrinsic_function(false),
+            regexp_prototype_exec: JsFunction::empty_intrinsic_function(false),
             iterator_prototypes: IteratorPrototypes::default(),
             generator: JsObject::with_null_proto(),
             async_generator: JsObject::with_null_proto(),
@@ -1224,6 +1270,15 @@ impl IntrinsicObjects {
         self.array_prototype_to_string.clone()
     }
 
+    /// Gets the [`%RegExp.prototype.exec%`][spec] intrinsic function.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype.exec
+    #[inline]
+    #[must_use]
+    pub fn regexp_prototype_exec(&self) -> JsFunction {
+        self.regexp_prototype_exec.clone()
+    }
+
     /// Gets the cached iterator prototypes.
     #[inline]
     #[must_use]
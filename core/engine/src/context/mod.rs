@@ -15,6 +15,8 @@ use temporal_rs::provider::TimeZoneProvider;
 #[cfg(any(feature = "temporal", feature = "intl"))]
 use timezone_provider::experimental_tzif::ZeroCompiledTzdbProvider;
 
+use crate::builtins::promise::{RejectionTracker, notify_rejections};
+use crate::builtins::regexp::RegExpCache;
 use crate::job::Job;
 use crate::js_error;
 use crate::module::DynModuleLoader;
@@ -130,6 +132,12 @@ pub struct Context {
     /// Unique identifier for each parser instance used during the context lifetime.
     parser_identifier: u32,
 
+    /// Cache of compiled `RegExp` matchers.
+    regexp_cache: RegExpCache,
+
+    /// Rejected promises without handlers, reported after each microtask checkpoint.
+    rejection_tracker: RejectionTracker,
+
     data: HostDefined<dyn Any>,
 }
 
@@ -495,9 +503,69 @@ impl Context {
     }
 
     /// Runs all the jobs with the provided job executor.
+    ///
+    /// With the default [`SimpleJobExecutor`], this drains promise jobs, async jobs and timeout
+    /// jobs, including any job enqueued while running them, and only returns once no work
+    /// remains. Active interval jobs keep it running; cancel them, use
+    /// [`Context::run_jobs_to_completion`] with a budget or use
+    /// [`SimpleJobExecutor::get_cancellation_token`] to stop earlier.
+    ///
+    /// Once the jobs are drained, the callbacks registered with
+    /// [`Context::on_unhandled_rejection`] and [`Context::on_rejection_handled`] are notified,
+    /// in case the job executor does not notify them itself after running promise jobs.
     #[inline]
     pub fn run_jobs(&mut self) -> JsResult<()> {
-        self.job_executor().run_jobs(self)
+        let result = self.job_executor().run_jobs(self);
+        notify_rejections(self);
+        result
+    }
+
+    /// Runs jobs with the provided job executor until none remain or `budget` jobs have run,
+    /// returning whether work remained.
+    ///
+    /// With the default [`SimpleJobExecutor`], each promise job, timer callback and generic job
+    /// counts against the budget, and jobs left once it is spent stay queued for the next call.
+    /// This lets embedders drain pending promises and timers without being stuck on an interval
+    /// or a job that keeps re-queuing itself. Job executors without budget support run all the
+    /// jobs and return `false`.
+    ///
+    /// Like [`Context::run_jobs`], the rejection callbacks are notified once the jobs have run,
+    /// unless work remained.
+    #[inline]
+    pub fn run_jobs_to_completion(&mut self, budget: usize) -> JsResult<bool> {
+        let result = self.job_executor().run_jobs_with_budget(self, budget);
+        if matches!(result, Ok(false)) {
+            notify_rejections(self);
+        }
+        result
+    }
+
+    /// Registers a callback invoked with the rejection reason of each promise that is rejected
+    /// without a handler, and still has no handler once the pending promise jobs are drained.
+    ///
+    /// [`SimpleJobExecutor`] notifies it after each microtask checkpoint, and
+    /// [`Context::run_jobs`] once all the jobs are drained.
+    ///
+    /// This replaces any previously registered callback.
+    #[inline]
+    pub fn on_unhandled_rejection<F>(&mut self, callback: F)
+    where
+        F: Fn(JsValue, &mut Context) + 'static,
+    {
+        self.rejection_tracker.set_on_unhandled(Rc::new(callback));
+    }
+
+    /// Registers a callback invoked with the rejection reason of a promise previously reported
+    /// to the [`Context::on_unhandled_rejection`] callback, once a handler is attached to it.
+    ///
+    /// Like unhandled rejections, this is notified once the pending promise jobs are drained.
+    /// This replaces any previously registered callback.
+    #[inline]
+    pub fn on_rejection_handled<F>(&mut self, callback: F)
+    where
+        F: Fn(JsValue, &mut Context) + 'static,
+    {
+        self.rejection_tracker.set_on_handled(Rc::new(callback));
     }
 
     /// Abstract operation [`ClearKeptObjects`][clear].
@@ -648,6 +716,16 @@ impl Context {
         self.module_loader.clone()
     }
 
+    /// Gets the cache of compiled `RegExp` matchers.
+    pub(crate) fn regexp_cache_mut(&mut self) -> &mut RegExpCache {
+        &mut self.regexp_cache
+    }
+
+    /// Gets the tracker of unhandled promise rejections.
+    pub(crate) fn rejection_tracker_mut(&mut self) -> &mut RejectionTracker {
+        &mut self.rejection_tracker
+    }
+
     /// Swaps the currently active realm with `realm`.
     pub(crate) fn swap_realm(&mut self, realm: &mut Realm) {
         std::mem::swap(&mut self.vm.frame_mut().realm, realm);
@@ -1259,6 +1337,8 @@ impl ContextBuilder {
             optimizer_options: OptimizerOptions::OPTIMIZE_ALL,
             root_shape,
             parser_identifier: 0,
+            regexp_cache: RegExpCache::default(),
+            rejection_tracker: RejectionTracker::default(),
             can_block: self.can_block,
             data: HostDefined::default(),
         };
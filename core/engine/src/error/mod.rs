@@ -31,9 +31,9 @@ use thiserror::Error;
 ///
 /// # Native Errors
 ///
-/// The only native error that is not buildable using this macro is
-/// `AggregateError`, which requires multiple error objects available at
-/// construction.
+/// The only native errors that are not buildable using this macro are
+/// `AggregateError` and `SuppressedError`, which require other error objects
+/// available at construction.
 ///
 /// [`InternalError`][mdn] is non-standard and unsupported in Boa.
 ///
@@ -533,8 +533,9 @@ impl JsError {
     ///
     /// This operation should be considered a lossy conversion, since it
     /// won't store any additional properties of the opaque
-    /// error, other than `message`, `cause` and `errors` (in the case of
-    /// `AggregateError`s). If you cannot afford a lossy conversion, clone
+    /// error, other than `message`, `cause`, `errors` (in the case of
+    /// `AggregateError`s) and `error` and `suppressed` (in the case of
+    /// `SuppressedError`s). If you cannot afford a lossy conversion, clone
     /// the object before calling [`from_opaque`][JsError::from_opaque]
     /// to preserve its original properties.
     ///
@@ -634,6 +635,17 @@ impl JsError {
 
                         JsNativeErrorKind::Aggregate(error_list)
                     }
+                    ErrorKind::Suppressed => {
+                        let error = try_get_property(js_string!("error"), "error", context)?
+                            .unwrap_or_default();
+                        let suppressed =
+                            try_get_property(js_string!("suppressed"), "suppressed", context)?
+                                .unwrap_or_default();
+                        JsNativeErrorKind::Suppressed {
+                            error: Box::new(Self::from_opaque(error)),
+                            suppressed: Box::new(Self::from_opaque(suppressed)),
+                        }
+                    }
                 };
 
                 let realm = try_get_property(js_string!("constructor"), "constructor", context)?
@@ -787,6 +799,12 @@ impl JsError {
             JsNativeErrorKind::Eval => JsErasedNativeErrorKind::Eval,
             JsNativeErrorKind::Range => JsErasedNativeErrorKind::Range,
             JsNativeErrorKind::Reference => JsErasedNativeErrorKind::Reference,
+            JsNativeErrorKind::Suppressed { error, suppressed } => {
+                JsErasedNativeErrorKind::Suppressed {
+                    error: Box::new(error.into_erased(context)),
+                    suppressed: Box::new(suppressed.into_erased(context)),
+                }
+            }
             JsNativeErrorKind::Syntax => JsErasedNativeErrorKind::Syntax,
             JsNativeErrorKind::Type => JsErasedNativeErrorKind::Type,
             JsNativeErrorKind::Uri => JsErasedNativeErrorKind::Uri,
@@ -1030,6 +1048,41 @@ impl JsNativeError {
         matches!(self.kind, JsNativeErrorKind::Aggregate(_))
     }
 
+    /// Creates a new `JsNativeError` of kind `SuppressedError` from the error that was thrown
+    /// and the error it suppressed, with empty `message` and undefined `cause`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{JsNativeError, JsNativeErrorKind};
+    /// let error = JsNativeError::suppressed(
+    ///     JsNativeError::typ().into(),
+    ///     JsNativeError::range().into(),
+    /// );
+    ///
+    /// assert!(matches!(error.kind(), JsNativeErrorKind::Suppressed { .. }));
+    /// ```
+    #[must_use]
+    #[inline]
+    #[cfg_attr(feature = "native-backtrace", track_caller)]
+    pub fn suppressed(error: JsError, suppressed: JsError) -> Self {
+        Self::new(
+            JsNativeErrorKind::Suppressed {
+                error: Box::new(error),
+                suppressed: Box::new(suppressed),
+            },
+            Cow::Borrowed(""),
+            None,
+        )
+    }
+
+    /// Check if it's a [`JsNativeErrorKind::Suppressed`].
+    #[must_use]
+    #[inline]
+    pub const fn is_suppressed(&self) -> bool {
+        matches!(self.kind, JsNativeErrorKind::Suppressed { .. })
+    }
+
     /// Creates a new `JsNativeError` of kind `Error`, with empty `message` and undefined `cause`.
     ///
     /// # Examples
@@ -1335,6 +1388,10 @@ impl JsNativeError {
                 constructors.reference_error().prototype(),
                 ErrorKind::Reference,
             ),
+            JsNativeErrorKind::Suppressed { .. } => (
+                constructors.suppressed_error().prototype(),
+                ErrorKind::Suppressed,
+            ),
             JsNativeErrorKind::Syntax => {
                 (constructors.syntax_error().prototype(), ErrorKind::Syntax)
             }
@@ -1365,25 +1422,39 @@ impl JsNativeError {
             );
         }
 
-        if let JsNativeErrorKind::Aggregate(errors) = kind {
-            let errors = errors
-                .into_iter()
-                .map(|e| {
-                    e.into_opaque(context)
-                        .expect("engine errors cannot be the cause of another error")
-                })
-                .collect::<Vec<_>>();
-            let errors = Array::create_array_from_list(errors, context);
-            o.define_property_or_throw(
-                js_string!("errors"),
-                PropertyDescriptor::builder()
-                    .configurable(true)
-                    .enumerable(false)
-                    .writable(true)
-                    .value(errors),
-                context,
-            )
-            .expect("The spec guarantees this succeeds for a newly created object ");
+        match kind {
+            JsNativeErrorKind::Aggregate(errors) => {
+                let errors = errors
+                    .into_iter()
+                    .map(|e| {
+                        e.into_opaque(context)
+                            .expect("engine errors cannot be the cause of another error")
+                    })
+                    .collect::<Vec<_>>();
+                let errors = Array::create_array_from_list(errors, context);
+                o.define_property_or_throw(
+                    js_string!("errors"),
+                    PropertyDescriptor::builder()
+                        .configurable(true)
+                        .enumerable(false)
+                        .writable(true)
+                        .value(errors),
+                    context,
+                )
+                .expect("The spec guarantees this succeeds for a newly created object ");
+            }
+            JsNativeErrorKind::Suppressed { error, suppressed } => {
+                for (key, error) in [
+                    (js_string!("error"), error),
+                    (js_string!("suppressed"), suppressed),
+                ] {
+                    let error = error
+                        .into_opaque(context)
+                        .expect("engine errors cannot be the cause of another error");
+                    o.create_non_enumerable_data_property_or_throw(key, error, context);
+                }
+            }
+            _ => {}
         }
         o
     }
@@ -1459,6 +1530,20 @@ pub enum JsNativeErrorKind {
     /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-referenceerror
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ReferenceError
     Reference,
+    /// An error thrown while disposing a resource, wrapping the error it suppressed.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    /// - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SuppressedError
+    Suppressed {
+        /// The error that was thrown.
+        error: Box<JsError>,
+        /// The error that was suppressed by `error`.
+        suppressed: Box<JsError>,
+    },
     /// An error representing an invalid syntax in the Javascript language.
     ///
     /// More information:
@@ -1498,6 +1583,10 @@ unsafe impl Trace for JsNativeErrorKind {
         mark,
         match &this {
             Self::Aggregate(errors) => mark(errors),
+            Self::Suppressed { error, suppressed } => {
+                mark(error);
+                mark(suppressed);
+            }
             Self::Error
             | Self::Eval
             | Self::Range
@@ -1518,6 +1607,7 @@ impl PartialEq<ErrorKind> for JsNativeErrorKind {
                 | (Self::Eval, ErrorKind::Eval)
                 | (Self::Range, ErrorKind::Range)
                 | (Self::Reference, ErrorKind::Reference)
+                | (Self::Suppressed { .. }, ErrorKind::Suppressed)
                 | (Self::Syntax, ErrorKind::Syntax)
                 | (Self::Type, ErrorKind::Type)
                 | (Self::Uri, ErrorKind::Uri)
@@ -1533,6 +1623,7 @@ impl fmt::Display for JsNativeErrorKind {
             Self::Eval => "EvalError",
             Self::Range => "RangeError",
             Self::Reference => "ReferenceError",
+            Self::Suppressed { .. } => "SuppressedError",
             Self::Syntax => "SyntaxError",
             Self::Type => "TypeError",
             Self::Uri => "UriError",
@@ -1738,6 +1829,20 @@ pub enum JsErasedNativeErrorKind {
     /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-referenceerror
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ReferenceError
     Reference,
+    /// An error thrown while disposing a resource, wrapping the error it suppressed.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    /// - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SuppressedError
+    Suppressed {
+        /// The error that was thrown.
+        error: Box<JsErasedError>,
+        /// The error that was suppressed by `error`.
+        suppressed: Box<JsErasedError>,
+    },
     /// An error representing an invalid syntax in the Javascript language.
     ///
     /// More information:
@@ -1783,6 +1888,7 @@ impl fmt::Display for JsErasedNativeErrorKind {
             Self::Eval => "EvalError",
             Self::Range => "RangeError",
             Self::Reference => "ReferenceError",
+            Self::Suppressed { .. } => "SuppressedError",
             Self::Syntax => "SyntaxError",
             Self::Type => "TypeError",
             Self::Uri => "UriError",
@@ -30,6 +30,7 @@
 //! [JobCallback]: https://tc39.es/ecma262/#sec-jobcallback-records
 //! [`Gc`]: boa_gc::Gc
 
+use crate::builtins::promise::notify_rejections;
 use crate::context::time::{JsDuration, JsInstant};
 use crate::sys::time;
 use crate::{
@@ -275,6 +276,8 @@ pub struct TimeoutJob {
     job: Option<NativeJob>,
     /// Signals if the timeout job was cancelled.
     cancellation_token: CancellationToken,
+    /// Whether the job keeps the executor running until it is due.
+    keep_alive: bool,
 }
 
 impl Drop for TimeoutJob {
@@ -291,9 +294,22 @@ impl TimeoutJob {
             timeout: JsDuration::from_millis(timeout_in_millis),
             job: Some(job),
             cancellation_token: CancellationToken::new(),
+            keep_alive: true,
         }
     }
 
+    /// Sets whether this job keeps the executor running until it is due, which it does by
+    /// default.
+    ///
+    /// Jobs that don't keep it running behave like an unreferenced timer in Node.js: they
+    /// still run if they are due while the executor runs other jobs, but the executor does
+    /// not wait for them once they are the only jobs left.
+    #[must_use]
+    pub fn with_keep_alive(mut self, keep_alive: bool) -> Self {
+        self.keep_alive = keep_alive;
+        self
+    }
+
     /// Creates a new `TimeoutJob` from a closure and a timeout as [`std::time::Duration`].
     #[must_use]
     pub fn from_duration<F>(f: F, timeout: impl Into<JsDuration>) -> Self
@@ -341,6 +357,13 @@ impl TimeoutJob {
         self.cancellation_token.revoked()
     }
 
+    /// Returns `true` if this job keeps the executor running until it is due.
+    #[inline]
+    #[must_use]
+    pub fn keeps_alive(&self) -> bool {
+        self.keep_alive
+    }
+
     /// Returns the [`CancellationToken`] for this timeout job.
     #[must_use]
     pub fn cancellation_token(&self) -> &CancellationToken {
@@ -804,6 +827,20 @@ pub trait JobExecutor: Any {
     /// Runs all jobs in the executor.
     fn run_jobs(self: Rc<Self>, context: &mut Context) -> JsResult<()>;
 
+    /// Runs jobs in the executor until none remain or `budget` jobs have run, returning
+    /// whether work remained.
+    ///
+    /// By default forwards to [`JobExecutor::run_jobs`], ignoring the budget. Implementors
+    /// able to stop between jobs should override this.
+    fn run_jobs_with_budget(
+        self: Rc<Self>,
+        context: &mut Context,
+        _budget: usize,
+    ) -> JsResult<bool> {
+        self.run_jobs(context)?;
+        Ok(false)
+    }
+
     /// Asynchronously runs all jobs in the executor.
     ///
     /// By default forwards to [`JobExecutor::run_jobs`]. Implementors using async should override this
@@ -858,6 +895,13 @@ impl ClockJob {
             ClockJob::Interval(i) => i.cancelled(),
         }
     }
+
+    fn keeps_alive(&self) -> bool {
+        match self {
+            ClockJob::Timeout(t) => t.keeps_alive(),
+            ClockJob::Interval(_) => true,
+        }
+    }
 }
 
 /// A simple FIFO executor that bails on the first error.
@@ -874,6 +918,7 @@ pub struct SimpleJobExecutor {
     clock_jobs: RefCell<BTreeMap<JsInstant, Vec<ClockJob>>>,
     generic_jobs: RefCell<VecDeque<GenericJob>>,
     stop: Arc<AtomicBool>,
+    budget: Cell<Option<usize>>,
 }
 
 impl SimpleJobExecutor {
@@ -906,11 +951,57 @@ impl SimpleJobExecutor {
         Arc::clone(&self.stop)
     }
 
+    /// Runs promise jobs until the queue is empty, including any job enqueued
+    /// while running them.
+    ///
+    /// This is the equivalent of an HTML [microtask checkpoint][spec], and must be
+    /// done before running the next timer so that microtasks always run first.
+    ///
+    /// Returns early once the executor is stopped through its cancellation token, so a
+    /// microtask that keeps re-queuing itself can still be cancelled.
+    ///
+    /// Once the microtasks are drained, the context is notified about the promises that
+    /// were rejected without a handler, like the checkpoint does in HTML. This is skipped
+    /// if jobs remain, since they may still add a handler.
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/webappapis.html#perform-a-microtask-checkpoint
+    fn run_promise_jobs(&self, context: &mut Context) -> JsResult<()> {
+        while !self.stop.load(Ordering::Relaxed) && !self.exhausted() {
+            let Some(job) = self.promise_jobs.borrow_mut().pop_front() else {
+                break;
+            };
+            self.spend();
+            job.call(context)?;
+        }
+        if self.promise_jobs.borrow().is_empty() {
+            notify_rejections(context);
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the budget of [`JobExecutor::run_jobs_with_budget`] is spent.
+    fn exhausted(&self) -> bool {
+        self.budget.get() == Some(0)
+    }
+
+    /// Counts a job against the budget of [`JobExecutor::run_jobs_with_budget`], if any.
+    fn spend(&self) {
+        if let Some(budget) = self.budget.get() {
+            self.budget.set(Some(budget.saturating_sub(1)));
+        }
+    }
+
+    /// Returns `true` if no job keeping the executor running remains.
     pub(crate) fn is_empty(&self) -> bool {
         self.promise_jobs.borrow().is_empty()
             && self.async_jobs.borrow().is_empty()
             && self.generic_jobs.borrow().is_empty()
-            && self.clock_jobs.borrow().is_empty()
+            && self
+                .clock_jobs
+                .borrow()
+                .values()
+                .flatten()
+                .all(|job| job.cancelled() || !job.keeps_alive())
     }
 }
 
@@ -946,6 +1037,18 @@ impl JobExecutor for SimpleJobExecutor {
         future::block_on(self.run_jobs_async(&RefCell::new(context)))
     }
 
+    fn run_jobs_with_budget(
+        self: Rc<Self>,
+        context: &mut Context,
+        budget: usize,
+    ) -> JsResult<bool> {
+        let previous = self.budget.replace(Some(budget));
+        let result = self.clone().run_jobs(context);
+        let exhausted = self.exhausted();
+        self.budget.set(previous);
+        result.map(|()| exhausted && !self.is_empty())
+    }
+
     async fn run_jobs_async(self: Rc<Self>, context: &RefCell<&mut Context>) -> JsResult<()>
     where
         Self: Sized,
@@ -959,6 +1062,12 @@ impl JobExecutor for SimpleJobExecutor {
                 return Ok(());
             }
 
+            // In-flight async jobs cannot be put back in the queue, so they are always driven
+            // to completion before returning.
+            if self.exhausted() && group.is_empty() && fr_group.is_empty() {
+                break;
+            }
+
             for job in mem::take(&mut *self.async_jobs.borrow_mut()) {
                 group.insert(job.call(context));
             }
@@ -969,6 +1078,11 @@ impl JobExecutor for SimpleJobExecutor {
 
             // Dispatch all past-due timeout jobs before the termination check.
             {
+                if let Err(err) = self.run_promise_jobs(&mut context.borrow_mut()) {
+                    self.clear();
+                    return Err(err);
+                }
+
                 let now = context.borrow().clock().now();
                 let jobs_to_run = {
                     let mut timeout_jobs = self.clock_jobs.borrow_mut();
@@ -980,29 +1094,56 @@ impl JobExecutor for SimpleJobExecutor {
                     mem::replace(&mut *timeout_jobs, jobs_to_keep)
                 };
 
-                for jobs in jobs_to_run.into_values() {
-                    for job in jobs {
-                        if !job.cancelled() {
-                            match job {
-                                ClockJob::Timeout(job) => {
-                                    if let Err(err) = job.call(&mut context.borrow_mut()) {
-                                        self.clear();
-                                        return Err(err);
-                                    }
+                let mut jobs_to_run = jobs_to_run
+                    .into_iter()
+                    .flat_map(|(at, jobs)| jobs.into_iter().map(move |job| (at, job)));
+                while let Some((at, job)) = jobs_to_run.next() {
+                    // The remaining timers are cleared at the top of the loop.
+                    if self.stop.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    if self.exhausted() {
+                        // Put the remaining timers back, ahead of any timer due at the same time.
+                        let mut remaining = BTreeMap::<JsInstant, Vec<ClockJob>>::new();
+                        for (at, job) in std::iter::once((at, job)).chain(jobs_to_run) {
+                            remaining.entry(at).or_default().push(job);
+                        }
+                        let mut clock_jobs = self.clock_jobs.borrow_mut();
+                        for (at, mut jobs) in remaining {
+                            let queued = clock_jobs.entry(at).or_default();
+                            jobs.append(queued);
+                            *queued = jobs;
+                        }
+                        break;
+                    }
+                    if !job.cancelled() {
+                        self.spend();
+                        match job {
+                            ClockJob::Timeout(job) => {
+                                let context = &mut context.borrow_mut();
+                                if let Err(err) = job
+                                    .call(context)
+                                    .and_then(|_| self.run_promise_jobs(context))
+                                {
+                                    self.clear();
+                                    return Err(err);
                                 }
-                                ClockJob::Interval(job) => {
-                                    let context = &mut context.borrow_mut();
-                                    let now = context.clock().now();
-                                    if let Err(err) = job.call(context) {
-                                        self.clear();
-                                        return Err(err);
-                                    }
-                                    self.clock_jobs
-                                        .borrow_mut()
-                                        .entry(now + job.interval())
-                                        .or_default()
-                                        .push(ClockJob::Interval(job));
+                            }
+                            ClockJob::Interval(job) => {
+                                let context = &mut context.borrow_mut();
+                                let now = context.clock().now();
+                                if let Err(err) = job
+                                    .call(context)
+                                    .and_then(|_| self.run_promise_jobs(context))
+                                {
+                                    self.clear();
+                                    return Err(err);
                                 }
+                                self.clock_jobs
+                                    .borrow_mut()
+                                    .entry(now + job.interval())
+                                    .or_default()
+                                    .push(ClockJob::Interval(job));
                             }
                         }
                     }
@@ -1025,16 +1166,20 @@ impl JobExecutor for SimpleJobExecutor {
                 return Err(err);
             }
 
-            let jobs = mem::take(&mut *self.promise_jobs.borrow_mut());
-            for job in jobs {
-                if let Err(err) = job.call(&mut context.borrow_mut()) {
-                    self.clear();
-                    return Err(err);
-                }
+            if let Err(err) = self.run_promise_jobs(&mut context.borrow_mut()) {
+                self.clear();
+                return Err(err);
             }
 
-            let jobs = mem::take(&mut *self.generic_jobs.borrow_mut());
-            for job in jobs {
+            let mut jobs = mem::take(&mut *self.generic_jobs.borrow_mut()).into_iter();
+            while let Some(job) = jobs.next() {
+                if self.exhausted() {
+                    let mut queued = self.generic_jobs.borrow_mut();
+                    let remaining = std::iter::once(job).chain(jobs).chain(queued.drain(..));
+                    *queued = remaining.collect();
+                    break;
+                }
+                self.spend();
                 if let Err(err) = job.call(&mut context.borrow_mut()) {
                     self.clear();
                     return Err(err);
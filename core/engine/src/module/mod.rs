@@ -281,6 +281,11 @@ impl Module {
         parser.set_identifier(context.next_parser_identifier());
         let (module, source) =
             parser.parse_module_with_source(realm.scope(), context.interner_mut())?;
+        if parser.has_decorators() {
+            return Err(JsNativeError::syntax()
+                .with_message("decorators are not supported yet")
+                .into());
+        }
 
         let source_text = SourceText::new(source);
         let src = SourceTextModule::new(module, context.interner(), source_text, path.clone());
@@ -1345,6 +1345,29 @@ pub fn js_typed_array_from_kind(
     }
 }
 
+/// Create a [`JsTypedArray`] from a [`TypedArrayKind`], viewing `length` elements of a
+/// backing [`JsArrayBuffer`] starting at `byte_offset`.
+///
+/// # Errors
+/// Throws a `RangeError` if the range is not a valid view of the buffer for this kind.
+pub fn js_typed_array_from_kind_with_range(
+    kind: TypedArrayKind,
+    inner: JsArrayBuffer,
+    byte_offset: u64,
+    length: u64,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let constructor =
+        kind.standard_constructor()(context.intrinsics().constructors()).constructor();
+    constructor
+        .construct(
+            &[inner.into(), byte_offset.into(), length.into()],
+            None,
+            context,
+        )
+        .map(Into::into)
+}
+
 #[test]
 fn typed_iterators_uint8() {
     let context = &mut Context::default();
@@ -869,10 +869,12 @@ pub(crate) fn ordinary_set(
         let obj_is_receiver = JsObject::equals(obj, &receiver);
 
         // NOTE(HaledOdat): If the object and receiver are not the same then it's not inline cacheable for now.
-        context
-            .slot()
-            .attributes
-            .set(SlotAttributes::NOT_CACHEABLE, !obj_is_receiver);
+        //
+        // The flag is never cleared here, since a `Proxy` forwarding to its target may have
+        // already marked the slot as not cacheable.
+        if !obj_is_receiver {
+            context.slot().attributes |= SlotAttributes::NOT_CACHEABLE;
+        }
 
         // OPTIMIZATION: If obj and receiver are the same, there's no need to call [[GetOwnProperty]](P)
         //              again because it was already performed above.
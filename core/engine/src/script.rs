@@ -16,7 +16,7 @@ use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 use boa_parser::{Parser, Source, source::ReadChar};
 
 use crate::{
-    Context, HostDefined, JsResult, JsString, JsValue, Module, SpannedSourceText,
+    Context, HostDefined, JsNativeError, JsResult, JsString, JsValue, Module, SpannedSourceText,
     bytecompiler::{ByteCompiler, global_declaration_instantiation_context},
     environments::EnvironmentStack,
     js_string,
@@ -97,6 +97,11 @@ impl Script {
         }
         let scope = context.realm().scope().clone();
         let (mut code, source) = parser.parse_script_with_source(&scope, context.interner_mut())?;
+        if parser.has_decorators() {
+            return Err(JsNativeError::syntax()
+                .with_message("decorators are not supported yet")
+                .into());
+        }
         if !context.optimizer_options().is_empty() {
             context.optimize_statement_list(code.statements_mut());
         }
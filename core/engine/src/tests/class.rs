@@ -201,3 +201,31 @@ fn class_boolean_literal_getter_setter_names() {
         TestAction::assert_eq("c.false", 84),
     ]);
 }
+
+#[test]
+fn decorators_are_rejected() {
+    run_test_actions([
+        TestAction::run("function dec() {}"),
+        TestAction::assert_native_error(
+            "var ran = true; @dec class A {}",
+            crate::JsNativeErrorKind::Syntax,
+            "decorators are not supported yet",
+        ),
+        TestAction::assert("typeof ran === 'undefined'"),
+        TestAction::assert_native_error(
+            "function f() { return class { @dec m() {} }; }",
+            crate::JsNativeErrorKind::Syntax,
+            "decorators are not supported yet",
+        ),
+        TestAction::assert_native_error(
+            "eval('@dec class B {}')",
+            crate::JsNativeErrorKind::Syntax,
+            "decorators are not supported yet",
+        ),
+        TestAction::assert_native_error(
+            "Function('return @dec class {}')",
+            crate::JsNativeErrorKind::Syntax,
+            "decorators are not supported yet",
+        ),
+    ]);
+}
@@ -2,14 +2,16 @@ use std::{
     cell::{Cell, RefCell},
     pin::pin,
     rc::Rc,
+    sync::{Arc, atomic::Ordering},
 };
 
 use futures_lite::future;
+use portable_atomic::AtomicBool;
 
 use crate::{
     JsValue, TestAction,
     context::{ContextBuilder, time::FixedClock},
-    job::{GenericJob, JobExecutor, NativeAsyncJob, SimpleJobExecutor},
+    job::{GenericJob, JobExecutor, NativeAsyncJob, PromiseJob, SimpleJobExecutor},
     run_test_actions_with,
 };
 
@@ -69,3 +71,33 @@ fn test_async_job_not_blocking_event_loop() {
         context,
     );
 }
+
+#[test]
+fn cancellation_token_stops_requeuing_microtasks() {
+    fn requeue(count: Rc<Cell<u32>>, stop: Arc<AtomicBool>) -> PromiseJob {
+        PromiseJob::new(move |context| {
+            count.set(count.get() + 1);
+            if count.get() == 100 {
+                stop.store(true, Ordering::Relaxed);
+            }
+            context.enqueue_job(requeue(count, stop).into());
+            Ok(JsValue::undefined())
+        })
+    }
+
+    let context = &mut ContextBuilder::default().build().unwrap();
+    let stop = context
+        .downcast_job_executor::<SimpleJobExecutor>()
+        .unwrap()
+        .get_cancellation_token();
+
+    let count = Rc::new(Cell::new(0));
+    context.enqueue_job(requeue(count.clone(), stop).into());
+    context.run_jobs().unwrap();
+    assert_eq!(count.get(), 100);
+
+    // The pending job was cleared, and the executor can run new jobs.
+    context.enqueue_job(PromiseJob::new(|_| Ok(JsValue::undefined())).into());
+    context.run_jobs().unwrap();
+    assert_eq!(count.get(), 100);
+}
@@ -36,6 +36,16 @@ pub(crate) struct InlineCache {
     /// Whether this access site has seen too many shapes and should no longer be cached.
     #[unsafe_ignore_trace]
     pub(crate) megamorphic: Cell<bool>,
+
+    /// Number of lookups that found a cached shape.
+    #[cfg(test)]
+    #[unsafe_ignore_trace]
+    pub(crate) hits: Cell<u32>,
+
+    /// Number of lookups that did not find a cached shape.
+    #[cfg(test)]
+    #[unsafe_ignore_trace]
+    pub(crate) misses: Cell<u32>,
 }
 
 impl fmt::Display for InlineCache {
@@ -59,6 +69,10 @@ impl InlineCache {
             name,
             entries: GcRefCell::new(ArrayVec::new()),
             megamorphic: Cell::new(false),
+            #[cfg(test)]
+            hits: Cell::new(0),
+            #[cfg(test)]
+            misses: Cell::new(0),
         }
     }
 
@@ -87,6 +101,19 @@ impl InlineCache {
     ///
     /// Opportunistically cleans up stale weak shape references during lookup.
     pub(crate) fn get(&self, shape: &Shape) -> Option<(Shape, Slot)> {
+        let result = self.lookup(shape);
+
+        #[cfg(test)]
+        if result.is_some() {
+            self.hits.set(self.hits.get() + 1);
+        } else {
+            self.misses.set(self.misses.get() + 1);
+        }
+
+        result
+    }
+
+    fn lookup(&self, shape: &Shape) -> Option<(Shape, Slot)> {
         if self.megamorphic.get() {
             return None;
         }
@@ -1,5 +1,6 @@
 use boa_gc::Gc;
 use boa_parser::Source;
+use indoc::indoc;
 
 use crate::{
     Context, JsObject, JsResult, JsValue,
@@ -316,6 +317,32 @@ fn set_internal_method() {
     assert_eq!(context.slot().index, slot.index);
 }
 
+#[test]
+fn set_internal_method_through_proxy() -> JsResult<()> {
+    let context = &mut Context::default();
+
+    let target = ObjectInitializer::new(context)
+        .property(js_string!("prop"), 0, Attribute::all())
+        .build();
+    context.register_global_property(js_string!("target"), target.clone(), Attribute::all())?;
+    let proxy = context.eval(Source::from_bytes("new Proxy(target, {})"))?;
+    let proxy = proxy.as_object().expect("should be an object").clone();
+
+    let property: PropertyKey = js_string!("prop").into();
+    let context = &mut InternalMethodPropertyContext::new(context);
+
+    // The target is both the object holding the property and the receiver, but the set
+    // still went through a `Proxy`.
+    proxy.__set__(property, 1.into(), target.into(), context)?;
+
+    assert!(
+        !context.slot().is_cacheable(),
+        "Since the set went through a Proxy, this should not be cacheable"
+    );
+
+    Ok(())
+}
+
 fn get_codeblock(value: &JsValue) -> Option<(JsObject, Gc<CodeBlock>)> {
     let object = value.as_object()?.clone();
     let code = object.downcast_ref::<OrdinaryFunction>()?.code.clone();
@@ -419,6 +446,47 @@ fn test_polymorphic_inline_cache() -> JsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_polymorphic_inline_cache_hit_rate() -> JsResult<()> {
+    let context = &mut Context::default();
+    let function = context.eval(Source::from_bytes("(function (o) { return o.test; })"))?;
+    let (function, code) = get_codeblock(&function).unwrap();
+
+    let shapes = vec![
+        ObjectInitializer::new(context)
+            .property(js_string!("test"), 1, Attribute::all())
+            .build(),
+        ObjectInitializer::new(context)
+            .property(js_string!("a"), 1, Attribute::all())
+            .property(js_string!("test"), 2, Attribute::all())
+            .build(),
+        ObjectInitializer::new(context)
+            .property(js_string!("b"), 1, Attribute::all())
+            .property(js_string!("test"), 3, Attribute::all())
+            .build(),
+    ];
+
+    // The first round only fills the cache.
+    for o in &shapes {
+        function.call(&JsValue::undefined(), &[o.clone().into()], context)?;
+    }
+    assert_eq!(code.ic[0].hits.get(), 0);
+    assert_eq!(code.ic[0].misses.get(), 3);
+
+    // Every later lookup should hit one of the cached shapes.
+    for _ in 0..10 {
+        for (o, expected) in shapes.iter().zip(1..) {
+            let value = function.call(&JsValue::undefined(), &[o.clone().into()], context)?;
+            assert_eq!(value, JsValue::new(expected));
+        }
+    }
+    assert_eq!(code.ic[0].hits.get(), 30);
+    assert_eq!(code.ic[0].misses.get(), 3);
+    assert!(!code.ic[0].megamorphic.get());
+
+    Ok(())
+}
+
 #[test]
 fn test_megamorphic_inline_cache() -> JsResult<()> {
     let context = &mut Context::default();
@@ -465,3 +533,52 @@ fn test_megamorphic_inline_cache() -> JsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn proxy_in_prototype_chain_is_not_cached() -> JsResult<()> {
+    let context = &mut Context::default();
+    let function = context.eval(Source::from_bytes("(function (o) { return o.test; })"))?;
+    let (function, code) = get_codeblock(&function).unwrap();
+
+    let o = context.eval(Source::from_bytes(indoc! {r#"
+        let count = 0;
+        const proxy = new Proxy({}, { get: () => ++count });
+        Object.create(proxy)
+    "#}))?;
+
+    for expected in 1..=3 {
+        let value = function.call(&JsValue::undefined(), std::slice::from_ref(&o), context)?;
+        assert_eq!(value, JsValue::new(expected));
+    }
+
+    assert_eq!(code.ic[0].entries.borrow().len(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn proxy_in_prototype_chain_set_is_not_cached() -> JsResult<()> {
+    let context = &mut Context::default();
+    let function = context.eval(Source::from_bytes("(function (o) { o.test = 30; })"))?;
+    let (function, code) = get_codeblock(&function).unwrap();
+
+    let o = context.eval(Source::from_bytes(indoc! {r#"
+        var sets = 0;
+        const setProxy = new Proxy({}, {
+            set() {
+                sets++;
+                return true;
+            },
+        });
+        Object.create(setProxy)
+    "#}))?;
+
+    for _ in 0..3 {
+        function.call(&JsValue::undefined(), std::slice::from_ref(&o), context)?;
+    }
+
+    assert_eq!(context.eval(Source::from_bytes("sets"))?, JsValue::new(3));
+    assert_eq!(code.ic[0].entries.borrow().len(), 0);
+
+    Ok(())
+}
@@ -550,12 +550,18 @@ fn generate_conversion(
         }
 
         let error_str = format!("cannot get property {name} of value");
+        let missing_str = format!("missing required property `{field_name}`");
         final_fields.push(quote! {
             let #name = match props.get(&::boa_engine::js_string!(#field_name).into()) {
                 Some(pd) => pd.value().ok_or_else(|| ::boa_engine::JsError::from(
                         ::boa_engine::JsNativeError::typ().with_message(#error_str)
                     ))?.clone().try_js_into(context)?,
-                None => ::boa_engine::JsValue::undefined().try_js_into(context)?,
+                // Only fields accepting `undefined`, like `Option<T>`, may be omitted.
+                None => ::boa_engine::JsValue::undefined().try_js_into(context).map_err(|_| {
+                    ::boa_engine::JsError::from(
+                        ::boa_engine::JsNativeError::typ().with_message(#missing_str)
+                    )
+                })?,
             };
         });
 
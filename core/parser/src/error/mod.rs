@@ -186,6 +186,19 @@ impl Error {
     pub(crate) const fn lex(e: LexError) -> Self {
         Self::Lex { err: e }
     }
+
+    /// Gets the position in the source code where the error occurred, if any.
+    ///
+    /// Columns are counted in UTF-16 code units, like in JavaScript tooling.
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        match self {
+            Self::Expected { span, .. } | Self::Unexpected { span, .. } => Some(span.start()),
+            Self::General { position, .. } => Some(*position),
+            Self::Lex { err } => err.position(),
+            Self::AbruptEnd | Self::ScopeAnalysis { .. } => None,
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -164,3 +164,26 @@ fn display() {
 
     assert_eq!(err.to_string(), "testing at line 1, col 1");
 }
+
+#[test]
+fn position() {
+    use crate::{Parser, Source};
+    use boa_ast::scope::Scope;
+    use boa_interner::Interner;
+
+    let parse_error = |src: &str| {
+        Parser::new(Source::from_bytes(src))
+            .parse_script(&Scope::new_global(), &mut Interner::default())
+            .expect_err("parsing should fail")
+    };
+
+    let err = parse_error("let a = 1;\nlet b = );");
+    assert_eq!(err.position(), Some(Position::new(2, 9)));
+    assert!(err.to_string().ends_with("at line 2, col 9"), "{err}");
+
+    // Columns are counted in UTF-16 code units.
+    let err = parse_error("let a = \"\u{1F600}\"; )");
+    assert_eq!(err.position(), Some(Position::new(1, 15)));
+
+    assert_eq!(Error::AbruptEnd.position(), None);
+}
@@ -2,9 +2,76 @@
 
 use crate::lexer::{Cursor, Error, Token, TokenKind, Tokenizer};
 use crate::source::ReadChar;
-use boa_ast::PositionGroup;
+use boa_ast::{LinearSpan, PositionGroup, Span, Spanned};
 use boa_interner::Interner;
 
+/// The kind of a [`Comment`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentKind {
+    /// A single line comment, like `// comment`.
+    ///
+    /// HTML-like comments (`<!--` and `-->`) are also single line comments.
+    Line,
+    /// A block comment, like `/* comment */`.
+    Block,
+    /// A hashbang comment at the start of the source, like `#!/usr/bin/env node`.
+    Hashbang,
+}
+
+/// A comment found in the source text.
+///
+/// Comments are only collected if the lexer was asked to preserve them, see
+/// [`Lexer::set_preserve_comments`][crate::Lexer::set_preserve_comments].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    kind: CommentKind,
+    text: String,
+    span: Span,
+    linear_span: LinearSpan,
+}
+
+impl Comment {
+    /// Creates a new comment.
+    #[inline]
+    #[must_use]
+    pub const fn new(kind: CommentKind, text: String, span: Span, linear_span: LinearSpan) -> Self {
+        Self {
+            kind,
+            text,
+            span,
+            linear_span,
+        }
+    }
+
+    /// Gets the kind of the comment.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> CommentKind {
+        self.kind
+    }
+
+    /// Gets the source text of the comment, including its delimiters.
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Gets the comment span in the original source code.
+    #[inline]
+    #[must_use]
+    pub const fn linear_span(&self) -> LinearSpan {
+        self.linear_span
+    }
+}
+
+impl Spanned for Comment {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
 /// Lexes a single line comment.
 ///
 /// Assumes that the initial '//' is already consumed.
@@ -1,7 +1,7 @@
 //! Boa's lexer cursor that manages the input byte stream.
 
 use crate::source::{ReadChar, UTF8Input};
-use boa_ast::{LinearPosition, Position, PositionGroup, SourceText};
+use boa_ast::{LinearPosition, LinearSpan, Position, PositionGroup, SourceText};
 use std::io::{self, Error, ErrorKind};
 
 /// Cursor over the source code.
@@ -34,15 +34,24 @@ impl<R> Cursor<R> {
         self.source_collector.cur_linear_position()
     }
 
+    /// Gets the source text collected within `span`.
+    pub(super) fn source_text(&self, span: LinearSpan) -> &[u16] {
+        self.source_collector.get_code_points_from_span(span)
+    }
+
     pub(super) fn take_source(&mut self) -> SourceText {
         let replace_with = SourceText::with_capacity(0);
         std::mem::replace(&mut self.source_collector, replace_with)
     }
 
-    /// Advances the position to the next column.
-    fn next_column(&mut self) {
+    /// Advances the position past a code point in the current line.
+    ///
+    /// Columns are counted in UTF-16 code units, so code points outside the Basic
+    /// Multilingual Plane take two columns.
+    fn next_column(&mut self, ch: u32) {
         let current_line = self.pos.line_number();
-        let next_column = self.pos.column_number() + 1;
+        let width = if ch > 0xFFFF { 2 } else { 1 };
+        let next_column = self.pos.column_number() + width;
         self.pos = Position::new(current_line, next_column);
     }
 
@@ -209,7 +218,7 @@ impl<R: ReadChar> Cursor<R> {
             }
             // '\n' | '\u{2028}' | '\u{2029}'
             Some(0xA | 0x2028 | 0x2029) => self.next_line(),
-            Some(_) => self.next_column(),
+            Some(ch) => self.next_column(ch),
             _ => {}
         }
 
@@ -42,6 +42,16 @@ impl Error {
         Self::Syntax(err.into(), pos.into())
     }
 
+    /// Gets the position in the source code where the error occurred, if any.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        match self {
+            Self::IO(_) => None,
+            Self::Syntax(_, pos) => Some(*pos),
+        }
+    }
+
     /// Creates an "unexpected" syntax error (found X, with message).
     #[inline]
     pub(crate) fn unexpected<F, M, P>(found: F, message: M, pos: P) -> Self
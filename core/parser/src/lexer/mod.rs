@@ -42,10 +42,11 @@ use self::{
     template::TemplateLiteral,
 };
 use crate::source::{ReadChar, UTF8Input};
-use boa_ast::{PositionGroup, Punctuator};
+use boa_ast::{PositionGroup, Punctuator, Spanned};
 use boa_interner::Interner;
 
 pub use self::{
+    comment::{Comment, CommentKind},
     error::Error,
     token::{Token, TokenKind},
 };
@@ -67,6 +68,7 @@ trait Tokenizer<R> {
 pub struct Lexer<R> {
     cursor: Cursor<R>,
     goal_symbol: InputElement,
+    comments: Option<Vec<Comment>>,
 }
 
 impl<R> Lexer<R> {
@@ -100,6 +102,31 @@ impl<R> Lexer<R> {
         self.cursor.set_module(module);
     }
 
+    /// Enables collecting the comments found while lexing, which are otherwise discarded.
+    ///
+    /// The collected comments can be retrieved with [`Lexer::take_comments`].
+    pub fn set_preserve_comments(&mut self) {
+        self.comments = Some(Vec::new());
+    }
+
+    /// Takes the comments collected so far, in source order.
+    ///
+    /// Returns an empty list if comments are not being preserved.
+    pub fn take_comments(&mut self) -> Vec<Comment> {
+        self.comments
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Records the comment lexed as `token`, if comments are being preserved.
+    fn record_comment(&mut self, kind: CommentKind, token: &Token) {
+        if let Some(comments) = &mut self.comments {
+            let text = String::from_utf16_lossy(self.cursor.source_text(token.linear_span()));
+            comments.push(Comment::new(kind, text, token.span(), token.linear_span()));
+        }
+    }
+
     /// Creates a new lexer.
     pub fn new(reader: R) -> Self
     where
@@ -108,6 +135,7 @@ impl<R> Lexer<R> {
         Self {
             cursor: Cursor::new(reader),
             goal_symbol: InputElement::default(),
+            comments: None,
         }
     }
 
@@ -134,12 +162,16 @@ impl<R> Lexer<R> {
                 // /
                 (0x002F, false) => {
                     self.cursor.next_char()?.expect("/ token vanished"); // Consume the '/'
-                    SingleLineComment.lex(&mut self.cursor, start, interner)
+                    let token = SingleLineComment.lex(&mut self.cursor, start, interner)?;
+                    self.record_comment(CommentKind::Line, &token);
+                    Ok(token)
                 }
                 // *
                 (0x002A, false) => {
                     self.cursor.next_char()?.expect("* token vanished"); // Consume the '*'
-                    MultiLineComment.lex(&mut self.cursor, start, interner)
+                    let token = MultiLineComment.lex(&mut self.cursor, start, interner)?;
+                    self.record_comment(CommentKind::Block, &token);
+                    Ok(token)
                 }
                 (ch, init_with_eq) => {
                     match self.get_goal() {
@@ -198,12 +230,13 @@ impl<R> Lexer<R> {
 
         // -->
         if self.cursor.peek_n(3)?[..3] == [Some(0x2D), Some(0x2D), Some(0x3E)] {
+            let start = self.cursor.pos_group();
             let _next = self.cursor.next_char();
             let _next = self.cursor.next_char();
             let _next = self.cursor.next_char();
 
-            let start = self.cursor.pos_group();
-            SingleLineComment.lex(&mut self.cursor, start, interner)?;
+            let token = SingleLineComment.lex(&mut self.cursor, start, interner)?;
+            self.record_comment(CommentKind::Line, &token);
         }
 
         Ok(())
@@ -229,7 +262,9 @@ impl<R> Lexer<R> {
         if self.get_goal() == InputElement::HashbangOrRegExp {
             self.set_goal(InputElement::RegExp);
             if next_ch == 0x23 && self.cursor.peek_char()? == Some(0x21) {
-                let _token = HashbangComment.lex(&mut self.cursor, start, interner);
+                if let Ok(token) = HashbangComment.lex(&mut self.cursor, start, interner) {
+                    self.record_comment(CommentKind::Hashbang, &token);
+                }
                 return self.next(interner);
             }
         }
@@ -315,6 +350,11 @@ impl<R> Lexer<R> {
                     self.cursor.pos_group(),
                 )),
                 '#' => PrivateIdentifier::new().lex(&mut self.cursor, start, interner),
+                '@' => Ok(Token::new_by_position_group(
+                    Punctuator::At.into(),
+                    start,
+                    self.cursor.pos_group(),
+                )),
                 '/' => self.lex_slash_token(start, interner, false),
                 #[cfg(feature = "annex-b")]
                 // <!--
@@ -324,8 +364,9 @@ impl<R> Lexer<R> {
                     let _next = self.cursor.next_char();
                     let _next = self.cursor.next_char();
                     let _next = self.cursor.next_char();
-                    let start = self.cursor.pos_group();
-                    SingleLineComment.lex(&mut self.cursor, start, interner)
+                    let token = SingleLineComment.lex(&mut self.cursor, start, interner)?;
+                    self.record_comment(CommentKind::Line, &token);
+                    Ok(token)
                 }
                 #[allow(clippy::cast_possible_truncation)]
                 '=' | '*' | '+' | '-' | '%' | '|' | '&' | '^' | '<' | '>' | '!' | '~' | '?' => {
@@ -568,10 +568,30 @@ fn numbers_with_separators() {
     expect_tokens(&mut lexer, &expected, interner);
 }
 
+#[test]
+fn numbers_with_separators_in_all_forms() {
+    let mut lexer =
+        Lexer::from("1_000 0x1_FF 0b1010_0101 0o7_7 1_000.000_1 1_0e1_0 100_000n".as_bytes());
+    let interner = &mut Interner::default();
+
+    let expected = [
+        TokenKind::numeric_literal(1000),
+        TokenKind::numeric_literal(511),
+        TokenKind::numeric_literal(165),
+        TokenKind::numeric_literal(63),
+        TokenKind::numeric_literal(1000.0001),
+        TokenKind::numeric_literal(100_000_000_000.0),
+        TokenKind::numeric_literal(num_bigint::BigInt::from(100_000)),
+    ];
+
+    expect_tokens(&mut lexer, &expected, interner);
+}
+
 #[test]
 fn numbers_with_bad_separators() {
     let numbers = [
-        "0b_10", "0x_10", "10_", "1._10", "1e+_10", "1E_10", "10__00",
+        "0b_10", "0x_10", "0o_10", "10_", "1._10", "1_.10", "1e+_10", "1E_10", "1_e10", "10__00",
+        "0x1__F", "0x1_", "100_n", "0_1", "08_1",
     ];
 
     for n in &numbers {
@@ -724,6 +744,49 @@ fn regex_literal_flags_err() {
         .expect_err("Lexer did not handle regex literal with error");
 }
 
+#[test]
+fn regex_literal_unicode_sets() {
+    let mut lexer = Lexer::from(&br"/[\p{L}--[a-z]]/v"[..]);
+    let interner = &mut Interner::default();
+
+    let expected = [TokenKind::regular_expression_literal(
+        interner.get_or_intern_static("[\\p{L}--[a-z]]", utf16!("[\\p{L}--[a-z]]")),
+        interner.get_or_intern_static("v", utf16!("v")),
+    )];
+
+    expect_tokens(&mut lexer, &expected, interner);
+
+    for source in [
+        &br"/[\p{L}&&\p{ASCII}]/v"[..],
+        &br"/[\q{abc|d}x]/v"[..],
+        &br"/[[a-z]--[aeiou]]/gv"[..],
+    ] {
+        let mut lexer = Lexer::from(source);
+        let interner = &mut Interner::default();
+        assert!(matches!(
+            lexer
+                .next(interner)
+                .unwrap()
+                .map(|token| token.kind().clone()),
+            Some(TokenKind::RegularExpressionLiteral(..))
+        ));
+    }
+}
+
+#[test]
+fn regex_literal_unicode_sets_with_unicode_err() {
+    let mut lexer = Lexer::from(&b"/[a-z]/uv"[..]);
+    let interner = &mut Interner::default();
+
+    let err = lexer
+        .next(interner)
+        .expect_err("Lexer did not reject the `u` and `v` flags together");
+    assert!(
+        err.to_string()
+            .contains("cannot use both 'u' and 'v' flags")
+    );
+}
+
 #[test]
 fn addition_no_spaces() {
     let mut lexer = Lexer::from(&b"1+1"[..]);
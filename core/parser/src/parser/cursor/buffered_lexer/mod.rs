@@ -1,6 +1,6 @@
 use crate::{
     Error,
-    lexer::{InputElement, Lexer, Token, TokenKind},
+    lexer::{Comment, InputElement, Lexer, Token, TokenKind},
     parser::ParseResult,
     source::{ReadChar, UTF8Input},
 };
@@ -276,4 +276,12 @@ where
     pub(super) fn take_source(&mut self) -> boa_ast::SourceText {
         self.lexer.take_source()
     }
+
+    pub(super) fn set_preserve_comments(&mut self) {
+        self.lexer.set_preserve_comments();
+    }
+
+    pub(super) fn take_comments(&mut self) -> Vec<Comment> {
+        self.lexer.take_comments()
+    }
 }
@@ -3,7 +3,7 @@ mod buffered_lexer;
 
 use crate::{
     Error,
-    lexer::{InputElement, Lexer, Token, TokenKind},
+    lexer::{Comment, InputElement, Lexer, Token, TokenKind},
     parser::{OrAbrupt, ParseResult},
     source::ReadChar,
 };
@@ -37,6 +37,12 @@ pub(super) struct Cursor<R> {
 
     /// Tracks the number of tagged templates that are currently being parsed.
     tagged_templates_count: u32,
+
+    /// The errors recovered from so far, if the cursor is in error recovery mode.
+    recovered_errors: Option<Vec<Error>>,
+
+    /// Tracks if a decorator has been parsed.
+    decorators: bool,
 }
 
 impl<R> Cursor<R>
@@ -51,6 +57,8 @@ where
             json_parse: false,
             identifier: 0,
             tagged_templates_count: 0,
+            recovered_errors: None,
+            decorators: false,
         }
     }
 
@@ -157,6 +165,84 @@ where
         self.json_parse = json_parse;
     }
 
+    /// Returns if a decorator has been parsed.
+    pub(super) const fn decorators(&self) -> bool {
+        self.decorators
+    }
+
+    /// Records that a decorator has been parsed.
+    pub(super) fn set_decorators(&mut self) {
+        self.decorators = true;
+    }
+
+    /// Enables error recovery mode, where recoverable errors are recorded instead of
+    /// aborting the parse.
+    pub(super) fn set_error_recovery(&mut self) {
+        self.recovered_errors = Some(Vec::new());
+    }
+
+    /// Returns if the cursor is in error recovery mode.
+    pub(super) const fn error_recovery(&self) -> bool {
+        self.recovered_errors.is_some()
+    }
+
+    /// Reports a recoverable error.
+    ///
+    /// In error recovery mode the error is recorded and `Ok` is returned, otherwise the
+    /// error is returned as is.
+    pub(super) fn report_error(&mut self, error: Error) -> ParseResult<()> {
+        match &mut self.recovered_errors {
+            Some(errors) => {
+                errors.push(error);
+                Ok(())
+            }
+            None => Err(error),
+        }
+    }
+
+    /// Takes the errors recorded in error recovery mode.
+    pub(super) fn take_recovered_errors(&mut self) -> Vec<Error> {
+        self.recovered_errors.take().unwrap_or_default()
+    }
+
+    /// Skips tokens until the next statement boundary, for error recovery.
+    ///
+    /// At least one token is skipped. Skipping stops after a `;`, or before a token
+    /// preceded by a line terminator, as long as all the brackets opened while skipping
+    /// have been closed.
+    pub(super) fn skip_to_statement_boundary(
+        &mut self,
+        interner: &mut Interner,
+    ) -> ParseResult<()> {
+        let mut depth = 0usize;
+        let mut skipped = false;
+        while let Some(token) = self.buffered_lexer.peek(0, false, interner)? {
+            match token.kind() {
+                TokenKind::LineTerminator if skipped && depth == 0 => break,
+                TokenKind::LineTerminator => {}
+                TokenKind::Punctuator(Punctuator::Semicolon) if depth == 0 => {
+                    self.buffered_lexer.next(false, interner)?;
+                    break;
+                }
+                TokenKind::Punctuator(
+                    Punctuator::OpenBlock | Punctuator::OpenParen | Punctuator::OpenBracket,
+                ) => {
+                    depth += 1;
+                    skipped = true;
+                }
+                TokenKind::Punctuator(
+                    Punctuator::CloseBlock | Punctuator::CloseParen | Punctuator::CloseBracket,
+                ) => {
+                    depth = depth.saturating_sub(1);
+                    skipped = true;
+                }
+                _ => skipped = true,
+            }
+            self.buffered_lexer.next(false, interner)?;
+        }
+        Ok(())
+    }
+
     /// Set the identifier of the cursor.
     #[inline]
     pub(super) fn set_identifier(&mut self, identifier: u32) {
@@ -312,4 +398,14 @@ where
     pub(super) fn take_source(&mut self) -> boa_ast::SourceText {
         self.buffered_lexer.take_source()
     }
+
+    /// Enables collecting the comments found in the source text.
+    pub(super) fn set_preserve_comments(&mut self) {
+        self.buffered_lexer.set_preserve_comments();
+    }
+
+    /// Takes the comments collected so far.
+    pub(super) fn take_comments(&mut self) -> Vec<Comment> {
+        self.buffered_lexer.take_comments()
+    }
 }
@@ -0,0 +1,156 @@
+//! Decorator parsing.
+//!
+//! More information:
+//!  - [TC39 proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-decorators/#sec-decorators
+
+use crate::{
+    Error,
+    lexer::TokenKind,
+    parser::{
+        AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
+        expression::{Expression, identifiers::IdentifierReference, left_hand_side::Arguments},
+    },
+    source::ReadChar,
+};
+use boa_ast::{
+    self as ast, Punctuator, Span, Spanned,
+    expression::{
+        Call, Identifier, Parenthesized,
+        access::{PrivatePropertyAccess, SimplePropertyAccess},
+    },
+    function::{Decorator, PrivateName},
+};
+use boa_interner::Interner;
+
+/// Parses a possibly empty list of decorators.
+///
+/// More information:
+///  - [TC39 proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-decorators/#prod-DecoratorList
+#[derive(Debug, Clone, Copy)]
+pub(in crate::parser) struct DecoratorList {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl DecoratorList {
+    /// Creates a new `DecoratorList` parser.
+    pub(in crate::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for DecoratorList
+where
+    R: ReadChar,
+{
+    type Output = Box<[Decorator]>;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let mut decorators = Vec::new();
+        while cursor.next_if(Punctuator::At, interner)?.is_some() {
+            let token = cursor.peek(0, interner).or_abrupt()?;
+            let expression = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
+                let start = token.span().start();
+                cursor.advance(interner);
+                let expression = Expression::new(true, self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                let end = cursor
+                    .expect(Punctuator::CloseParen, "decorator", interner)?
+                    .span()
+                    .end();
+                Parenthesized::new(expression, Span::new(start, end)).into()
+            } else {
+                self.parse_member_expression(cursor, interner)?
+            };
+            decorators.push(Decorator::new(expression));
+        }
+
+        if !decorators.is_empty() {
+            cursor.set_decorators();
+        }
+
+        // Decorators can only be followed by a class or a class element, neither of which
+        // can start with these tokens.
+        if !decorators.is_empty()
+            && let Some(token) = cursor.peek(0, interner)?
+            && matches!(
+                token.kind(),
+                TokenKind::Punctuator(Punctuator::Semicolon | Punctuator::CloseBlock)
+            )
+        {
+            return Err(Error::general(
+                "expected class or class element after decorator",
+                token.span().start(),
+            ));
+        }
+
+        Ok(decorators.into_boxed_slice())
+    }
+}
+
+impl DecoratorList {
+    /// Parses a `DecoratorMemberExpression`, optionally followed by arguments.
+    fn parse_member_expression<R>(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> ParseResult<ast::Expression>
+    where
+        R: ReadChar,
+    {
+        let mut lhs: ast::Expression = IdentifierReference::new(self.allow_yield, self.allow_await)
+            .parse(cursor, interner)?
+            .into();
+
+        while cursor.next_if(Punctuator::Dot, interner)?.is_some() {
+            let token = cursor.next(interner).or_abrupt()?;
+            let lhs_span = lhs.span();
+            let access = match token.kind() {
+                TokenKind::IdentifierName((name, _)) => {
+                    SimplePropertyAccess::new(lhs, Identifier::new(*name, token.span())).into()
+                }
+                TokenKind::Keyword((kw, _)) => {
+                    SimplePropertyAccess::new(lhs, Identifier::new(kw.to_sym(), token.span()))
+                        .into()
+                }
+                TokenKind::PrivateIdentifier(name) => PrivatePropertyAccess::new(
+                    lhs,
+                    PrivateName::new(*name, token.span()),
+                    Span::new(lhs_span.start(), token.span().end()),
+                )
+                .into(),
+                _ => {
+                    return Err(Error::expected(
+                        ["identifier".to_owned()],
+                        token.to_string(interner),
+                        token.span(),
+                        "decorator",
+                    ));
+                }
+            };
+            lhs = ast::Expression::PropertyAccess(access);
+        }
+
+        if cursor
+            .peek(0, interner)?
+            .is_some_and(|token| token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen))
+        {
+            let (args, args_span) =
+                Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+            lhs = Call::new(lhs, args, args_span).into();
+        }
+
+        Ok(lhs)
+    }
+}
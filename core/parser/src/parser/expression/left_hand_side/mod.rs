@@ -12,6 +12,7 @@ mod tests;
 
 mod arguments;
 mod call;
+mod decorator;
 mod member;
 mod optional;
 mod template;
@@ -39,6 +40,8 @@ use boa_ast::{
 };
 use boa_interner::{Interner, Sym};
 
+pub(in crate::parser) use decorator::DecoratorList;
+
 /// Parses a left hand side expression.
 ///
 /// More information:
@@ -46,7 +46,7 @@ pub(super) use self::{assignment::AssignmentExpression, primary::Initializer};
 pub(in crate::parser) use {
     fpl_or_exp::FormalParameterListOrExpression,
     identifiers::{BindingIdentifier, LabelIdentifier},
-    left_hand_side::LeftHandSideExpression,
+    left_hand_side::{DecoratorList, LeftHandSideExpression},
     primary::object_initializer::{
         AsyncGeneratorMethod, AsyncMethod, GeneratorMethod, PropertyName,
     },
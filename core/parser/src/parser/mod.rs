@@ -12,7 +12,7 @@ mod tests;
 use crate::{
     Error, Source,
     error::ParseResult,
-    lexer::{Error as LexError, InputElement},
+    lexer::{Comment, Error as LexError, InputElement},
     parser::{
         cursor::Cursor,
         function::{FormalParameters, FunctionStatementList},
@@ -171,6 +171,38 @@ impl<'a, R: ReadChar> Parser<'a, R> {
         Ok((ast, source))
     }
 
+    /// Parse the full input as a [ECMAScript Script][spec] into the boa AST representation,
+    /// recovering from syntax errors.
+    ///
+    /// Instead of stopping at the first syntax error, the error is recorded and parsing
+    /// continues at the next statement boundary, replacing the invalid statement with a
+    /// [`Statement::Error`](boa_ast::Statement::Error) node in the resulting `Script`. This is
+    /// meant for tooling that wants to report all the errors of a source text at once; the
+    /// returned `Script` cannot be executed if any error was reported.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-Script
+    pub fn parse_script_with_recovery(
+        &mut self,
+        scope: &Scope,
+        interner: &mut Interner,
+    ) -> (boa_ast::Script, Vec<Error>) {
+        self.cursor.set_goal(InputElement::HashbangOrRegExp);
+        self.cursor.set_error_recovery();
+        let result = ScriptParser::new(false).parse(&mut self.cursor, interner);
+        let mut errors = self.cursor.take_recovered_errors();
+        let mut ast = match result {
+            Ok((ast, _source)) => ast,
+            Err(error) => {
+                errors.push(error);
+                return (boa_ast::Script::default(), errors);
+            }
+        };
+        if let Err(reason) = ast.analyze_scope(scope, interner) {
+            errors.push(Error::scope_analysis(reason));
+        }
+        (ast, errors)
+    }
+
     /// Parse the full input as an [ECMAScript Module][spec] into the boa AST representation without source text.
     /// The resulting `ModuleItemList` can be compiled into boa bytecode and executed in the boa vm.
     ///
@@ -291,6 +323,38 @@ impl<R> Parser<'_, R> {
     {
         self.cursor.set_identifier(identifier);
     }
+
+    /// Set the parser to preserve the comments of the source text.
+    ///
+    /// Comments are not attached to the nodes of the AST; after parsing, they can be
+    /// retrieved in source order with [`Parser::take_comments`].
+    pub fn set_preserve_comments(&mut self)
+    where
+        R: ReadChar,
+    {
+        self.cursor.set_preserve_comments();
+    }
+
+    /// Returns `true` if the source text parsed so far contains a decorator.
+    ///
+    /// This lets engines that don't evaluate decorators yet reject code using them without
+    /// walking the AST.
+    pub fn has_decorators(&self) -> bool
+    where
+        R: ReadChar,
+    {
+        self.cursor.decorators()
+    }
+
+    /// Takes the comments collected while parsing, in source order.
+    ///
+    /// Returns an empty list unless [`Parser::set_preserve_comments`] was called before parsing.
+    pub fn take_comments(&mut self) -> Vec<Comment>
+    where
+        R: ReadChar,
+    {
+        self.cursor.take_comments()
+    }
 }
 
 /// Parses a full script.
@@ -327,20 +391,20 @@ where
         let mut lexical_names = FxHashSet::default();
         for name in lexically_declared_names(&script) {
             if !lexical_names.insert(name) {
-                return Err(Error::general(
+                cursor.report_error(Error::general(
                     "lexical name declared multiple times",
                     Position::new(1, 1),
-                ));
+                ))?;
             }
         }
 
         // It is a Syntax Error if any element of the LexicallyDeclaredNames of ScriptBody also occurs in the VarDeclaredNames of ScriptBody.
         for name in var_declared_names(&script) {
             if lexical_names.contains(&name) {
-                return Err(Error::general(
+                cursor.report_error(Error::general(
                     "lexical name declared multiple times",
                     Position::new(1, 1),
-                ));
+                ))?;
             }
         }
 
@@ -389,6 +453,10 @@ where
             self.directive_prologues,
             self.strict,
         )
+        .with_error_recovery(cursor.error_recovery())
+        // It is a Syntax Error if the goal symbol is Script and StatementList directly
+        // contains a `using` or `await using` declaration.
+        .reject_using()
         .parse(cursor, interner)?;
 
         if !self.direct_eval {
@@ -396,41 +464,41 @@ where
             // code that is being processed by a direct eval.
             // Additional early error rules for super within direct eval are defined in 19.2.1.1.
             if contains(&body, ContainsSymbol::Super) {
-                return Err(Error::general("invalid super usage", Position::new(1, 1)));
+                cursor.report_error(Error::general("invalid super usage", Position::new(1, 1)))?;
             }
             // It is a Syntax Error if StatementList Contains NewTarget unless the source text containing NewTarget
             // is eval code that is being processed by a direct eval.
             // Additional early error rules for NewTarget in direct eval are defined in 19.2.1.1.
             if contains(&body, ContainsSymbol::NewTarget) {
-                return Err(Error::general(
+                cursor.report_error(Error::general(
                     "invalid new.target usage",
                     Position::new(1, 1),
-                ));
+                ))?;
             }
 
             // It is a Syntax Error if AllPrivateIdentifiersValid of StatementList with
             // argument « » is false unless the source text containing ScriptBody is
             // eval code that is being processed by a direct eval.
             if !all_private_identifiers_valid(&body, Vec::new()) {
-                return Err(Error::general(
+                cursor.report_error(Error::general(
                     "invalid private identifier usage",
                     Position::new(1, 1),
-                ));
+                ))?;
             }
         }
 
         if let Err(error) = check_labels(&body) {
-            return Err(Error::lex(LexError::Syntax(
+            cursor.report_error(Error::lex(LexError::Syntax(
                 error.message(interner).into(),
                 Position::new(1, 1),
-            )));
+            )))?;
         }
 
         if contains_invalid_object_literal(&body) {
-            return Err(Error::lex(LexError::Syntax(
+            cursor.report_error(Error::lex(LexError::Syntax(
                 "invalid object literal in script statement list".into(),
                 Position::new(1, 1),
-            )));
+            )))?;
         }
 
         Ok(body)
@@ -8,7 +8,7 @@ use crate::{
         AllowAwait, AllowDefault, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
         expression::{
             AssignmentExpression, AsyncGeneratorMethod, AsyncMethod, BindingIdentifier,
-            GeneratorMethod, LeftHandSideExpression, PropertyName,
+            DecoratorList, GeneratorMethod, LeftHandSideExpression, PropertyName,
         },
         function::{FUNCTION_BREAK_TOKENS, FunctionBody, UniqueFormalParameters},
         statement::StatementList,
@@ -75,6 +75,8 @@ where
     type Output = ClassDeclarationNode;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let decorators =
+            DecoratorList::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
         let span = cursor
             .expect((Keyword::Class, false), "class declaration", interner)?
             .span();
@@ -106,12 +108,10 @@ where
         let (super_ref, constructor, elements, _end) =
             ClassTail::new(name, self.allow_yield, self.allow_await).parse(cursor, interner)?;
 
-        Ok(ClassDeclarationNode::new(
-            name,
-            super_ref,
-            constructor,
-            elements.into_boxed_slice(),
-        ))
+        Ok(
+            ClassDeclarationNode::new(name, super_ref, constructor, elements.into_boxed_slice())
+                .with_decorators(decorators),
+        )
     }
 }
 
@@ -533,6 +533,44 @@ where
     type Output = (Option<FunctionExpression>, Option<function::ClassElement>);
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        if cursor.peek(0, interner).or_abrupt()?.kind() == &TokenKind::Punctuator(Punctuator::At) {
+            let strict = cursor.strict();
+            cursor.set_strict(true);
+            let decorators =
+                DecoratorList::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+            cursor.set_strict(strict);
+
+            let position = cursor.peek(0, interner).or_abrupt()?.span().start();
+            let element = match self.parse(cursor, interner)? {
+                (None, Some(function::ClassElement::MethodDefinition(m))) => {
+                    function::ClassElement::MethodDefinition(m.with_decorators(decorators))
+                }
+                (None, Some(function::ClassElement::FieldDefinition(field))) => {
+                    function::ClassElement::FieldDefinition(field.with_decorators(decorators))
+                }
+                (None, Some(function::ClassElement::StaticFieldDefinition(field))) => {
+                    function::ClassElement::StaticFieldDefinition(field.with_decorators(decorators))
+                }
+                (None, Some(function::ClassElement::PrivateFieldDefinition(field))) => {
+                    function::ClassElement::PrivateFieldDefinition(
+                        field.with_decorators(decorators),
+                    )
+                }
+                (None, Some(function::ClassElement::PrivateStaticFieldDefinition(field))) => {
+                    function::ClassElement::PrivateStaticFieldDefinition(
+                        field.with_decorators(decorators),
+                    )
+                }
+                _ => {
+                    return Err(Error::general(
+                        "decorators are not allowed on constructors or static blocks",
+                        position,
+                    ));
+                }
+            };
+            return Ok((None, Some(element)));
+        }
+
         let token = cursor.peek(0, interner).or_abrupt()?;
         let r#static = match token.kind() {
             TokenKind::Punctuator(Punctuator::Semicolon) => {
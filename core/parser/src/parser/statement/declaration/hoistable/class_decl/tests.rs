@@ -1,17 +1,19 @@
 use crate::parser::tests::check_script_parser;
+use crate::{Parser, Source};
 use boa_ast::{
-    Declaration, Expression, Span, Statement, StatementList, StatementListItem,
+    Declaration, Expression, Position, Span, Statement, StatementList, StatementListItem,
     declaration::{LexicalDeclaration, Variable, VariableList},
     expression::{
-        Call, Identifier, NewTarget,
+        Call, Identifier, NewTarget, Parenthesized,
         access::{PropertyAccess, SimplePropertyAccess},
         literal::Literal,
     },
     function::{
-        ClassDeclaration, ClassElement, ClassFieldDefinition, ClassMethodDefinition,
+        ClassDeclaration, ClassElement, ClassFieldDefinition, ClassMethodDefinition, Decorator,
         FormalParameterList, FunctionBody, FunctionExpression,
     },
     property::MethodDefinitionKind,
+    scope::Scope,
 };
 use boa_interner::Interner;
 use boa_macros::utf16;
@@ -255,3 +257,190 @@ fn check_non_reserved_keyword_as_identifier() {
         interner,
     );
 }
+
+#[test]
+fn check_decorated_class() {
+    let interner = &mut Interner::default();
+
+    check_script_parser(
+        "@sealed class A {}",
+        [Declaration::ClassDeclaration(
+            ClassDeclaration::new(
+                Identifier::new(
+                    interner.get_or_intern_static("A", utf16!("A")),
+                    Span::new((1, 15), (1, 16)),
+                ),
+                None,
+                None,
+                Box::default(),
+            )
+            .with_decorators(
+                vec![Decorator::new(
+                    Identifier::new(
+                        interner.get_or_intern_static("sealed", utf16!("sealed")),
+                        Span::new((1, 2), (1, 8)),
+                    )
+                    .into(),
+                )]
+                .into(),
+            )
+            .into(),
+        )
+        .into()],
+        interner,
+    );
+}
+
+#[test]
+fn check_decorated_method() {
+    let interner = &mut Interner::default();
+
+    let decorator = Call::new(
+        PropertyAccess::from(SimplePropertyAccess::new(
+            Identifier::new(
+                interner.get_or_intern_static("log", utf16!("log")),
+                Span::new((2, 6), (2, 9)),
+            )
+            .into(),
+            Identifier::new(
+                interner.get_or_intern_static("bind", utf16!("bind")),
+                Span::new((2, 10), (2, 14)),
+            ),
+        ))
+        .into(),
+        vec![Literal::new(1, Span::new((2, 15), (2, 16))).into()].into(),
+        Span::new((2, 14), (2, 17)),
+    );
+
+    let elements = vec![ClassElement::MethodDefinition(
+        ClassMethodDefinition::new(
+            boa_ast::function::ClassElementName::PropertyName(
+                Identifier::new(
+                    interner.get_or_intern_static("method", utf16!("method")),
+                    Span::new((2, 18), (2, 24)),
+                )
+                .into(),
+            ),
+            FormalParameterList::default(),
+            FunctionBody::new(StatementList::default(), Span::new((2, 27), (2, 29))),
+            MethodDefinitionKind::Ordinary,
+            false,
+            boa_ast::LinearPosition::default(),
+        )
+        .with_decorators(vec![Decorator::new(decorator.into())].into()),
+    )];
+
+    check_script_parser(
+        indoc! {"
+            class A {
+                @log.bind(1) method() {}
+            }
+        "},
+        [Declaration::ClassDeclaration(
+            ClassDeclaration::new(
+                Identifier::new(
+                    interner.get_or_intern_static("A", utf16!("A")),
+                    Span::new((1, 7), (1, 8)),
+                ),
+                None,
+                None,
+                elements.into(),
+            )
+            .into(),
+        )
+        .into()],
+        interner,
+    );
+}
+
+#[test]
+fn check_parenthesized_decorator() {
+    let interner = &mut Interner::default();
+
+    let decorator = Parenthesized::new(
+        Call::new(
+            Identifier::new(
+                interner.get_or_intern_static("cache", utf16!("cache")),
+                Span::new((2, 7), (2, 12)),
+            )
+            .into(),
+            vec![Literal::new(1, Span::new((2, 13), (2, 14))).into()].into(),
+            Span::new((2, 12), (2, 15)),
+        )
+        .into(),
+        Span::new((2, 6), (2, 16)),
+    );
+
+    let elements = vec![ClassElement::FieldDefinition(
+        ClassFieldDefinition::new(
+            Identifier::new(
+                interner.get_or_intern_static("x", utf16!("x")),
+                Span::new((2, 17), (2, 18)),
+            )
+            .into(),
+            Some(Literal::new(2, Span::new((2, 21), (2, 22))).into()),
+        )
+        .with_decorators(vec![Decorator::new(decorator.into())].into()),
+    )];
+
+    check_script_parser(
+        indoc! {"
+            class A {
+                @(cache(1)) x = 2;
+            }
+        "},
+        [Declaration::ClassDeclaration(
+            ClassDeclaration::new(
+                Identifier::new(
+                    interner.get_or_intern_static("A", utf16!("A")),
+                    Span::new((1, 7), (1, 8)),
+                ),
+                None,
+                None,
+                elements.into(),
+            )
+            .into(),
+        )
+        .into()],
+        interner,
+    );
+}
+
+#[test]
+fn check_decorator_without_class_or_element() {
+    for (source, position) in [
+        ("@dec ;", Position::new(1, 6)),
+        ("(@dec ;)", Position::new(1, 7)),
+        ("class A { @dec ; }", Position::new(1, 16)),
+        ("class A { @dec.x() }", Position::new(1, 20)),
+    ] {
+        let error = Parser::new(Source::from_bytes(source))
+            .parse_script(&Scope::new_global(), &mut Interner::default())
+            .expect_err("parsing should fail");
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "expected class or class element after decorator at line {}, col {}",
+                position.line_number(),
+                position.column_number()
+            ),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn check_parser_records_decorators() {
+    for (source, expected) in [
+        ("class A { m() {} }", false),
+        ("a = { at: 1 }; a.at", false),
+        ("@dec class A {}", true),
+        ("x = class { @dec m() {} }", true),
+    ] {
+        let mut parser = Parser::new(Source::from_bytes(source));
+        parser
+            .parse_script(&Scope::new_global(), &mut Interner::default())
+            .expect("parsing should succeed");
+        assert_eq!(parser.has_decorators(), expected, "{source}");
+    }
+}
@@ -193,6 +193,23 @@ pub(crate) fn allowed_token_after_let(token: Option<&Token>) -> bool {
     )
 }
 
+/// Checks if the given token is valid after the `using` keyword of a `using` declaration.
+///
+/// A `using` declaration only binds identifiers and there must be no line terminator between
+/// `using` and the binding, so the token has to be peeked with `peek_no_skip_line_term`.
+pub(crate) fn allowed_token_after_using(token: Option<&Token>) -> bool {
+    matches!(
+        token.map(Token::kind),
+        Some(
+            TokenKind::IdentifierName(_)
+                | TokenKind::Keyword((
+                    Keyword::Yield | Keyword::Let | Keyword::Async | Keyword::Of | Keyword::Using,
+                    _
+                ))
+        )
+    )
+}
+
 /// Parses a binding list.
 ///
 /// It will return an error if a `const` or `using` declaration is being parsed and there is no
@@ -20,11 +20,11 @@ pub(in crate::parser) use self::{
         ClassDeclaration, FunctionDeclaration, HoistableDeclaration, class_decl::ClassTail,
     },
     import::ImportDeclaration,
-    lexical::{LexicalDeclaration, allowed_token_after_let},
+    lexical::{LexicalDeclaration, allowed_token_after_let, allowed_token_after_using},
 };
 use crate::{
     Error,
-    lexer::TokenKind,
+    lexer::{TokenKind, token::ContainsEscapeSequence},
     parser::{AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser},
     source::ReadChar,
 };
@@ -71,7 +71,8 @@ where
         let tok_str = tok.to_string(interner);
 
         match tok_kind {
-            TokenKind::Keyword((Keyword::Function | Keyword::Async | Keyword::Class, _)) => {
+            TokenKind::Keyword((Keyword::Function | Keyword::Async | Keyword::Class, _))
+            | TokenKind::Punctuator(Punctuator::At) => {
                 HoistableDeclaration::new(self.allow_yield, self.allow_await, false)
                     .parse(cursor, interner)
             }
@@ -172,10 +173,15 @@ where
 
 /// Parses an optional `with` clause for import attributes.
 ///
+/// The legacy `assert { ... }` form is accepted as well, as long as the `assert` keyword
+/// is on the same line as the module specifier.
+///
 /// More information:
 ///  - [ECMAScript specification][spec]
+///  - [V8 documentation on the legacy `assert` form][v8]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-imports
+/// [v8]: https://v8.dev/features/import-attributes
 #[derive(Debug, Clone, Copy)]
 pub(in crate::parser) struct WithClause {
     context: &'static str,
@@ -196,11 +202,18 @@ where
     type Output = Box<[ImportAttribute]>;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let is_legacy_assert = cursor
+            .peek_no_skip_line_term(0, interner)?
+            .is_some_and(|tok| {
+                tok.kind()
+                    == &TokenKind::IdentifierName((Sym::ASSERT, ContainsEscapeSequence(false)))
+            });
+
         let Some(tok) = cursor.peek(0, interner)? else {
             return Ok(Box::default());
         };
 
-        if !matches!(tok.kind(), TokenKind::Keyword((Keyword::With, _))) {
+        if !is_legacy_assert && !matches!(tok.kind(), TokenKind::Keyword((Keyword::With, _))) {
             return Ok(Box::default());
         }
 
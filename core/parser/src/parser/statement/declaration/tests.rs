@@ -1,20 +1,24 @@
 use crate::parser::tests::{check_invalid_script, check_module_parser, check_script_parser};
 use crate::{Parser, Source};
 use boa_ast::{
-    Declaration, ModuleItem, Span, Statement,
+    Declaration, Expression, ModuleItem, Position, Span, Statement, StatementListItem,
     declaration::{
-        ExportDeclaration, ExportSpecifier, ImportAttribute, ImportDeclaration, ImportKind,
-        LexicalDeclaration, ModuleSpecifier, ReExportKind, VarDeclaration, Variable,
+        Binding, ExportDeclaration, ExportSpecifier, ImportAttribute, ImportDeclaration,
+        ImportKind, LexicalDeclaration, ModuleSpecifier, ReExportKind, VarDeclaration, Variable,
     },
     expression::{
-        Identifier,
+        Call, Identifier,
         literal::{Literal, LiteralKind},
+        operator::{Assign, assign::AssignOp},
     },
+    statement::{Block, If},
 };
 use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
 use indoc::indoc;
 
+const PSEUDO_LINEAR_POS: boa_ast::LinearPosition = boa_ast::LinearPosition::new(0);
+
 /// Checks `var` declaration parsing.
 #[test]
 fn var_declaration() {
@@ -635,6 +639,48 @@ fn import_duplicate_attribute_key() {
     );
 }
 
+/// Checks import declaration with the legacy `assert` clause.
+#[test]
+fn import_with_legacy_assert() {
+    let interner = &mut Interner::default();
+    let json = interner.get_or_intern_static("json", utf16!("json"));
+    let foo_json = interner.get_or_intern_static("./foo.json", utf16!("./foo.json"));
+    let type_sym = interner.get_or_intern_static("type", utf16!("type"));
+
+    check_module_parser(
+        r#"import json from "./foo.json" assert { type: "json" };"#,
+        vec![ModuleItem::ImportDeclaration(ImportDeclaration::new(
+            Some(Identifier::new(json, Span::new((1, 8), (1, 12)))),
+            ImportKind::DefaultOrUnnamed,
+            ModuleSpecifier::new(foo_json),
+            vec![ImportAttribute::new(type_sym, json)].into(),
+        ))],
+        interner,
+    );
+}
+
+/// Checks that the legacy `assert` clause must be on the same line as the module specifier,
+/// and that it rejects duplicate keys.
+#[test]
+fn import_invalid_legacy_assert() {
+    let scope = boa_ast::scope::Scope::new_global();
+
+    assert!(
+        Parser::new(Source::from_bytes(
+            "import json from \"./foo.json\"\nassert { type: \"json\" };"
+        ))
+        .parse_module(&scope, &mut Interner::default())
+        .is_err()
+    );
+    assert!(
+        Parser::new(Source::from_bytes(
+            r#"import json from "./foo.json" assert { type: "json", type: "css" };"#
+        ))
+        .parse_module(&scope, &mut Interner::default())
+        .is_err()
+    );
+}
+
 /// Checks that non-string attribute values are rejected.
 #[test]
 fn import_non_string_attribute_value() {
@@ -663,13 +709,13 @@ fn import_non_string_attribute_value() {
     );
 }
 
-/// Checks `using` declaration parsing.
+/// Checks `using` declaration parsing at the top level of a module.
 #[test]
 fn using_declaration() {
     let interner = &mut Interner::default();
-    check_script_parser(
+    check_module_parser(
         "using x = resource;",
-        vec![
+        vec![ModuleItem::StatementListItem(
             Declaration::Lexical(LexicalDeclaration::Using(
                 vec![Variable::from_identifier(
                     Identifier::new(
@@ -688,7 +734,7 @@ fn using_declaration() {
                 .unwrap(),
             ))
             .into(),
-        ],
+        )],
         interner,
     );
 }
@@ -697,9 +743,9 @@ fn using_declaration() {
 #[test]
 fn using_declaration_multiple() {
     let interner = &mut Interner::default();
-    check_script_parser(
+    check_module_parser(
         "using a = res1, b = res2;",
-        vec![
+        vec![ModuleItem::StatementListItem(
             Declaration::Lexical(LexicalDeclaration::Using(
                 vec![
                     Variable::from_identifier(
@@ -733,7 +779,7 @@ fn using_declaration_multiple() {
                 .unwrap(),
             ))
             .into(),
-        ],
+        )],
         interner,
     );
 }
@@ -741,7 +787,7 @@ fn using_declaration_multiple() {
 /// Checks that `using` declaration without initializer fails.
 #[test]
 fn using_declaration_no_init() {
-    check_invalid_script("using x;");
+    check_invalid_script("{ using x; }");
 }
 
 /// Checks `await using` declaration parsing in async function.
@@ -801,13 +847,27 @@ fn await_using_no_line_terminator() {
 /// The grammar uses ~Pattern parameter which means destructuring is NOT allowed.
 #[test]
 fn using_no_destructuring_object() {
-    check_invalid_script("using {x, y} = resource;");
+    check_invalid_script("{ using {x, y} = resource; }");
 }
 
-/// Checks that destructuring patterns are rejected for `using` declarations.
+/// Checks that `using [` starts a computed member expression instead of a declaration.
 #[test]
 fn using_no_destructuring_array() {
-    check_invalid_script("using [a, b] = resource;");
+    let interner = &mut Interner::default();
+    let scope = boa_ast::scope::Scope::new_global();
+    let script = Parser::new(Source::from_bytes("{ using [a, b] = resource; }"))
+        .parse_script(&scope, interner)
+        .unwrap();
+    let [StatementListItem::Statement(statement)] = script.statements().statements() else {
+        panic!("expected a single statement");
+    };
+    let Statement::Block(block) = &**statement else {
+        panic!("expected a block statement");
+    };
+    assert!(matches!(
+        block.statement_list().statements(),
+        [StatementListItem::Statement(statement)] if matches!(**statement, Statement::Expression(_))
+    ));
 }
 
 /// Checks that destructuring patterns are rejected for `await using` declarations.
@@ -825,13 +885,13 @@ fn await_using_no_destructuring_array() {
 /// Checks that `using let` is rejected (let is not allowed as a bound name).
 #[test]
 fn using_let_rejected() {
-    check_invalid_script("using let = resource;");
+    check_invalid_script("{ using let = resource; }");
 }
 
 /// Checks that duplicate names in `using` declarations are rejected.
 #[test]
 fn using_duplicate_names() {
-    check_invalid_script("using x = r1, x = r2;");
+    check_invalid_script("{ using x = r1, x = r2; }");
 }
 
 /// Checks that `await using` with duplicate names is rejected.
@@ -844,9 +904,9 @@ fn await_using_duplicate_names() {
 #[test]
 fn using_valid_identifiers() {
     let interner = &mut Interner::default();
-    check_script_parser(
+    check_module_parser(
         "using x = resource, y = resource2;",
-        vec![
+        vec![ModuleItem::StatementListItem(
             Declaration::Lexical(LexicalDeclaration::Using(
                 vec![
                     Variable::from_identifier(
@@ -880,7 +940,7 @@ fn using_valid_identifiers() {
                 .unwrap(),
             ))
             .into(),
-        ],
+        )],
         interner,
     );
 }
@@ -899,3 +959,160 @@ fn await_using_valid_identifiers() {
         result.err()
     );
 }
+
+/// Checks `using` declaration parsing inside a block.
+#[test]
+fn using_declaration_in_block() {
+    let interner = &mut Interner::default();
+    check_script_parser(
+        "{ using f = open(); }",
+        vec![
+            Statement::Block(Block::from((
+                vec![
+                    Declaration::Lexical(LexicalDeclaration::Using(
+                        vec![Variable::from_identifier(
+                            Identifier::new(
+                                interner.get_or_intern_static("f", utf16!("f")),
+                                Span::new((1, 9), (1, 10)),
+                            ),
+                            Some(
+                                Call::new(
+                                    Identifier::new(
+                                        interner.get_or_intern_static("open", utf16!("open")),
+                                        Span::new((1, 13), (1, 17)),
+                                    )
+                                    .into(),
+                                    Box::default(),
+                                    Span::new((1, 17), (1, 19)),
+                                )
+                                .into(),
+                            ),
+                        )]
+                        .try_into()
+                        .unwrap(),
+                    ))
+                    .into(),
+                ],
+                PSEUDO_LINEAR_POS,
+            )))
+            .into(),
+        ],
+        interner,
+    );
+}
+
+/// Checks `await using` declaration parsing inside an async function.
+#[test]
+fn await_using_declaration_in_async_function() {
+    let interner = &mut Interner::default();
+    let scope = boa_ast::scope::Scope::new_global();
+    let script = Parser::new(Source::from_bytes(
+        "async function h() { await using g = open(); }",
+    ))
+    .parse_script(&scope, interner)
+    .unwrap();
+    let [StatementListItem::Declaration(declaration)] = script.statements().statements() else {
+        panic!("expected a single declaration");
+    };
+    let Declaration::AsyncFunctionDeclaration(function) = &**declaration else {
+        panic!("expected an async function declaration");
+    };
+    let g = interner.get_or_intern_static("g", utf16!("g"));
+    assert!(matches!(
+        function.body().statements(),
+        [StatementListItem::Declaration(declaration)] if matches!(
+            &**declaration,
+            Declaration::Lexical(LexicalDeclaration::AwaitUsing(list))
+                if matches!(
+                    list.as_ref(),
+                    [variable] if matches!(variable.binding(), Binding::Identifier(ident) if ident.sym() == g)
+                )
+        )
+    ));
+}
+
+/// Checks that `using` declarations are rejected at the top level of a script.
+#[test]
+fn using_declaration_top_level_script() {
+    check_invalid_script("using x = resource;");
+    check_invalid_script("if (a) { } using x = resource;");
+
+    // The error is reported at the start of the offending declaration.
+    for (source, position) in [
+        ("if (a) { }\n  using x = resource;", Position::new(2, 3)),
+        ("f();  await using x = resource;", Position::new(1, 7)),
+    ] {
+        let error = Parser::new(Source::from_bytes(source))
+            .parse_script(
+                &boa_ast::scope::Scope::new_global(),
+                &mut Interner::default(),
+            )
+            .expect_err("parsing should fail");
+        assert_eq!(error.position(), Some(position), "{source}");
+    }
+}
+
+/// Checks `using` declarations in the head of `for` loops.
+#[test]
+fn using_declaration_for_head() {
+    let scope = boa_ast::scope::Scope::new_global();
+    for source in [
+        "for (using x of xs) {}",
+        "for (using x = open(); ; ) {}",
+        "async function f() { for (await using x of xs) {} }",
+    ] {
+        let result =
+            Parser::new(Source::from_bytes(source)).parse_script(&scope, &mut Interner::default());
+        assert!(
+            result.is_ok(),
+            "failed to parse `{source}`: {:?}",
+            result.err()
+        );
+    }
+    check_invalid_script("for (using x in xs) {}");
+    check_invalid_script("for (using x; ; ) {}");
+}
+
+/// Checks that `using` is still a plain identifier where it does not start a declaration.
+#[test]
+fn using_as_identifier() {
+    let interner = &mut Interner::default();
+    let using = interner.get_or_intern_static("using", utf16!("using"));
+    check_script_parser(
+        "if (a) using = 1;",
+        vec![
+            Statement::If(If::new(
+                Identifier::new(
+                    interner.get_or_intern_static("a", utf16!("a")),
+                    Span::new((1, 5), (1, 6)),
+                )
+                .into(),
+                Statement::Expression(Expression::from(Assign::new(
+                    AssignOp::Assign,
+                    Identifier::new(using, Span::new((1, 8), (1, 13))).into(),
+                    Literal::new(1, Span::new((1, 16), (1, 17))).into(),
+                ))),
+                None,
+            ))
+            .into(),
+        ],
+        interner,
+    );
+
+    let scope = boa_ast::scope::Scope::new_global();
+    for source in [
+        "var using = 1; using = 2;",
+        "using(x);",
+        "using\nx = 1;",
+        "for (using of xs) {}",
+        "for (using in xs) {}",
+    ] {
+        let result =
+            Parser::new(Source::from_bytes(source)).parse_script(&scope, &mut Interner::default());
+        assert!(
+            result.is_ok(),
+            "failed to parse `{source}`: {:?}",
+            result.err()
+        );
+    }
+}
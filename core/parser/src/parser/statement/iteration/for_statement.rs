@@ -9,13 +9,13 @@
 
 use crate::{
     Error,
-    lexer::{Error as LexError, TokenKind},
+    lexer::{Error as LexError, Token, TokenKind},
     parser::{
         AllowAwait, AllowReturn, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
         expression::{AssignmentExpression, Expression},
         statement::{
             Statement,
-            declaration::{LexicalDeclaration, allowed_token_after_let},
+            declaration::{LexicalDeclaration, allowed_token_after_let, allowed_token_after_using},
             variable::VariableDeclarationList,
         },
     },
@@ -131,6 +131,33 @@ where
                     .parse(cursor, interner)?
                     .into(),
             ),
+            // `for (using of ...)` is a `for...of` loop over the `using` identifier.
+            TokenKind::Keyword((Keyword::Using, false))
+                if allowed_token_after_using(cursor.peek_no_skip_line_term(1, interner)?)
+                    && !matches!(
+                        cursor.peek(1, interner).or_abrupt()?.kind(),
+                        TokenKind::Keyword((Keyword::Of, _))
+                    ) =>
+            {
+                Some(
+                    LexicalDeclaration::new(false, self.allow_yield, self.allow_await, true)
+                        .parse(cursor, interner)?
+                        .into(),
+                )
+            }
+            TokenKind::Keyword((Keyword::Await, false))
+                if self.allow_await.0
+                    && matches!(
+                        cursor.peek_no_skip_line_term(1, interner)?.map(Token::kind),
+                        Some(TokenKind::Keyword((Keyword::Using, false)))
+                    ) =>
+            {
+                Some(
+                    LexicalDeclaration::new(false, self.allow_yield, self.allow_await, true)
+                        .parse(cursor, interner)?
+                        .into(),
+                )
+            }
             TokenKind::Keyword((Keyword::Async, false)) if !r#await => {
                 if matches!(
                     cursor.peek(1, interner).or_abrupt()?.kind(),
@@ -190,6 +217,19 @@ where
                 }
 
                 let in_loop = kw == &Keyword::In;
+                if in_loop
+                    && let ForLoopInitializer::Lexical(decl) = &init
+                    && matches!(
+                        decl.declaration(),
+                        ast::declaration::LexicalDeclaration::Using(_)
+                            | ast::declaration::LexicalDeclaration::AwaitUsing(_)
+                    )
+                {
+                    return Err(Error::general(
+                        "'using' declarations are not allowed in the head of a for-in loop",
+                        position,
+                    ));
+                }
                 let init = initializer_to_iterable_loop_initializer(
                     init,
                     position,
@@ -258,16 +298,25 @@ where
             (init, _) => init,
         };
 
-        if let Some(ForLoopInitializer::Lexical(initializer)) = &init
-            && let ast::declaration::LexicalDeclaration::Const(list) = initializer.declaration()
-        {
-            for decl in list.as_ref() {
-                if decl.init().is_none() {
-                    return Err(Error::general(
-                        "Expected initializer for const declaration",
-                        position,
-                    ));
-                }
+        if let Some(ForLoopInitializer::Lexical(initializer)) = &init {
+            let decl_name = match initializer.declaration() {
+                ast::declaration::LexicalDeclaration::Const(_) => Some("const"),
+                ast::declaration::LexicalDeclaration::Using(_) => Some("using"),
+                ast::declaration::LexicalDeclaration::AwaitUsing(_) => Some("await using"),
+                ast::declaration::LexicalDeclaration::Let(_) => None,
+            };
+            if let Some(decl_name) = decl_name
+                && initializer
+                    .declaration()
+                    .variable_list()
+                    .as_ref()
+                    .iter()
+                    .any(|decl| decl.init().is_none())
+            {
+                return Err(Error::general(
+                    format!("Expected initializer for {decl_name} declaration"),
+                    position,
+                ));
             }
         }
 
@@ -26,7 +26,10 @@ use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::{Declaration, ExportDeclaration, ImportDeclaration, allowed_token_after_let},
+    declaration::{
+        Declaration, ExportDeclaration, ImportDeclaration, allowed_token_after_let,
+        allowed_token_after_using,
+    },
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
@@ -236,6 +239,7 @@ where
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-StatementList
 #[derive(Debug, Clone, Copy)]
+#[allow(clippy::struct_excessive_bools)]
 pub(super) struct StatementList {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
@@ -243,6 +247,8 @@ pub(super) struct StatementList {
     break_nodes: &'static [TokenKind],
     directive_prologues: bool,
     strict: bool,
+    error_recovery: bool,
+    reject_using: bool,
 }
 
 impl StatementList {
@@ -267,8 +273,26 @@ impl StatementList {
             break_nodes,
             directive_prologues,
             strict,
+            error_recovery: false,
+            reject_using: false,
         }
     }
+
+    /// Makes the parser record the errors of invalid statements and skip to the next
+    /// statement, instead of aborting the parse.
+    ///
+    /// The cursor must be in error recovery mode.
+    pub(super) const fn with_error_recovery(mut self, error_recovery: bool) -> Self {
+        self.error_recovery = error_recovery;
+        self
+    }
+
+    /// Makes the parser reject `using` and `await using` declarations directly contained
+    /// in the list, as required for the body of a script.
+    pub(super) const fn reject_using(mut self) -> Self {
+        self.reject_using = true;
+        self
+    }
 }
 
 impl<R> TokenParser<R> for StatementList
@@ -299,6 +323,7 @@ where
 
         loop {
             let peek_token = cursor.peek(0, interner)?;
+            let item_start = peek_token.map(|token| token.span().start());
             if let Some(peek_token) = peek_token {
                 linear_pos_end = peek_token.linear_span().end();
                 end_position = Some(peek_token.span().end());
@@ -315,9 +340,40 @@ where
                 _ => {}
             }
 
+            // Items such as classes make the cursor strict while they are parsed, which has to
+            // be undone if they fail to parse.
+            let item_strict = cursor.strict();
             let item =
-                StatementListItem::new(self.allow_yield, self.allow_await, self.allow_return)
-                    .parse(cursor, interner)?;
+                match StatementListItem::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor, interner)
+                {
+                    Ok(item) => item,
+                    Err(error) if self.error_recovery => {
+                        cursor.set_strict(item_strict);
+                        cursor.report_error(error)?;
+                        items.push(ast::Statement::Error.into());
+                        if let Err(error) = cursor.skip_to_statement_boundary(interner) {
+                            cursor.report_error(error)?;
+                            break;
+                        }
+                        continue;
+                    }
+                    Err(error) => return Err(error),
+                };
+
+            if self.reject_using
+                && let ast::StatementListItem::Declaration(decl) = &item
+                && let ast::Declaration::Lexical(
+                    ast::declaration::LexicalDeclaration::Using(_)
+                    | ast::declaration::LexicalDeclaration::AwaitUsing(_),
+                ) = decl.as_ref()
+                && let Some(position) = item_start
+            {
+                cursor.report_error(Error::general(
+                    "'using' declarations are not allowed at the top level of a script",
+                    position,
+                ))?;
+            }
 
             if directive_prologues {
                 if let ast::StatementListItem::Statement(statement) = &item {
@@ -424,12 +480,12 @@ where
         let tok = cursor.peek(0, interner).or_abrupt()?;
 
         match tok.kind().clone() {
-            TokenKind::Keyword((
-                Keyword::Function | Keyword::Class | Keyword::Const | Keyword::Using,
-                _,
-            )) => Declaration::new(self.allow_yield, self.allow_await)
-                .parse(cursor, interner)
-                .map(ast::StatementListItem::from),
+            TokenKind::Keyword((Keyword::Function | Keyword::Class | Keyword::Const, _))
+            | TokenKind::Punctuator(Punctuator::At) => {
+                Declaration::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)
+                    .map(ast::StatementListItem::from)
+            }
             TokenKind::Keyword((Keyword::Let, false))
                 if allowed_token_after_let(cursor.peek(1, interner)?) =>
             {
@@ -437,6 +493,13 @@ where
                     .parse(cursor, interner)
                     .map(ast::StatementListItem::from)
             }
+            TokenKind::Keyword((Keyword::Using, _))
+                if allowed_token_after_using(cursor.peek_no_skip_line_term(1, interner)?) =>
+            {
+                Declaration::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)
+                    .map(ast::StatementListItem::from)
+            }
             TokenKind::Keyword((Keyword::Await, false)) => {
                 // Check if this is `await using`
                 // Per spec, there must be [no LineTerminator here] between `await` and `using`
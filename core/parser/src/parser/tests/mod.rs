@@ -4,10 +4,10 @@ mod format;
 
 use std::convert::TryInto;
 
-use crate::{Parser, Source};
+use crate::{Parser, Source, lexer::CommentKind};
 use boa_ast::{
     Expression, LinearPosition, LinearSpan, Module, ModuleItem, ModuleItemList, Script, Span,
-    Statement, StatementList, StatementListItem,
+    Spanned, Statement, StatementList, StatementListItem,
     declaration::{Declaration, LexicalDeclaration, VarDeclaration, Variable},
     expression::{
         Call, Identifier, New, Parenthesized,
@@ -776,6 +776,50 @@ fn hashbang_comment() {
     check_script_parser(r"#!Comment Here", vec![], &mut Interner::default());
 }
 
+#[test]
+fn hashbang_line() {
+    let expected = vec![
+        Statement::Expression(
+            Binary::new(
+                ArithmeticOp::Add.into(),
+                Literal::new(1, Span::new((2, 1), (2, 2))).into(),
+                Literal::new(1, Span::new((2, 3), (2, 4))).into(),
+            )
+            .into(),
+        )
+        .into(),
+    ];
+    check_script_parser(
+        "#!/usr/bin/node\n1+1",
+        expected.clone(),
+        &mut Interner::default(),
+    );
+
+    let interner = &mut Interner::default();
+    let mut module = Module::new(ModuleItemList::from(
+        expected
+            .into_iter()
+            .map(ModuleItem::StatementListItem)
+            .collect::<Box<[_]>>(),
+    ));
+    module
+        .analyze_scope(&Scope::new_global(), interner)
+        .expect("failed to analyze");
+    assert_eq!(
+        Parser::new(Source::from_bytes("#!/usr/bin/node\n1+1"))
+            .parse_module(&Scope::new_global(), interner)
+            .expect("failed to parse"),
+        module,
+    );
+}
+
+#[test]
+fn hashbang_not_at_start() {
+    check_invalid_script("1+1\n#!/usr/bin/node");
+    check_invalid_script(" #!/usr/bin/node\n1+1");
+    check_invalid_script("\n#!/usr/bin/node\n1+1");
+}
+
 #[test]
 fn deny_unicode_escape_in_false_expression() {
     check_invalid_script(r"let x = f\u{61}lse;");
@@ -830,3 +874,113 @@ fn invalid_arrow_function() {
     check_invalid_script(r#"(!()=>"#);
     check_invalid_script(r#"!()=>{}"#);
 }
+
+#[test]
+fn error_recovery() {
+    let interner = &mut Interner::default();
+    let (script, errors) = Parser::new(Source::from_bytes(indoc! {"
+        let a = 1;
+        let b = ;
+        let c = a + 2;
+        if (c { }
+        let d = c * 3;
+    "}))
+    .parse_script_with_recovery(&Scope::new_global(), interner);
+
+    assert_eq!(errors.len(), 2, "unexpected errors: {errors:?}");
+    assert!(errors[0].to_string().contains("at line 2"), "{}", errors[0]);
+    assert!(errors[1].to_string().contains("at line 4"), "{}", errors[1]);
+
+    let names = boa_ast::operations::lexically_declared_names(&script)
+        .into_iter()
+        .map(|sym| interner.resolve_expect(sym).to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["a", "c", "d"]);
+
+    let errors = script
+        .statements()
+        .statements()
+        .iter()
+        .map(
+            |item| matches!(item, StatementListItem::Statement(stmt) if **stmt == Statement::Error),
+        )
+        .collect::<Vec<_>>();
+    assert_eq!(errors, [false, true, false, true, false]);
+}
+
+#[test]
+fn error_recovery_restores_strict_mode() {
+    let interner = &mut Interner::default();
+    let (script, errors) = Parser::new(Source::from_bytes(indoc! {"
+        class A { x = 1 + }
+        with ({}) {}
+        var let = 010;
+    "}))
+    .parse_script_with_recovery(&Scope::new_global(), interner);
+
+    assert_eq!(errors.len(), 1, "unexpected errors: {errors:?}");
+    assert_eq!(script.statements().statements().len(), 3);
+}
+
+#[test]
+fn error_recovery_valid_script() {
+    let interner = &mut Interner::default();
+    let (script, errors) = Parser::new(Source::from_bytes("let a = 1;\nlet b = a;"))
+        .parse_script_with_recovery(&Scope::new_global(), interner);
+
+    assert!(errors.is_empty());
+    assert_eq!(
+        script,
+        Parser::new(Source::from_bytes("let a = 1;\nlet b = a;"))
+            .parse_script(&Scope::new_global(), interner)
+            .unwrap()
+    );
+}
+
+#[test]
+fn preserve_comments() {
+    let interner = &mut Interner::default();
+    let mut parser = Parser::new(Source::from_bytes(indoc! {"
+        // leading
+        let a = /* inline */ 1;
+        /* multi
+           line */
+    "}));
+    parser.set_preserve_comments();
+    let script = parser.parse_script(&Scope::new_global(), interner).unwrap();
+    assert_eq!(script.statements().statements().len(), 1);
+
+    let comments = parser
+        .take_comments()
+        .into_iter()
+        .map(|comment| (comment.kind(), comment.text().to_string(), comment.span()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        comments,
+        [
+            (
+                CommentKind::Line,
+                "// leading".to_string(),
+                Span::new((1, 1), (1, 11))
+            ),
+            (
+                CommentKind::Block,
+                "/* inline */".to_string(),
+                Span::new((2, 9), (2, 21))
+            ),
+            (
+                CommentKind::Block,
+                "/* multi\n   line */".to_string(),
+                Span::new((3, 1), (4, 11))
+            ),
+        ]
+    );
+}
+
+#[test]
+fn comments_not_preserved_by_default() {
+    let interner = &mut Interner::default();
+    let mut parser = Parser::new(Source::from_bytes("// comment\nlet a = 1;"));
+    parser.parse_script(&Scope::new_global(), interner).unwrap();
+    assert!(parser.take_comments().is_empty());
+}
@@ -1,7 +1,8 @@
 //! `AbortController` and `AbortSignal` Web API implementations.
 
+use crate::event::{EventListeners, JsEvent};
+use crate::interval;
 use boa_engine::class::Class;
-use boa_engine::job::GenericJob;
 use boa_engine::object::builtins::JsFunction;
 use boa_engine::realm::Realm;
 use boa_engine::{
@@ -37,78 +38,77 @@ impl CancellationToken {
     }
 }
 
-fn make_abort_error(context: &mut Context) -> JsValue {
+fn make_dom_error(name: JsString, message: &'static str, context: &mut Context) -> JsValue {
     let obj = JsNativeError::error()
-        .with_message("signal is aborted without reason")
+        .with_message(message)
         .into_opaque(context);
-    obj.set(js_string!("name"), js_string!("AbortError"), false, context)
-        .ok();
+    obj.set(js_string!("name"), name, false, context).ok();
     obj.into()
 }
 
+fn make_abort_error(context: &mut Context) -> JsValue {
+    make_dom_error(
+        js_string!("AbortError"),
+        "signal is aborted without reason",
+        context,
+    )
+}
+
+fn make_timeout_error(context: &mut Context) -> JsValue {
+    make_dom_error(js_string!("TimeoutError"), "signal timed out", context)
+}
+
 /// The JavaScript `AbortSignal` class.
 #[derive(Debug, Clone, JsData, Trace, Finalize)]
 pub struct JsAbortSignal {
     #[unsafe_ignore_trace]
     aborted: Cell<bool>,
     reason: GcRefCell<Option<JsValue>>,
-    listeners: GcRefCell<Vec<AbortEventListener>>,
+    listeners: EventListeners,
     #[unsafe_ignore_trace]
     cancel_token: CancellationToken,
 }
 
-#[derive(Debug, Clone, Trace, Finalize)]
-struct AbortEventListener {
-    event_type: JsString,
-    callback: JsFunction,
-}
-
 impl Default for JsAbortSignal {
     fn default() -> Self {
         Self {
             aborted: Cell::new(false),
             reason: GcRefCell::default(),
-            listeners: GcRefCell::default(),
+            listeners: EventListeners::default(),
             cancel_token: CancellationToken::new(),
         }
     }
 }
 
 impl JsAbortSignal {
-    /// # Errors
+    /// Aborts `signal` with `reason` and dispatches an `abort` event to it. Does
+    /// nothing if the signal is already aborted.
     ///
-    /// Returns an error if the signal has already been aborted.
-    pub fn signal_abort(&self, reason: JsValue, context: &mut Context) -> JsResult<()> {
-        if self.aborted.get() {
-            return Ok(());
-        }
-        self.aborted.set(true);
-        *self.reason.borrow_mut() = Some(reason);
-
-        let abort = js_string!("abort");
-        let listeners: Vec<JsFunction> = self
-            .listeners
-            .borrow()
-            .iter()
-            .filter(|listener| listener.event_type == abort)
-            .map(|listener| listener.callback.clone())
-            .collect();
-
-        let realm = context.realm().clone();
-        for listener in listeners {
-            context.enqueue_job(
-                GenericJob::new(
-                    move |context| {
-                        listener.call(&JsValue::undefined(), &[], context)?;
-                        Ok(JsValue::undefined())
-                    },
-                    realm.clone(),
-                )
-                .into(),
-            );
-        }
+    /// This takes the signal object rather than `&self`, since the object is the
+    /// target of the dispatched event.
+    ///
+    /// # Errors
+    /// Returns an error if the `abort` event cannot be dispatched.
+    pub fn signal_abort(
+        signal: &JsObject<Self>,
+        reason: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // The handlers may access the signal, so it must not stay borrowed while they run.
+        let listeners = {
+            let this = signal.borrow();
+            let this = this.data();
+            if this.aborted.get() {
+                return Ok(());
+            }
+            this.aborted.set(true);
+            *this.reason.borrow_mut() = Some(reason);
+            this.cancel_token.cancel();
+            this.listeners.clone()
+        };
 
-        self.cancel_token.cancel();
+        let event = JsEvent::create(js_string!("abort"), context);
+        listeners.dispatch(&signal.clone().upcast(), &event, context)?;
 
         Ok(())
     }
@@ -147,11 +147,80 @@ impl JsAbortSignal {
             .into())
     }
 
+    /// Returns an `AbortSignal` that is already aborted with the given reason.
+    ///
+    /// # Errors
+    /// Returns an error if the `AbortSignal` class is not registered.
+    #[boa(static)]
+    #[boa(rename = "abort")]
+    fn abort_static(reason: Option<JsValue>, context: &mut Context) -> JsResult<JsObject> {
+        let signal = Self::default();
+        signal.aborted.set(true);
+        *signal.reason.borrow_mut() = Some(reason.unwrap_or_else(|| make_abort_error(context)));
+        signal.cancel_token.cancel();
+        Class::from_data(signal, context)
+    }
+
+    /// Returns an `AbortSignal` that will be aborted with a `TimeoutError` after
+    /// `milliseconds` have elapsed.
+    ///
+    /// The timeout is scheduled through the [`interval`](crate::interval) module, but does
+    /// not keep the event loop running until it fires.
+    ///
+    /// # Errors
+    /// Returns an error if `milliseconds` is not a valid non-negative integer, or
+    /// if the `AbortSignal` class is not registered.
+    #[boa(static)]
+    fn timeout(milliseconds: JsValue, context: &mut Context) -> JsResult<JsObject> {
+        // WebIDL `[EnforceRange] unsigned long long`.
+        let milliseconds = milliseconds.to_number(context)?;
+        if !milliseconds.is_finite() || milliseconds < 0.0 {
+            return Err(
+                js_error!(TypeError: "AbortSignal.timeout: {} is not a valid delay", milliseconds),
+            );
+        }
+
+        let signal = Class::from_data(Self::default(), context)?;
+        let target = signal.clone();
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let delay = milliseconds.trunc() as u64;
+        interval::set_unref_timeout(
+            move |context| {
+                let reason = make_timeout_error(context);
+                if let Ok(signal) = target.downcast::<JsAbortSignal>() {
+                    JsAbortSignal::signal_abort(&signal, reason, context)?;
+                }
+                Ok(JsValue::undefined())
+            },
+            delay,
+            context,
+        )?;
+
+        Ok(signal)
+    }
+
     #[boa(getter)]
     fn aborted(&self) -> bool {
         self.aborted.get()
     }
 
+    #[boa(getter)]
+    fn onabort(&self) -> JsValue {
+        self.listeners
+            .handler(&js_string!("abort"))
+            .map_or_else(JsValue::null, Into::into)
+    }
+
+    #[boa(setter)]
+    #[boa(rename = "onabort")]
+    fn set_onabort(&self, value: JsValue) {
+        let handler = value
+            .as_object()
+            .and_then(|o| JsFunction::from_object(o.clone()))
+            .map(JsObject::from);
+        self.listeners.set_handler(js_string!("abort"), handler);
+    }
+
     #[boa(getter)]
     fn reason(&self, context: &mut Context) -> JsValue {
         self.abort_reason(context)
@@ -171,24 +240,11 @@ impl JsAbortSignal {
         callback: JsFunction,
         _context: &mut Context,
     ) {
-        {
-            let listeners = self.listeners.borrow();
-            if listeners.iter().any(|listener| {
-                listener.event_type == event_type && JsObject::equals(&listener.callback, &callback)
-            }) {
-                return;
-            }
-        }
-        self.listeners.borrow_mut().push(AbortEventListener {
-            event_type,
-            callback,
-        });
+        self.listeners.add(event_type, callback.into(), false);
     }
 
     fn remove_event_listener(&self, event_type: JsString, callback: JsFunction) {
-        self.listeners.borrow_mut().retain(|listener| {
-            listener.event_type != event_type || !JsObject::equals(&listener.callback, &callback)
-        });
+        self.listeners.remove(&event_type, &callback.into());
     }
 }
 
@@ -215,10 +271,10 @@ impl JsAbortController {
     fn abort(&self, reason: Option<JsValue>, context: &mut Context) -> JsResult<()> {
         let abort_reason = reason.unwrap_or_else(|| make_abort_error(context));
 
-        let Some(signal) = self.signal.downcast_ref::<JsAbortSignal>() else {
+        let Ok(signal) = self.signal.clone().downcast::<JsAbortSignal>() else {
             return Err(js_error!(TypeError: "AbortController: invalid signal object"));
         };
-        signal.signal_abort(abort_reason, context)
+        JsAbortSignal::signal_abort(&signal, abort_reason, context)
     }
 }
 
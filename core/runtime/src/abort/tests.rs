@@ -1,4 +1,8 @@
-use crate::test::{TestAction, run_test_actions};
+use crate::test::{TestAction, run_test_actions, run_test_actions_with};
+use boa_engine::Source;
+use boa_engine::context::ContextBuilder;
+use boa_engine::context::time::{FixedClock, JsDuration};
+use std::rc::Rc;
 
 #[test]
 fn abort_controller_exists() {
@@ -257,6 +261,45 @@ fn multiple_listeners() {
     ]);
 }
 
+#[test]
+fn abort_dispatches_an_event() {
+    run_test_actions([
+        TestAction::run(
+            r"
+            let ctrl = new AbortController();
+            let calls = [];
+            ctrl.signal.onabort = function(event) { calls.push(['onabort', this, event]); };
+            ctrl.signal.addEventListener('abort', function(event) {
+                calls.push(['listener', this, event]);
+                this.seen = true;
+            });
+            ctrl.abort();
+            ",
+        ),
+        TestAction::inspect_context(|ctx| {
+            ctx.run_jobs().unwrap();
+        }),
+        TestAction::run(
+            r"
+            if (calls.length !== 2 || calls[0][0] !== 'onabort' || calls[1][0] !== 'listener') {
+                throw new Error('unexpected calls: ' + calls.map(c => c[0]));
+            }
+            for (const [name, thisValue, event] of calls) {
+                if (thisValue !== ctrl.signal) {
+                    throw new Error(name + ' was not called with the signal as this');
+                }
+                if (!(event instanceof Event) || event.type !== 'abort' || event.target !== ctrl.signal) {
+                    throw new Error(name + ' did not receive an abort event');
+                }
+            }
+            if (calls[0][2] !== calls[1][2] || !ctrl.signal.seen) {
+                throw new Error('handlers did not share the dispatched event');
+            }
+            ",
+        ),
+    ]);
+}
+
 #[test]
 fn repeated_abort_is_idempotent() {
     run_test_actions([
@@ -325,3 +368,161 @@ fn reason_abort_error_when_aborted_without_reason() {
         "#,
     )]);
 }
+
+#[test]
+fn onabort_fires_once() {
+    run_test_actions([
+        TestAction::run(
+            r"
+            let ctrl = new AbortController();
+            let count = 0;
+            if (ctrl.signal.onabort !== null) {
+                throw new Error('onabort should be null initially');
+            }
+            ctrl.signal.onabort = function() { count += 1; };
+            ctrl.abort();
+            ctrl.abort();
+            ",
+        ),
+        TestAction::inspect_context(|ctx| {
+            ctx.run_jobs().unwrap();
+        }),
+        TestAction::run(
+            r"
+            if (count !== 1) {
+                throw new Error('onabort should fire exactly once, got: ' + count);
+            }
+            ",
+        ),
+    ]);
+}
+
+#[test]
+fn static_abort_returns_aborted_signal() {
+    run_test_actions([TestAction::run(
+        r#"
+        let sig = AbortSignal.abort("why");
+        if (!(sig instanceof AbortSignal) || sig.aborted !== true || sig.reason !== "why") {
+            throw new Error('AbortSignal.abort() should return an aborted signal');
+        }
+        if (AbortSignal.abort().reason.name !== "AbortError") {
+            throw new Error('AbortSignal.abort() default reason should be an AbortError');
+        }
+        "#,
+    )]);
+}
+
+#[test]
+fn static_timeout_aborts_after_delay() {
+    let clock = Rc::new(FixedClock::default());
+    let context = &mut ContextBuilder::default()
+        .clock(clock.clone())
+        .build()
+        .unwrap();
+    crate::abort::register(None, context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(
+                r"
+                let sig = AbortSignal.timeout(100);
+                let fired = false;
+                sig.addEventListener('abort', function() { fired = true; });
+                ",
+            ),
+            TestAction::inspect_context(move |ctx| {
+                // The pending timeout does not keep `run_jobs` waiting.
+                clock.forward(50);
+                ctx.run_jobs().unwrap();
+                let aborted = ctx.eval(Source::from_bytes("sig.aborted")).unwrap();
+                assert_eq!(aborted.as_boolean(), Some(false));
+
+                clock.forward(51);
+                ctx.run_jobs().unwrap();
+            }),
+            TestAction::run(
+                r#"
+                if (sig.aborted !== true || !fired) {
+                    throw new Error('signal should be aborted after the timeout');
+                }
+                if (sig.reason.name !== "TimeoutError") {
+                    throw new Error('reason should be a TimeoutError, got: ' + sig.reason.name);
+                }
+                "#,
+            ),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn static_timeout_is_a_pending_timer() {
+    let clock = Rc::new(FixedClock::default());
+    let context = &mut ContextBuilder::default()
+        .clock(clock.clone())
+        .build()
+        .unwrap();
+    crate::abort::register(None, context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run("let sig = AbortSignal.timeout(100);"),
+            TestAction::inspect_context(move |ctx| {
+                let timers = crate::interval::pending_timers(ctx);
+                assert_eq!(timers.len(), 1);
+                assert_eq!(timers[0].remaining, JsDuration::from_millis(100));
+
+                crate::interval::clear_all(ctx);
+                assert!(crate::interval::pending_timers(ctx).is_empty());
+
+                clock.forward(101);
+                ctx.run_jobs().unwrap();
+                let aborted = ctx.eval(Source::from_bytes("sig.aborted")).unwrap();
+                assert_eq!(aborted.as_boolean(), Some(false));
+            }),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn onabort_runs_at_its_registration_position() {
+    run_test_actions([
+        TestAction::inspect_context(|ctx| {
+            crate::event::set_exception_reporter(ctx, |_, _| {});
+        }),
+        TestAction::run(
+            r"
+            let ctrl = new AbortController();
+            let calls = [];
+            ctrl.signal.addEventListener('abort', () => calls.push('first'));
+            ctrl.signal.onabort = () => calls.push('replaced');
+            ctrl.signal.addEventListener('abort', () => {
+                calls.push('second');
+                throw new Error('listener error');
+            });
+            // Changing the handler keeps its position.
+            ctrl.signal.onabort = () => {
+                calls.push('onabort');
+                throw new Error('handler error');
+            };
+            ctrl.signal.addEventListener('abort', () => calls.push('third'));
+
+            // Exceptions are reported instead of being thrown by `abort`.
+            ctrl.abort();
+            if (calls.join() !== 'first,onabort,second,third') {
+                throw new Error('unexpected calls: ' + calls.join());
+            }
+
+            let other = new AbortController();
+            let count = 0;
+            other.signal.onabort = () => count++;
+            other.signal.onabort = null;
+            other.abort();
+            if (count !== 0 || other.signal.onabort !== null) {
+                throw new Error('a removed onabort handler was called');
+            }
+            ",
+        ),
+    ]);
+}
@@ -0,0 +1,430 @@
+//! Boa's implementation of the `Blob` and `File` Web API classes.
+//!
+//! A `Blob` is an immutable chunk of bytes with a MIME type. The bytes are shared between a
+//! blob and the blobs created from it with `slice()`, so slicing never copies data.
+//!
+//! More information:
+//!  - [File API specification][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://w3c.github.io/FileAPI/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Blob
+
+#[cfg(test)]
+mod tests;
+
+use crate::stream::JsReadableStream;
+use crate::text::encodings;
+use boa_engine::builtins::array_buffer::AlignedVec;
+use boa_engine::interop::JsThis;
+use boa_engine::object::builtins::{JsArrayBuffer, JsDataView, JsPromise, JsTypedArray};
+use boa_engine::realm::Realm;
+use boa_engine::value::TryFromJs;
+use boa_engine::{
+    Context, Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace, boa_class,
+    boa_module, js_error,
+};
+use std::ops::Range;
+use std::sync::Arc;
+
+/// Options for the [`JsBlob`] constructor.
+///
+/// See <https://w3c.github.io/FileAPI/#dfn-BlobPropertyBag>
+#[derive(Debug, Default, Clone, TryFromJs)]
+pub struct BlobPropertyBag {
+    #[boa(rename = "type")]
+    content_type: Option<JsString>,
+}
+
+/// Options for the [`JsFile`] constructor.
+///
+/// See <https://w3c.github.io/FileAPI/#dfn-FilePropertyBag>
+#[derive(Debug, Default, Clone, TryFromJs)]
+pub struct FilePropertyBag {
+    #[boa(rename = "type")]
+    content_type: Option<JsString>,
+    #[boa(rename = "lastModified")]
+    last_modified: Option<f64>,
+}
+
+/// Normalizes a MIME type given to a blob.
+///
+/// Types containing characters outside of the printable ASCII range are replaced by the
+/// empty string, all others are converted to ASCII lowercase.
+fn normalize_type(content_type: Option<&JsString>) -> JsString {
+    let Some(content_type) = content_type else {
+        return JsString::default();
+    };
+
+    if content_type.iter().any(|c| !(0x20..=0x7E).contains(&c)) {
+        return JsString::default();
+    }
+
+    let lowercase = content_type
+        .iter()
+        .map(|c| {
+            if (0x41..=0x5A).contains(&c) {
+                c + 0x20
+            } else {
+                c
+            }
+        })
+        .collect::<Vec<_>>();
+    JsString::from(lowercase.as_slice())
+}
+
+/// Resolves a relative `slice()` index against the size of a blob.
+///
+/// The index is converted like a Web IDL `[Clamp] long long`, rounding to the nearest
+/// integer with ties to even.
+fn relative_index(index: Option<f64>, size: usize, default: usize) -> usize {
+    let Some(index) = index else {
+        return default;
+    };
+
+    // Float to integer casts saturate, and `NaN` is cast to zero.
+    let index = index.round_ties_even();
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    if index < 0.0 {
+        size.saturating_sub((-index) as usize)
+    } else {
+        (index as usize).min(size)
+    }
+}
+
+/// Collects the bytes of a list of blob parts, following the "process blob parts"
+/// algorithm.
+///
+/// Each part can be a `Blob`, an `ArrayBuffer`, a `TypedArray`, a `DataView` or any other
+/// value, which is converted to a string and encoded as UTF-8.
+///
+/// See <https://w3c.github.io/FileAPI/#process-blob-parts>
+fn process_blob_parts(parts: &[JsValue], context: &mut Context) -> JsResult<Vec<u8>> {
+    let mut bytes = Vec::new();
+
+    for part in parts {
+        if let Some(object) = part.as_object() {
+            if let Ok(blob) = JsBlob::try_from_js(part, context) {
+                bytes.extend_from_slice(blob.bytes());
+                continue;
+            }
+
+            let (buffer, range) = if let Ok(buffer) = JsArrayBuffer::from_object(object.clone()) {
+                (buffer, None)
+            } else if let Ok(typed_array) = JsTypedArray::from_object(object.clone()) {
+                let offset = typed_array.byte_offset(context)?;
+                let length = typed_array.byte_length(context)?;
+                let buffer = typed_array
+                    .buffer(context)?
+                    .as_object()
+                    .ok_or_else(|| js_error!(TypeError: "Invalid buffer backing TypedArray."))?;
+                (
+                    JsArrayBuffer::from_object(buffer)?,
+                    Some(offset..offset + length),
+                )
+            } else if let Ok(data_view) = JsDataView::from_object(object.clone()) {
+                let offset = usize::try_from(data_view.byte_offset(context)?).map_err(
+                    |_| js_error!(RangeError: "DataView offset exceeds addressable size."),
+                )?;
+                let length = usize::try_from(data_view.byte_length(context)?).map_err(
+                    |_| js_error!(RangeError: "DataView length exceeds addressable size."),
+                )?;
+                let buffer = data_view
+                    .buffer(context)?
+                    .as_object()
+                    .ok_or_else(|| js_error!(TypeError: "Invalid buffer backing DataView."))?;
+                (
+                    JsArrayBuffer::from_object(buffer)?,
+                    Some(offset..offset + length),
+                )
+            } else {
+                let string = part.to_string(context)?;
+                bytes.extend(encodings::utf8::encode(&string));
+                continue;
+            };
+
+            let data = buffer
+                .data()
+                .ok_or_else(|| js_error!(TypeError: "cannot read a detached ArrayBuffer"))?;
+            let data = match range {
+                Some(range) => data.get(range).ok_or_else(
+                    || js_error!(RangeError: "The range for the underlying ArrayBuffer can not be accessed."),
+                )?,
+                None => &data,
+            };
+            bytes.extend_from_slice(data);
+        } else {
+            let string = part.to_string(context)?;
+            bytes.extend(encodings::utf8::encode(&string));
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// The JavaScript `Blob` class.
+///
+/// See the [MDN documentation][mdn] for more information.
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Blob
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsBlob {
+    #[unsafe_ignore_trace]
+    data: Arc<[u8]>,
+    #[unsafe_ignore_trace]
+    range: Range<usize>,
+    r#type: JsString,
+}
+
+impl JsBlob {
+    /// Create a new blob from its bytes and its MIME type.
+    #[must_use]
+    pub fn new(data: impl Into<Arc<[u8]>>, r#type: JsString) -> Self {
+        let data = data.into();
+        let range = 0..data.len();
+        Self {
+            data,
+            range,
+            r#type,
+        }
+    }
+
+    /// Returns the bytes of this blob.
+    #[must_use]
+    pub fn bytes(&self) -> &[u8] {
+        &self.data[self.range.clone()]
+    }
+
+    /// Returns the MIME type of this blob, or the empty string if it is unknown.
+    #[must_use]
+    pub fn content_type(&self) -> JsString {
+        self.r#type.clone()
+    }
+}
+
+impl TryFromJs for JsBlob {
+    /// Converts a `Blob` or `File` instance to its blob data.
+    fn try_from_js(value: &JsValue, _context: &mut Context) -> JsResult<Self> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| js_error!(TypeError: "value is not a Blob"))?;
+
+        if let Some(blob) = object.downcast_ref::<Self>() {
+            return Ok(blob.clone());
+        }
+        if let Some(file) = object.downcast_ref::<JsFile>() {
+            return Ok(file.blob.clone());
+        }
+
+        Err(js_error!(TypeError: "value is not a Blob"))
+    }
+}
+
+#[boa_class(rename = "Blob")]
+#[boa(rename_all = "camelCase")]
+impl JsBlob {
+    /// Creates a new `Blob` from a list of parts.
+    ///
+    /// See <https://w3c.github.io/FileAPI/#constructorBlob>
+    #[boa(constructor)]
+    fn constructor(
+        parts: Option<Vec<JsValue>>,
+        options: Option<BlobPropertyBag>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let bytes = process_blob_parts(parts.as_deref().unwrap_or_default(), context)?;
+        let r#type = normalize_type(options.and_then(|o| o.content_type).as_ref());
+
+        Ok(Self::new(bytes, r#type))
+    }
+
+    /// Returns the size of the blob, in bytes.
+    ///
+    /// See <https://w3c.github.io/FileAPI/#dfn-size>
+    #[boa(getter)]
+    fn size(JsThis(blob): JsThis<Self>) -> usize {
+        blob.range.len()
+    }
+
+    /// Returns the MIME type of the blob.
+    ///
+    /// See <https://w3c.github.io/FileAPI/#dfn-type>
+    #[boa(getter)]
+    #[boa(rename = "type")]
+    fn r#type(JsThis(blob): JsThis<Self>) -> JsString {
+        blob.r#type.clone()
+    }
+
+    /// Returns a new `Blob` containing the bytes in the given range, sharing the data
+    /// of this blob.
+    ///
+    /// See <https://w3c.github.io/FileAPI/#dfn-slice>
+    #[boa(method)]
+    fn slice(
+        JsThis(blob): JsThis<Self>,
+        start: Option<f64>,
+        end: Option<f64>,
+        content_type: Option<JsString>,
+    ) -> Self {
+        let size = blob.range.len();
+        let start = relative_index(start, size, 0);
+        let end = relative_index(end, size, size).max(start);
+
+        Self {
+            range: blob.range.start + start..blob.range.start + end,
+            data: blob.data.clone(),
+            r#type: normalize_type(content_type.as_ref()),
+        }
+    }
+
+    /// Returns a promise resolving to the contents of the blob, decoded as UTF-8.
+    ///
+    /// See <https://w3c.github.io/FileAPI/#dom-blob-text>
+    #[boa(method)]
+    fn text(JsThis(blob): JsThis<Self>, context: &mut Context) -> JsPromise {
+        JsPromise::from_async_fn(
+            async move |_| Ok(encodings::utf8::decode(blob.bytes(), true).into()),
+            context,
+        )
+    }
+
+    /// Returns a `ReadableStream` of the blob's bytes, sharing the data of this blob.
+    ///
+    /// See <https://w3c.github.io/FileAPI/#dom-blob-stream>
+    #[boa(method)]
+    fn stream(JsThis(blob): JsThis<Self>, context: &mut Context) -> JsResult<JsObject> {
+        JsReadableStream::from_bytes(blob.data.clone(), blob.range.clone()).into_object(context)
+    }
+
+    /// Returns a promise resolving to an `ArrayBuffer` with a copy of the blob's bytes.
+    ///
+    /// See <https://w3c.github.io/FileAPI/#dom-blob-arraybuffer>
+    #[boa(method)]
+    fn array_buffer(JsThis(blob): JsThis<Self>, context: &mut Context) -> JsPromise {
+        JsPromise::from_async_fn(
+            async move |context| {
+                let data = AlignedVec::from_slice(0, blob.bytes());
+                JsArrayBuffer::from_byte_block(data, &mut context.borrow_mut()).map(Into::into)
+            },
+            context,
+        )
+    }
+}
+
+/// The JavaScript `File` class, a [`JsBlob`] with a name and a modification date.
+///
+/// See the [MDN documentation][mdn] for more information.
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/File
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsFile {
+    blob: JsBlob,
+    name: JsString,
+    #[unsafe_ignore_trace]
+    last_modified: i64,
+}
+
+impl JsFile {
+    /// Create a new file from its blob data, name and last modification date, in
+    /// milliseconds since the Unix epoch.
+    #[must_use]
+    pub fn new(blob: JsBlob, name: JsString, last_modified: i64) -> Self {
+        Self {
+            blob,
+            name,
+            last_modified,
+        }
+    }
+
+    /// Returns the blob data of this file.
+    #[must_use]
+    pub fn blob(&self) -> &JsBlob {
+        &self.blob
+    }
+}
+
+#[boa_class(rename = "File")]
+#[boa(rename_all = "camelCase")]
+impl JsFile {
+    /// Creates a new `File` from a list of parts and a file name.
+    ///
+    /// See <https://w3c.github.io/FileAPI/#file-constructor>
+    #[boa(constructor)]
+    fn constructor(
+        parts: Vec<JsValue>,
+        name: Option<JsValue>,
+        options: Option<FilePropertyBag>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let name = name.ok_or_else(
+            || js_error!(TypeError: "Failed to construct 'File': 2 arguments required"),
+        )?;
+        let bytes = process_blob_parts(&parts, context)?;
+        let name = name.to_string(context)?;
+        let options = options.unwrap_or_default();
+        let r#type = normalize_type(options.content_type.as_ref());
+
+        #[allow(clippy::cast_possible_truncation)]
+        let last_modified = options.last_modified.map_or_else(
+            || context.clock().system_time_millis(),
+            |last_modified| {
+                if last_modified.is_finite() {
+                    last_modified.trunc() as i64
+                } else {
+                    0
+                }
+            },
+        );
+
+        Ok(Self::new(JsBlob::new(bytes, r#type), name, last_modified))
+    }
+
+    /// Returns the name of the file.
+    ///
+    /// See <https://w3c.github.io/FileAPI/#dfn-name>
+    #[boa(getter)]
+    #[must_use]
+    pub fn name(&self) -> JsString {
+        self.name.clone()
+    }
+
+    /// Returns the last modification date of the file, in milliseconds since the Unix epoch.
+    ///
+    /// See <https://w3c.github.io/FileAPI/#dfn-lastModified>
+    #[boa(getter)]
+    #[must_use]
+    pub fn last_modified(&self) -> i64 {
+        self.last_modified
+    }
+}
+
+/// JavaScript module containing the `Blob` and `File` classes.
+#[boa_module]
+pub mod js_module {
+    type Blob = super::JsBlob;
+    type File = super::JsFile;
+}
+
+/// Register the `Blob` and `File` classes into the realm/context, along with the
+/// `ReadableStream` classes returned by `Blob.prototype.stream()`.
+///
+/// `File` inherits from `Blob`, so blob methods like `slice()` and `text()` can be used on
+/// files.
+///
+/// # Errors
+/// This will error if the context or realm cannot register the classes.
+pub fn register(realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+    crate::stream::register(realm.clone(), context)?;
+    js_module::boa_register(realm.clone(), context)?;
+
+    let realm = realm.unwrap_or_else(|| context.realm().clone());
+    let (Some(blob), Some(file)) = (realm.get_class::<JsBlob>(), realm.get_class::<JsFile>())
+    else {
+        return Err(js_error!(Error: "the Blob and File classes were not registered"));
+    };
+
+    file.prototype().set_prototype(Some(blob.prototype()));
+    file.constructor().set_prototype(Some(blob.constructor()));
+
+    Ok(())
+}
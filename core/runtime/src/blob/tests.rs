@@ -0,0 +1,157 @@
+use crate::test::{TestAction, run_test_actions};
+use boa_engine::js_str;
+
+/// Awaits the promise stored in the `result` global, panicking if it rejects.
+fn await_result() -> TestAction {
+    TestAction::inspect_context(|ctx| {
+        let result = ctx.global_object().get(js_str!("result"), ctx).unwrap();
+        result.as_promise().unwrap().await_blocking(ctx).unwrap();
+    })
+}
+
+#[test]
+fn blob_size_and_slice_text() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                globalThis.result = (async () => {
+                    const blob = new Blob(["abc", "def"]);
+                    assertEq(blob.size, 6);
+                    assertEq(blob.type, "");
+                    assertEq(await blob.text(), "abcdef");
+                    assertEq(await blob.slice(1, 4).text(), "bcd");
+                    assertEq(await blob.slice(-2).text(), "ef");
+                    assertEq(await blob.slice(-0.5).text(), "abcdef");
+                    assertEq(await blob.slice(0, -0.5).text(), "");
+                    assertEq(await blob.slice(1.9, 4.9).text(), "cde");
+                    assertEq(await blob.slice(1.5, 3.5).text(), "cd");
+                    assertEq(await blob.slice(0.5, 2.5).text(), "ab");
+                    assertEq(await blob.slice(4, 2).text(), "");
+                    assertEq(await blob.slice(1, 5).slice(1, 3).text(), "cd");
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
+
+#[test]
+fn blob_parts_and_type() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                globalThis.result = (async () => {
+                    const bytes = new Uint8Array([0x61, 0x62, 0x63, 0x64]);
+                    const blob = new Blob(
+                        [bytes.subarray(1, 3), new Blob(["é"]), bytes.buffer, 1],
+                        { type: "Text/Plain" },
+                    );
+                    assertEq(blob.type, "text/plain");
+                    assertEq(blob.size, 9);
+                    assertEq(await blob.text(), "bcéabcd1");
+                    assertEq(blob.slice(0, 1, "é").type, "");
+
+                    const buffer = await blob.slice(0, 2).arrayBuffer();
+                    assertArrayEqual(Array.from(new Uint8Array(buffer)), [0x62, 0x63]);
+
+                    assertEq(new Blob().size, 0);
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
+
+#[test]
+fn file_extends_blob() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                globalThis.result = (async () => {
+                    const file = new File(["hello"], "hello.txt", {
+                        type: "text/plain",
+                        lastModified: 42,
+                    });
+                    assert(file instanceof File);
+                    assert(file instanceof Blob);
+                    assertEq(Object.getPrototypeOf(File), Blob);
+                    assertEq(file.name, "hello.txt");
+                    assertEq(file.lastModified, 42);
+                    assertEq(file.size, 5);
+                    assertEq(file.type, "text/plain");
+                    assertEq(await file.slice(1).text(), "ello");
+                    assertEq(await new Blob([file, "!"]).text(), "hello!");
+
+                    assertEq(typeof new File([], "empty").lastModified, "number");
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
+
+#[test]
+fn file_requires_a_name() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                for (const construct of [() => new File(), () => new File([])]) {
+                    try {
+                        construct();
+                        throw new Error("File should require two arguments");
+                    } catch (e) {
+                        if (!(e instanceof TypeError)) {
+                            throw e;
+                        }
+                    }
+                }
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn blob_stream() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                globalThis.result = (async () => {
+                    const blob = new Blob(["ab", "cd".repeat(40000)]).slice(1);
+                    const stream = blob.stream();
+                    assert(stream instanceof ReadableStream);
+
+                    const reader = stream.getReader();
+                    assert(stream.locked);
+                    const chunks = [];
+                    while (true) {
+                        const { value, done } = await reader.read();
+                        if (done) {
+                            break;
+                        }
+                        assert(value instanceof Uint8Array);
+                        chunks.push(value);
+                    }
+                    assertEq(chunks.length, 2);
+                    assertEq(chunks[0].length, 65536);
+
+                    const bytes = new Uint8Array(blob.size);
+                    let offset = 0;
+                    for (const chunk of chunks) {
+                        bytes.set(chunk, offset);
+                        offset += chunk.length;
+                    }
+                    assertEq(offset, blob.size);
+                    assertEq(new TextDecoder().decode(bytes), await blob.text());
+
+                    assertEq((await blob.stream().getReader().read()).value[0], 0x62);
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
@@ -30,7 +30,12 @@ use boa_gc::{Finalize, Trace};
 use comfy_table::{Cell, Table};
 use rustc_hash::FxHashMap;
 use std::{
-    cell::RefCell, collections::hash_map::Entry, fmt::Write as _, io::Write, rc::Rc,
+    cell::RefCell,
+    collections::hash_map::Entry,
+    fmt::Write as _,
+    io::{IsTerminal, Write},
+    rc::Rc,
+    sync::Mutex,
     time::SystemTime,
 };
 
@@ -118,7 +123,9 @@ pub trait Logger: Trace {
 /// Implements the [`Logger`] trait and output errors to stderr and all
 /// the others to stdout. Will add indentation based on the number of
 /// groups.
-#[derive(Debug, Trace, Finalize)]
+///
+/// Use a [`StreamLogger`] to redirect the output to other sinks or to color it.
+#[derive(Debug, Default, Trace, Finalize)]
 pub struct DefaultLogger;
 
 impl Logger for DefaultLogger {
@@ -145,6 +152,139 @@ impl Logger for DefaultLogger {
     }
 }
 
+/// A logger writing errors to one sink and all the other messages to another, like
+/// [`DefaultLogger`] does with the process' standard streams.
+///
+/// Messages can also be colored by level with [`StreamLogger::with_colors`].
+#[derive(Default, Trace, Finalize)]
+pub struct StreamLogger {
+    #[unsafe_ignore_trace]
+    writers: Option<Mutex<Writers>>,
+    colors: bool,
+}
+
+/// ANSI escape code coloring error messages in red.
+const ERROR_COLOR: &str = "\x1b[31m";
+/// ANSI escape code coloring warnings in yellow.
+const WARN_COLOR: &str = "\x1b[33m";
+/// ANSI escape code resetting the color.
+const RESET_COLOR: &str = "\x1b[0m";
+
+/// The sinks used by a [`StreamLogger`] created with [`StreamLogger::new`].
+struct Writers {
+    out: Box<dyn Write>,
+    err: Box<dyn Write>,
+}
+
+impl StreamLogger {
+    /// Creates a logger writing errors to `err` and all the other messages to `out`.
+    pub fn new(out: impl Write + 'static, err: impl Write + 'static) -> Self {
+        Self {
+            writers: Some(Mutex::new(Writers {
+                out: Box::new(out),
+                err: Box::new(err),
+            })),
+            colors: false,
+        }
+    }
+
+    /// Creates a logger writing errors to stderr and all the other messages to stdout.
+    #[must_use]
+    pub fn stdio() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether errors are colored in red and warnings in yellow using ANSI escape codes.
+    ///
+    /// Colors are never used when the `NO_COLOR` environment variable is set to a non-empty
+    /// value. When writing to the standard streams, colors are also disabled if the stream
+    /// is not a terminal.
+    #[must_use]
+    pub fn with_colors(mut self, colors: bool) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Returns whether a message written to stderr (if `error`) or stdout should be colored.
+    fn use_colors(&self, error: bool) -> bool {
+        if !self.colors || std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+            return false;
+        }
+
+        self.writers.is_some()
+            || if error {
+                std::io::stderr().is_terminal()
+            } else {
+                std::io::stdout().is_terminal()
+            }
+    }
+
+    fn write_line(
+        &self,
+        msg: &str,
+        indent: usize,
+        error: bool,
+        color: Option<&str>,
+    ) -> std::io::Result<()> {
+        let (color, reset) = match color {
+            Some(color) if self.use_colors(error) => (color, RESET_COLOR),
+            _ => ("", ""),
+        };
+
+        let Some(writers) = &self.writers else {
+            return if error {
+                writeln!(std::io::stderr(), "{color}{msg:>indent$}{reset}")
+            } else {
+                writeln!(std::io::stdout(), "{color}{msg:>indent$}{reset}")
+            };
+        };
+
+        let mut writers = writers
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let writer = if error {
+            &mut writers.err
+        } else {
+            &mut writers.out
+        };
+        writeln!(writer, "{color}{msg:>indent$}{reset}")
+    }
+}
+
+impl std::fmt::Debug for StreamLogger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamLogger")
+            .field("custom_writers", &self.writers.is_some())
+            .field("colors", &self.colors)
+            .finish()
+    }
+}
+
+impl Logger for StreamLogger {
+    #[inline]
+    fn log(&self, msg: String, state: &ConsoleState, _context: &mut Context) -> JsResult<()> {
+        self.write_line(&msg, state.indent(), false, None)
+            .map_err(JsError::from_rust)
+    }
+
+    #[inline]
+    fn info(&self, msg: String, state: &ConsoleState, context: &mut Context) -> JsResult<()> {
+        self.log(msg, state, context)
+    }
+
+    #[inline]
+    fn warn(&self, msg: String, state: &ConsoleState, _context: &mut Context) -> JsResult<()> {
+        self.write_line(&msg, state.indent(), false, Some(WARN_COLOR))
+            .map_err(JsError::from_rust)
+    }
+
+    #[inline]
+    fn error(&self, msg: String, state: &ConsoleState, _context: &mut Context) -> JsResult<()> {
+        self.write_line(&msg, state.indent(), true, Some(ERROR_COLOR))
+            .map_err(JsError::from_rust)
+    }
+}
+
 /// A logger that drops all logging. Useful for testing.
 #[derive(Debug, Trace, Finalize)]
 pub struct NullLogger;
@@ -239,6 +379,8 @@ fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
 
                             arg_index += 1;
                         }
+                        /* CSS styling: consume the style argument and emit nothing */
+                        'c' => arg_index += 1,
                         '%' => formatted.push('%'),
                         c => {
                             formatted.push('%');
@@ -310,6 +452,10 @@ pub struct Console {
     state: ConsoleState,
 }
 
+/// The state of the most recently initialized console, stored in the context data so
+/// it can be reset by [`Console::reset_state`].
+struct ConsoleStateRc(Rc<RefCell<Console>>);
+
 impl Console {
     /// Name of the built-in `console` property.
     pub const NAME: JsString = js_string!("console");
@@ -365,6 +511,7 @@ impl Console {
 
         let state = Rc::new(RefCell::new(Self::default()));
         let logger = Rc::new(logger);
+        context.insert_data(ConsoleStateRc(state.clone()));
 
         ObjectInitializer::with_native_data_and_proto(
             Self::default(),
@@ -484,6 +631,18 @@ impl Console {
         Self::init_with_logger(DefaultLogger, context)
     }
 
+    /// Resets the state of the console most recently initialized in `context`, closing all
+    /// the groups and clearing the counters and timers.
+    ///
+    /// An exception thrown between `console.group()` and `console.groupEnd()` leaves the
+    /// group open, indenting all the following logs. Embedders can call this after a
+    /// top-level evaluation throws to start over from a clean state.
+    pub fn reset_state(context: &Context) {
+        if let Some(ConsoleStateRc(console)) = context.get_data::<ConsoleStateRc>() {
+            console.borrow_mut().state = ConsoleState::default();
+        }
+    }
+
     /// `console.assert(condition, ...data)`
     ///
     /// Prints a JavaScript value to the standard error if first argument evaluates to `false` or there
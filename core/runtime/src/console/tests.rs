@@ -1,9 +1,11 @@
 use super::{Console, ConsoleState, formatter};
 use crate::test::{TestAction, run_test_actions, run_test_actions_with};
-use crate::{Logger, NullLogger};
-use boa_engine::{Context, JsError, JsResult, JsValue, js_string, property::Attribute};
+use crate::{Logger, NullLogger, StreamLogger};
+use boa_engine::{Context, JsError, JsResult, JsValue, Source, js_string, property::Attribute};
 use boa_gc::{Gc, GcRefCell};
 use indoc::indoc;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 #[test]
 fn formatter_no_args_is_empty_string() {
@@ -94,6 +96,26 @@ fn formatter_float_format_works() {
     })]);
 }
 
+#[test]
+fn formatter_strips_css_directives() {
+    run_test_actions([TestAction::inspect_context(|ctx| {
+        assert_eq!(
+            formatter(
+                &[
+                    JsValue::new(js_string!("%cHello %cworld%c!")),
+                    JsValue::new(js_string!("color: red")),
+                    JsValue::new(js_string!("font-weight: bold")),
+                    JsValue::new(js_string!("")),
+                    JsValue::new(1),
+                ],
+                ctx
+            )
+            .unwrap(),
+            "Hello world! 1"
+        );
+    })]);
+}
+
 #[test]
 fn console_log_cyclic() {
     let mut context = Context::default();
@@ -366,6 +388,65 @@ fn console_log_arguments() {
     );
 }
 
+/// A logger that records the indentation of each log message.
+#[derive(Clone, Debug, Default, boa_engine::Trace, boa_engine::Finalize)]
+struct IndentLogger {
+    #[unsafe_ignore_trace]
+    log: Rc<RefCell<Vec<(usize, String)>>>,
+}
+
+impl Logger for IndentLogger {
+    fn log(&self, msg: String, state: &ConsoleState, _: &mut Context) -> JsResult<()> {
+        self.log.borrow_mut().push((state.indent(), msg));
+        Ok(())
+    }
+
+    fn info(&self, msg: String, state: &ConsoleState, context: &mut Context) -> JsResult<()> {
+        self.log(msg, state, context)
+    }
+
+    fn warn(&self, msg: String, state: &ConsoleState, context: &mut Context) -> JsResult<()> {
+        self.log(msg, state, context)
+    }
+
+    fn error(&self, msg: String, state: &ConsoleState, context: &mut Context) -> JsResult<()> {
+        self.log(msg, state, context)
+    }
+}
+
+#[test]
+fn console_reset_state() {
+    let mut context = Context::default();
+    let logger = IndentLogger::default();
+    Console::register_with_logger(logger.clone(), &mut context).unwrap();
+
+    let result = context.eval(Source::from_bytes(indoc! {r#"
+        console.group("outer");
+        console.count();
+        throw new Error("oops");
+    "#}));
+    assert!(result.is_err());
+    Console::reset_state(&context);
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            console.log("after");
+            console.count();
+        "#})],
+        &mut context,
+    );
+
+    assert_eq!(
+        *logger.log.borrow(),
+        [
+            (0, "group: outer".to_string()),
+            (2, "count default: 1".to_string()),
+            (0, "after".to_string()),
+            (0, "count default: 1".to_string()),
+        ]
+    );
+}
+
 #[test]
 fn console_log_regexp() {
     let mut context = Context::default();
@@ -869,3 +950,129 @@ fn console_table_set_ignores_properties_filter() {
     assert!(logs.contains("(iteration index)"));
     assert!(logs.contains("Values"));
 }
+
+/// A `Write` sink sharing its buffer, so the test can read it back.
+#[derive(Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl std::io::Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn stream_logger_with_writers() {
+    let mut context = Context::default();
+    let out = SharedBuffer::default();
+    let err = SharedBuffer::default();
+    Console::register_with_logger(StreamLogger::new(out.clone(), err.clone()), &mut context)
+        .unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            console.log("hello");
+            console.error("oops");
+            console.info("info");
+        "#})],
+        &mut context,
+    );
+
+    assert_eq!(
+        String::from_utf8(out.0.borrow().clone()).unwrap(),
+        "hello\ninfo\n"
+    );
+    assert_eq!(String::from_utf8(err.0.borrow().clone()).unwrap(), "oops\n");
+}
+
+/// Logs a warning and an error to a `StreamLogger` with colors enabled, returning
+/// what was written to stdout and stderr.
+fn log_with_colors() -> (String, String) {
+    let mut context = Context::default();
+    let out = SharedBuffer::default();
+    let err = SharedBuffer::default();
+    Console::register_with_logger(
+        StreamLogger::new(out.clone(), err.clone()).with_colors(true),
+        &mut context,
+    )
+    .unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            console.log("hello");
+            console.warn("careful");
+            console.error("oops");
+        "#})],
+        &mut context,
+    );
+
+    let out = String::from_utf8(out.0.borrow().clone()).unwrap();
+    let err = String::from_utf8(err.0.borrow().clone()).unwrap();
+    (out, err)
+}
+
+#[test]
+fn stream_logger_with_colors() {
+    let (out, err) = temp_env::with_var("NO_COLOR", None::<&str>, log_with_colors);
+    assert_eq!(out, "hello\n\x1b[33mcareful\x1b[0m\n");
+    assert_eq!(err, "\x1b[31moops\x1b[0m\n");
+}
+
+#[test]
+fn stream_logger_strips_css_styling() {
+    let mut context = Context::default();
+    let out = SharedBuffer::default();
+    Console::register_with_logger(
+        StreamLogger::new(out.clone(), SharedBuffer::default()),
+        &mut context,
+    )
+    .unwrap();
+
+    run_test_actions_with(
+        [TestAction::run("console.log('%cHello', 'color:red');")],
+        &mut context,
+    );
+
+    let out = String::from_utf8(out.0.borrow().clone()).unwrap();
+    assert_eq!(out, "Hello\n");
+}
+
+#[test]
+fn stream_logger_no_color() {
+    let (out, err) = temp_env::with_var("NO_COLOR", Some("1"), log_with_colors);
+    assert_eq!(out, "hello\ncareful\n");
+    assert_eq!(err, "oops\n");
+}
+
+#[test]
+fn console_log_collections() {
+    let mut context = Context::default();
+    let logger = RecordingLogger::default();
+    Console::register_with_logger(logger.clone(), &mut context).unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            console.log(new Map([["a", 1], ["b", 2]]));
+            console.log(new Set([1, 2]));
+            console.log(new Uint8Array([1, 2, 3]));
+            console.log([new Map([["a", 1]]), new Set(), new Float64Array(2)]);
+        "#})],
+        &mut context,
+    );
+
+    let logs = logger.log.borrow().clone();
+    assert_eq!(
+        logs,
+        indoc! { r#"
+            Map { "a" → 1, "b" → 2 }
+            Set { 1, 2 }
+            Uint8Array(3) [ 1, 2, 3 ]
+            [ Map(1), Set(0), Float64Array(2) ]
+        "# }
+    );
+}
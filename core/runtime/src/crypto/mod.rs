@@ -0,0 +1,225 @@
+//! Boa's implementation of the `crypto` Web API object.
+//!
+//! Only the random number generation part of the [Web Crypto API][spec] is implemented.
+//! Randomness is sourced from a [`RandomSource`], which can be replaced by embedders
+//! (e.g. to use a deterministic seed in tests, or a platform-specific generator).
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://w3c.github.io/webcrypto/#crypto-interface
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Crypto
+
+#[cfg(test)]
+mod tests;
+
+use boa_engine::builtins::typed_array::TypedArrayKind;
+use boa_engine::native_function::NativeFunction;
+use boa_engine::object::ObjectInitializer;
+use boa_engine::object::builtins::{JsArrayBuffer, JsTypedArray};
+use boa_engine::property::Attribute;
+use boa_engine::{Context, JsObject, JsResult, JsString, JsSymbol, JsValue, js_error, js_string};
+use std::fmt::Write;
+
+/// The maximum number of bytes `getRandomValues` can fill in a single call.
+const MAX_RANDOM_BYTES: usize = 65536;
+
+/// A source of cryptographically secure random bytes used by the `crypto` object.
+pub trait RandomSource {
+    /// Fill `dest` with random bytes.
+    fn fill_bytes(&self, dest: &mut [u8]);
+}
+
+/// The default random source, backed by the operating system's generator.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StdRandomSource;
+
+impl RandomSource for StdRandomSource {
+    fn fill_bytes(&self, dest: &mut [u8]) {
+        rand::fill(dest);
+    }
+}
+
+/// The random source registered in the context.
+struct RandomSourceState(Box<dyn RandomSource>);
+
+/// Fill `dest` using the random source registered in the context.
+fn fill_random(dest: &mut [u8], context: &Context) -> JsResult<()> {
+    let source = context
+        .get_data::<RandomSourceState>()
+        .ok_or_else(|| js_error!(Error: "crypto random source is not registered"))?;
+    source.0.fill_bytes(dest);
+    Ok(())
+}
+
+/// Boa's implementation of the `crypto` object.
+#[derive(Debug, Clone, Copy)]
+pub struct Crypto;
+
+impl Crypto {
+    /// Name of the built-in `crypto` property.
+    pub const NAME: JsString = js_string!("crypto");
+
+    /// Initializes the `crypto` object with a custom random source.
+    ///
+    /// The random source is stored in the context, replacing any previously
+    /// registered one.
+    ///
+    /// # Errors
+    /// Returns an error if the object cannot be created.
+    pub fn init_with_source<R>(context: &mut Context, source: R) -> JsResult<JsObject>
+    where
+        R: RandomSource + 'static,
+    {
+        context.insert_data(RandomSourceState(Box::new(source)));
+
+        Ok(ObjectInitializer::new(context)
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Crypto"),
+                Attribute::CONFIGURABLE,
+            )
+            .function(
+                NativeFunction::from_fn_ptr(Self::get_random_values),
+                js_string!("getRandomValues"),
+                1,
+            )
+            .function(
+                NativeFunction::from_fn_ptr(Self::random_uuid),
+                js_string!("randomUUID"),
+                0,
+            )
+            .build())
+    }
+
+    /// Register the `crypto` object globally with a custom random source.
+    ///
+    /// # Errors
+    /// This function will return an error if the property cannot be defined on the global object.
+    pub fn register_with_source<R>(context: &mut Context, source: R) -> JsResult<()>
+    where
+        R: RandomSource + 'static,
+    {
+        let crypto = Self::init_with_source(context, source)?;
+        context.register_global_property(
+            Self::NAME,
+            crypto,
+            Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        )?;
+        Ok(())
+    }
+
+    /// Initializes the `crypto` object with the default random source.
+    ///
+    /// # Errors
+    /// Returns an error if the object cannot be created.
+    pub fn init(context: &mut Context) -> JsResult<JsObject> {
+        Self::init_with_source(context, StdRandomSource)
+    }
+
+    /// Register the `crypto` object globally with the default random source.
+    ///
+    /// # Errors
+    /// This function will return an error if the property cannot be defined on the global object.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        Self::register_with_source(context, StdRandomSource)
+    }
+
+    /// `crypto.getRandomValues(typedArray)`
+    ///
+    /// Fills an integer typed array with random values, and returns it.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Crypto/getRandomValues
+    fn get_random_values(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let value = args.first().cloned().unwrap_or_default();
+        let array = value
+            .as_object()
+            .and_then(|o| JsTypedArray::from_object(o.clone()).ok())
+            .ok_or_else(|| {
+                js_error!(TypeError: "TypeMismatchError: argument is not an integer typed array")
+            })?;
+
+        match array.kind() {
+            Some(
+                TypedArrayKind::Int8
+                | TypedArrayKind::Uint8
+                | TypedArrayKind::Uint8Clamped
+                | TypedArrayKind::Int16
+                | TypedArrayKind::Uint16
+                | TypedArrayKind::Int32
+                | TypedArrayKind::Uint32
+                | TypedArrayKind::BigInt64
+                | TypedArrayKind::BigUint64,
+            ) => {}
+            _ => {
+                return Err(
+                    js_error!(TypeError: "TypeMismatchError: argument is not an integer typed array"),
+                );
+            }
+        }
+
+        let byte_length = array.byte_length(context)?;
+        if byte_length > MAX_RANDOM_BYTES {
+            return Err(js_error!(
+                Error: "QuotaExceededError: the typed array byte length ({}) exceeds {} bytes",
+                byte_length,
+                MAX_RANDOM_BYTES
+            ));
+        }
+
+        let byte_offset = array.byte_offset(context)?;
+        let buffer = array
+            .buffer(context)?
+            .as_object()
+            .and_then(|o| JsArrayBuffer::from_object(o.clone()).ok())
+            .ok_or_else(
+                || js_error!(TypeError: "typed arrays backed by a shared buffer are not supported"),
+            )?;
+
+        let mut bytes = vec![0; byte_length];
+        fill_random(&mut bytes, context)?;
+
+        let mut data = buffer
+            .data_mut()
+            .ok_or_else(|| js_error!(TypeError: "the typed array buffer is detached"))?;
+        data[byte_offset..byte_offset + byte_length].copy_from_slice(&bytes);
+
+        Ok(value)
+    }
+
+    /// `crypto.randomUUID()`
+    ///
+    /// Returns a new, lowercased [RFC 4122][rfc] version 4 UUID string.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [rfc]: https://www.rfc-editor.org/rfc/rfc4122#section-4.4
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Crypto/randomUUID
+    fn random_uuid(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let mut bytes = [0u8; 16];
+        fill_random(&mut bytes, context)?;
+
+        // Set the version (4) and the variant (10xx) bits.
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        let mut uuid = String::with_capacity(36);
+        for (i, byte) in bytes.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                uuid.push('-');
+            }
+            // Writing to a `String` cannot fail.
+            let _ = write!(uuid, "{byte:02x}");
+        }
+
+        Ok(JsString::from(uuid).into())
+    }
+}
@@ -0,0 +1,118 @@
+use crate::crypto::{Crypto, RandomSource};
+use crate::test::{TestAction, run_test_actions, run_test_actions_with};
+use boa_engine::{Context, JsValue, Source, js_string};
+use indoc::indoc;
+use std::cell::Cell;
+
+/// A deterministic random source, filling buffers with an incrementing counter.
+#[derive(Debug, Default)]
+struct CountingSource(Cell<u8>);
+
+impl RandomSource for CountingSource {
+    fn fill_bytes(&self, dest: &mut [u8]) {
+        for byte in dest {
+            *byte = self.0.get();
+            self.0.set(self.0.get().wrapping_add(1));
+        }
+    }
+}
+
+#[test]
+fn get_random_values_fills_in_place() {
+    run_test_actions([TestAction::run(indoc! {r#"
+        const array = new Uint8Array(16);
+        const result = crypto.getRandomValues(array);
+        if (result !== array) {
+            throw new Error("getRandomValues should return the same object");
+        }
+        if (result.length !== 16) {
+            throw new Error("getRandomValues should not change the length");
+        }
+
+        // The largest allowed array, and BigInt arrays, are accepted.
+        crypto.getRandomValues(new Uint8Array(65536));
+        crypto.getRandomValues(new BigUint64Array(2));
+    "#})]);
+}
+
+#[test]
+fn get_random_values_uses_custom_source() {
+    let context = &mut Context::default();
+    Crypto::register_with_source(context, CountingSource::default()).unwrap();
+
+    run_test_actions_with(
+        [TestAction::inspect_context(|ctx| {
+            let mut eval = |src: &str| ctx.eval(Source::from_bytes(src)).unwrap();
+            let bytes = eval(indoc! {r#"
+                const buffer = new ArrayBuffer(8);
+                crypto.getRandomValues(new Uint8Array(buffer, 2, 4));
+                new Uint8Array(buffer).join()
+            "#});
+            assert_eq!(bytes, JsValue::from(js_string!("0,0,0,1,2,3,0,0")));
+
+            let bytes =
+                eval("new Uint8Array(crypto.getRandomValues(new Uint16Array(1)).buffer).join()");
+            assert_eq!(bytes, JsValue::from(js_string!("4,5")));
+        })],
+        context,
+    );
+}
+
+#[test]
+fn get_random_values_errors() {
+    run_test_actions([TestAction::run(indoc! {r#"
+        function assertThrows(fn, type, name) {
+            try {
+                fn();
+            } catch (e) {
+                if (!(e instanceof type) || !e.message.startsWith(name + ":")) {
+                    throw new Error("Unexpected error: " + e);
+                }
+                return;
+            }
+            throw new Error("Expected " + name + " to be thrown");
+        }
+
+        assertThrows(() => crypto.getRandomValues(new Uint8Array(65537)), Error, "QuotaExceededError");
+        assertThrows(() => crypto.getRandomValues(new Uint32Array(16385)), Error, "QuotaExceededError");
+        assertThrows(() => crypto.getRandomValues(new Float64Array(4)), TypeError, "TypeMismatchError");
+        assertThrows(() => crypto.getRandomValues(new Float32Array(4)), TypeError, "TypeMismatchError");
+        assertThrows(() => crypto.getRandomValues([1, 2, 3]), TypeError, "TypeMismatchError");
+    "#})]);
+}
+
+#[test]
+fn random_uuid_format() {
+    run_test_actions([TestAction::run(indoc! {r#"
+        const pattern = /^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$/;
+        for (let i = 0; i < 100; i++) {
+            const uuid = crypto.randomUUID();
+            if (!pattern.test(uuid)) {
+                throw new Error("Invalid UUID: " + uuid);
+            }
+            if (uuid[14] !== "4" || !"89ab".includes(uuid[19])) {
+                throw new Error("Invalid UUID version or variant: " + uuid);
+            }
+        }
+        if (crypto.randomUUID() === crypto.randomUUID()) {
+            throw new Error("randomUUID should return distinct values");
+        }
+    "#})]);
+}
+
+#[test]
+fn random_uuid_uses_custom_source() {
+    let context = &mut Context::default();
+    Crypto::register_with_source(context, CountingSource::default()).unwrap();
+
+    run_test_actions_with(
+        [TestAction::inspect_context(|ctx| {
+            let uuid = ctx.eval(Source::from_bytes("crypto.randomUUID()")).unwrap();
+            assert_eq!(
+                uuid,
+                JsValue::from(js_string!("00010203-0405-4607-8809-0a0b0c0d0e0f"))
+            );
+        })],
+        context,
+    );
+}
@@ -0,0 +1,509 @@
+//! `EventTarget` and `Event` Web API implementations.
+//!
+//! There is no DOM tree in this runtime, so events are only ever dispatched to
+//! their target (there is no capture or bubbling phase). The listener storage,
+//! [`EventListeners`], can be reused by other classes that dispatch events.
+//!
+//! More information:
+//!  - [WHATWG DOM specification][spec]
+//!
+//! [spec]: https://dom.spec.whatwg.org/#events
+#![allow(clippy::needless_pass_by_value)]
+
+use boa_engine::interop::JsClass;
+use boa_engine::realm::Realm;
+use boa_engine::value::Convert;
+use boa_engine::{
+    Context, Finalize, JsData, JsError, JsObject, JsResult, JsString, JsValue, Trace, boa_class,
+    boa_module, js_error, js_string,
+};
+use boa_gc::{Gc, GcRefCell};
+use std::cell::Cell;
+use std::io::Write;
+use std::rc::Rc;
+
+#[cfg(test)]
+mod tests;
+
+/// A registered event listener.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct EventListener {
+    #[unsafe_ignore_trace]
+    id: u64,
+    event_type: JsString,
+    callback: JsObject,
+    #[unsafe_ignore_trace]
+    once: bool,
+    /// Whether this is the listener of an event handler attribute such as `onabort`.
+    #[unsafe_ignore_trace]
+    handler: bool,
+}
+
+/// The storage of [`EventListeners`].
+#[derive(Debug, Default, Trace, Finalize)]
+struct ListenerList {
+    listeners: Vec<EventListener>,
+    #[unsafe_ignore_trace]
+    next_id: u64,
+}
+
+impl ListenerList {
+    fn push(&mut self, event_type: JsString, callback: JsObject, once: bool, handler: bool) {
+        self.next_id += 1;
+        self.listeners.push(EventListener {
+            id: self.next_id,
+            event_type,
+            callback,
+            once,
+            handler,
+        });
+    }
+}
+
+/// A function reporting an exception thrown by an event listener.
+type ReportFn = dyn Fn(&JsError, &mut Context);
+
+/// The exception reporter registered in the context, see [`set_exception_reporter`].
+struct ExceptionReporter(Rc<ReportFn>);
+
+/// Sets the function used to report exceptions thrown by event listeners.
+///
+/// An exception thrown by a listener does not stop the dispatch of the event, and is not
+/// propagated to the caller of `dispatchEvent`. It is reported instead, by printing it to
+/// stderr unless a reporter is set.
+pub fn set_exception_reporter<F>(context: &mut Context, reporter: F)
+where
+    F: Fn(&JsError, &mut Context) + 'static,
+{
+    context.insert_data(ExceptionReporter(Rc::new(reporter)));
+}
+
+/// Reports an exception thrown by an event listener.
+fn report_exception(error: &JsError, context: &mut Context) {
+    match context.get_data::<ExceptionReporter>() {
+        Some(reporter) => {
+            let reporter = reporter.0.clone();
+            reporter(error, context);
+        }
+        // NOTE: There is nowhere else to report a failure to write the exception.
+        None => writeln!(std::io::stderr(), "Uncaught {error}").unwrap_or_default(),
+    }
+}
+
+/// A list of event listeners, keyed by event type.
+///
+/// Cloning an `EventListeners` returns a handle to the same list, so it can be taken
+/// out of its owner's borrow before listeners run.
+#[derive(Debug, Default, Clone, Trace, Finalize)]
+pub struct EventListeners(Gc<GcRefCell<ListenerList>>);
+
+impl EventListeners {
+    /// Adds a listener for `event_type`, unless the same callback is already
+    /// registered for that type.
+    pub fn add(&self, event_type: JsString, callback: JsObject, once: bool) {
+        if self.contains(&event_type, &callback) {
+            return;
+        }
+        self.0.borrow_mut().push(event_type, callback, once, false);
+    }
+
+    /// Removes the listener registered for `event_type` with `callback`, if any.
+    pub fn remove(&self, event_type: &JsString, callback: &JsObject) {
+        self.0.borrow_mut().listeners.retain(|listener| {
+            listener.handler
+                || listener.event_type != *event_type
+                || !JsObject::equals(&listener.callback, callback)
+        });
+    }
+
+    /// Returns whether `callback` is registered for `event_type`.
+    #[must_use]
+    pub fn contains(&self, event_type: &JsString, callback: &JsObject) -> bool {
+        self.0.borrow().listeners.iter().any(|listener| {
+            !listener.handler
+                && listener.event_type == *event_type
+                && JsObject::equals(&listener.callback, callback)
+        })
+    }
+
+    /// Returns the callbacks registered for `event_type`, in registration order.
+    ///
+    /// This does not include the event handler attribute, see [`EventListeners::handler`].
+    #[must_use]
+    pub fn callbacks(&self, event_type: &JsString) -> Vec<JsObject> {
+        self.0
+            .borrow()
+            .listeners
+            .iter()
+            .filter(|listener| !listener.handler && listener.event_type == *event_type)
+            .map(|listener| listener.callback.clone())
+            .collect()
+    }
+
+    /// Returns the value of the event handler attribute for `event_type`, such as `onabort`.
+    #[must_use]
+    pub fn handler(&self, event_type: &JsString) -> Option<JsObject> {
+        self.0
+            .borrow()
+            .listeners
+            .iter()
+            .find(|listener| listener.handler && listener.event_type == *event_type)
+            .map(|listener| listener.callback.clone())
+    }
+
+    /// Sets the value of the event handler attribute for `event_type`, such as `onabort`.
+    ///
+    /// The handler is invoked along with the listeners, at the position where it was first
+    /// set. Changing its value keeps that position, and setting it to `None` removes it.
+    pub fn set_handler(&self, event_type: JsString, handler: Option<JsObject>) {
+        let mut list = self.0.borrow_mut();
+        let existing = list
+            .listeners
+            .iter_mut()
+            .position(|listener| listener.handler && listener.event_type == event_type);
+        match (existing, handler) {
+            (Some(index), Some(handler)) => list.listeners[index].callback = handler,
+            (Some(index), None) => {
+                list.listeners.remove(index);
+            }
+            (None, Some(handler)) => list.push(event_type, handler, false, true),
+            (None, None) => {}
+        }
+    }
+
+    /// Invokes the listeners for the type of `event` with `target` as the event's target,
+    /// in registration order. Returns `false` if the event was cancelled.
+    ///
+    /// Listeners added during dispatch are not invoked, and listeners removed during
+    /// dispatch are skipped. Exceptions thrown by listeners are reported with the
+    /// reporter set by [`set_exception_reporter`], and don't stop the dispatch.
+    ///
+    /// # Errors
+    /// Returns an error if the event is already being dispatched.
+    pub fn dispatch(
+        &self,
+        target: &JsObject,
+        event: &JsObject<JsEvent>,
+        context: &mut Context,
+    ) -> JsResult<bool> {
+        let event_type = {
+            let event = event.borrow();
+            let event = event.data();
+            if event.dispatching.get() {
+                return Err(js_error!(
+                    TypeError: "InvalidStateError: the event is already being dispatched"
+                ));
+            }
+            event.dispatching.set(true);
+            event.stop_immediate_propagation.set(false);
+            *event.target.borrow_mut() = Some(target.clone());
+            event.event_type.clone()
+        };
+
+        self.invoke(target, &event_type, event, context);
+
+        let event = event.borrow();
+        let event = event.data();
+        event.dispatching.set(false);
+        Ok(!event.default_prevented.get())
+    }
+
+    fn invoke(
+        &self,
+        target: &JsObject,
+        event_type: &JsString,
+        event: &JsObject<JsEvent>,
+        context: &mut Context,
+    ) {
+        let ids: Vec<u64> = self
+            .0
+            .borrow()
+            .listeners
+            .iter()
+            .filter(|listener| listener.event_type == *event_type)
+            .map(|listener| listener.id)
+            .collect();
+
+        let event_value: JsValue = event.clone().upcast().into();
+        for id in ids {
+            if event.borrow().data().stop_immediate_propagation.get() {
+                break;
+            }
+
+            // NOTE: The listener is looked up again, since it may have been removed, or the
+            //       value of an event handler attribute changed, by a previous listener.
+            let listener = {
+                let mut list = self.0.borrow_mut();
+                let Some(index) = list.listeners.iter().position(|listener| listener.id == id)
+                else {
+                    continue;
+                };
+                if list.listeners[index].once {
+                    list.listeners.remove(index)
+                } else {
+                    list.listeners[index].clone()
+                }
+            };
+
+            let result = if listener.callback.is_callable() {
+                listener
+                    .callback
+                    .call(
+                        &target.clone().into(),
+                        std::slice::from_ref(&event_value),
+                        context,
+                    )
+                    .map(|_| ())
+            } else if listener.handler {
+                // A non-callable event handler attribute is ignored.
+                Ok(())
+            } else {
+                listener
+                    .callback
+                    .get(js_string!("handleEvent"), context)
+                    .and_then(|handle_event| {
+                        let Some(handle_event) = handle_event.as_callable() else {
+                            return Err(js_error!(
+                                TypeError: "event listener has no callable handleEvent method"
+                            ));
+                        };
+                        handle_event.call(
+                            &listener.callback.clone().into(),
+                            std::slice::from_ref(&event_value),
+                            context,
+                        )
+                    })
+                    .map(|_| ())
+            };
+
+            if let Err(error) = result {
+                report_exception(&error, context);
+            }
+        }
+    }
+}
+
+/// Reads the `once` member of an `addEventListener` options argument.
+fn once_option(options: Option<JsValue>, context: &mut Context) -> JsResult<bool> {
+    match options.as_ref().and_then(JsValue::as_object) {
+        Some(options) => Ok(options.get(js_string!("once"), context)?.to_boolean()),
+        None => Ok(false),
+    }
+}
+
+/// The JavaScript `Event` class.
+#[derive(Debug, Clone, JsData, Trace, Finalize)]
+pub struct JsEvent {
+    event_type: JsString,
+    target: GcRefCell<Option<JsObject>>,
+    #[unsafe_ignore_trace]
+    bubbles: bool,
+    #[unsafe_ignore_trace]
+    cancelable: bool,
+    #[unsafe_ignore_trace]
+    default_prevented: Cell<bool>,
+    #[unsafe_ignore_trace]
+    stop_propagation: Cell<bool>,
+    #[unsafe_ignore_trace]
+    stop_immediate_propagation: Cell<bool>,
+    #[unsafe_ignore_trace]
+    dispatching: Cell<bool>,
+}
+
+impl JsEvent {
+    /// Creates a new event of the given type.
+    #[must_use]
+    pub fn new(event_type: JsString, bubbles: bool, cancelable: bool) -> Self {
+        Self {
+            event_type,
+            target: GcRefCell::default(),
+            bubbles,
+            cancelable,
+            default_prevented: Cell::new(false),
+            stop_propagation: Cell::new(false),
+            stop_immediate_propagation: Cell::new(false),
+            dispatching: Cell::new(false),
+        }
+    }
+
+    /// Creates an `Event` object of the given type that is neither bubbling nor
+    /// cancelable. The object falls back to `Object.prototype` if the `Event` class
+    /// is not registered.
+    #[must_use]
+    pub fn create(event_type: JsString, context: &mut Context) -> JsObject<Self> {
+        let prototype = context.get_global_class::<Self>().map_or_else(
+            || context.intrinsics().constructors().object().prototype(),
+            |class| class.prototype(),
+        );
+        JsObject::new(
+            context.root_shape(),
+            prototype,
+            Self::new(event_type, false, false),
+        )
+    }
+}
+
+#[boa_class(rename = "Event")]
+#[boa(rename_all = "camelCase")]
+impl JsEvent {
+    /// Create a new `Event` object of type `event_type`. `init` can contain the
+    /// `bubbles` and `cancelable` flags.
+    ///
+    /// # Errors
+    /// If `init` cannot be read.
+    #[boa(constructor)]
+    fn constructor(
+        event_type: Convert<JsString>,
+        init: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let (bubbles, cancelable) = match init.as_ref().and_then(JsValue::as_object) {
+            Some(init) => (
+                init.get(js_string!("bubbles"), context)?.to_boolean(),
+                init.get(js_string!("cancelable"), context)?.to_boolean(),
+            ),
+            None => (false, false),
+        };
+        Ok(Self::new(event_type.0.clone(), bubbles, cancelable))
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "type")]
+    fn r#type(&self) -> JsString {
+        self.event_type.clone()
+    }
+
+    #[boa(getter)]
+    fn target(&self) -> JsValue {
+        self.target
+            .borrow()
+            .clone()
+            .map_or_else(JsValue::null, Into::into)
+    }
+
+    /// Returns the target while the event is being dispatched, `null` otherwise.
+    #[boa(getter)]
+    fn current_target(&self) -> JsValue {
+        if self.dispatching.get() {
+            self.target()
+        } else {
+            JsValue::null()
+        }
+    }
+
+    #[boa(getter)]
+    fn bubbles(&self) -> bool {
+        self.bubbles
+    }
+
+    #[boa(getter)]
+    fn cancelable(&self) -> bool {
+        self.cancelable
+    }
+
+    #[boa(getter)]
+    fn default_prevented(&self) -> bool {
+        self.default_prevented.get()
+    }
+
+    /// Cancels the event, if it is cancelable.
+    fn prevent_default(&self) {
+        if self.cancelable {
+            self.default_prevented.set(true);
+        }
+    }
+
+    /// Stops the event from propagating further. As events are only dispatched to
+    /// their target, this only records the request.
+    fn stop_propagation(&self) {
+        self.stop_propagation.set(true);
+    }
+
+    /// Stops the event from propagating further, and prevents the remaining
+    /// listeners of the target from being invoked.
+    fn stop_immediate_propagation(&self) {
+        self.stop_propagation.set(true);
+        self.stop_immediate_propagation.set(true);
+    }
+}
+
+/// The JavaScript `EventTarget` class.
+#[derive(Debug, Default, Clone, JsData, Trace, Finalize)]
+pub struct JsEventTarget {
+    listeners: EventListeners,
+}
+
+impl JsEventTarget {
+    /// Returns the listeners registered on this target.
+    #[must_use]
+    pub fn listeners(&self) -> &EventListeners {
+        &self.listeners
+    }
+}
+
+#[boa_class(rename = "EventTarget")]
+#[boa(rename_all = "camelCase")]
+impl JsEventTarget {
+    #[boa(constructor)]
+    fn constructor() -> Self {
+        Self::default()
+    }
+
+    /// Registers `callback` to be invoked when an event of type `event_type` is
+    /// dispatched. A `null` callback is ignored.
+    ///
+    /// # Errors
+    /// If `options` cannot be read.
+    fn add_event_listener(
+        &self,
+        event_type: Convert<JsString>,
+        callback: Option<JsObject>,
+        options: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let once = once_option(options, context)?;
+        if let Some(callback) = callback {
+            self.listeners.add(event_type.0.clone(), callback, once);
+        }
+        Ok(())
+    }
+
+    /// Removes the listener registered for `event_type` with `callback`.
+    fn remove_event_listener(&self, event_type: Convert<JsString>, callback: Option<JsObject>) {
+        if let Some(callback) = callback {
+            self.listeners.remove(event_type.as_ref(), &callback);
+        }
+    }
+
+    /// Dispatches `event` to this target. Returns `false` if the event was cancelled.
+    ///
+    /// # Errors
+    /// If `event` is not an `Event`, or is already being dispatched.
+    #[boa(method)]
+    fn dispatch_event(
+        this: JsClass<Self>,
+        event: JsObject,
+        context: &mut Context,
+    ) -> JsResult<bool> {
+        let Ok(event) = event.downcast::<JsEvent>() else {
+            return Err(js_error!(TypeError: "dispatchEvent: argument is not an Event"));
+        };
+        let target = this.inner().upcast();
+        // Listeners may access the target, so it must not stay borrowed while they run.
+        let listeners = this.borrow().listeners.clone();
+        listeners.dispatch(&target, &event, context)
+    }
+}
+
+/// `EventTarget` and `Event` module.
+#[boa_module]
+pub mod js_module {
+    type JsEvent = super::JsEvent;
+    type JsEventTarget = super::JsEventTarget;
+}
+
+/// # Errors
+/// Returns an error if registration fails.
+pub fn register(realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+    js_module::boa_register(realm, context)
+}
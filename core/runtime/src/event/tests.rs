@@ -0,0 +1,164 @@
+use super::set_exception_reporter;
+use crate::test::{TestAction, run_test_actions};
+use indoc::indoc;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn listeners_fire_in_registration_order() {
+    run_test_actions([TestAction::run(indoc! {r#"
+        const target = new EventTarget();
+        const calls = [];
+        const first = (e) => calls.push("first:" + e.type);
+        const second = function (e) {
+            calls.push("second:" + (this === target) + ":" + (e.target === target));
+        };
+        target.addEventListener("ping", first);
+        target.addEventListener("ping", second);
+        // Adding the same listener twice is a no-op.
+        target.addEventListener("ping", first);
+
+        const event = new Event("ping");
+        if (target.dispatchEvent(event) !== true) {
+            throw new Error("dispatchEvent should return true for a non-cancelled event");
+        }
+        if (calls.join() !== "first:ping,second:true:true") {
+            throw new Error("Unexpected calls: " + calls.join());
+        }
+        if (event.target !== target || event.currentTarget !== null) {
+            throw new Error("Unexpected event targets after dispatch");
+        }
+
+        target.removeEventListener("ping", first);
+        calls.length = 0;
+        target.dispatchEvent(new Event("ping"));
+        if (calls.join() !== "second:true:true") {
+            throw new Error("Removed listener was called: " + calls.join());
+        }
+
+        target.removeEventListener("ping", second);
+        calls.length = 0;
+        target.dispatchEvent(new Event("ping"));
+        if (calls.length !== 0) {
+            throw new Error("Removed listener was called: " + calls.join());
+        }
+    "#})]);
+}
+
+#[test]
+fn prevent_default_and_stop_propagation() {
+    run_test_actions([TestAction::run(indoc! {r#"
+        const target = new EventTarget();
+        const calls = [];
+        target.addEventListener("x", (e) => {
+            calls.push(1);
+            e.preventDefault();
+            e.stopImmediatePropagation();
+        });
+        target.addEventListener("x", () => calls.push(2));
+
+        const cancelable = new Event("x", { cancelable: true });
+        if (target.dispatchEvent(cancelable) !== false || !cancelable.defaultPrevented) {
+            throw new Error("cancelable event should be cancelled");
+        }
+
+        const plain = new Event("x");
+        if (target.dispatchEvent(plain) !== true || plain.defaultPrevented) {
+            throw new Error("non-cancelable event should not be cancelled");
+        }
+        if (calls.join() !== "1,1") {
+            throw new Error("stopImmediatePropagation should skip later listeners: " + calls.join());
+        }
+
+        // `stopPropagation` does not affect listeners on the same target.
+        const other = new EventTarget();
+        let count = 0;
+        other.addEventListener("y", (e) => { count++; e.stopPropagation(); });
+        other.addEventListener("y", () => count++);
+        other.dispatchEvent(new Event("y"));
+        if (count !== 2) {
+            throw new Error("stopPropagation should not skip listeners on the target");
+        }
+    "#})]);
+}
+
+#[test]
+fn once_and_handle_event() {
+    run_test_actions([TestAction::run(indoc! {r#"
+        const target = new EventTarget();
+        let count = 0;
+        target.addEventListener("x", () => count++, { once: true });
+        const handler = { handleEvent(e) { count += 10; } };
+        target.addEventListener("x", handler);
+
+        target.dispatchEvent(new Event("x"));
+        target.dispatchEvent(new Event("x"));
+        if (count !== 21) {
+            throw new Error("Unexpected count: " + count);
+        }
+
+        try {
+            target.dispatchEvent({ type: "x" });
+            throw new Error("dispatchEvent should reject non-Event objects");
+        } catch (e) {
+            if (!(e instanceof TypeError)) {
+                throw e;
+            }
+        }
+    "#})]);
+}
+
+#[test]
+fn listeners_can_mutate_the_target() {
+    run_test_actions([TestAction::run(indoc! {r#"
+        const target = new EventTarget();
+        target.addEventListener("x", function () {
+            this.hit = (this.hit ?? 0) + 1;
+            target.addEventListener("x", () => {});
+        });
+        target.addEventListener("x", (e) => {
+            e.target.other = true;
+        });
+
+        target.dispatchEvent(new Event("x"));
+        if (target.hit !== 1 || target.other !== true) {
+            throw new Error("listeners did not mutate the target");
+        }
+    "#})]);
+}
+
+#[test]
+fn listener_exceptions_are_reported() {
+    let reported = Rc::new(RefCell::new(Vec::new()));
+    let sink = reported.clone();
+    run_test_actions([
+        TestAction::inspect_context(move |ctx| {
+            set_exception_reporter(ctx, move |error, _| {
+                sink.borrow_mut().push(error.to_string());
+            });
+        }),
+        TestAction::run(indoc! {r#"
+            const target = new EventTarget();
+            const calls = [];
+            target.addEventListener("x", () => {
+                calls.push(1);
+                throw new Error("first");
+            });
+            target.addEventListener("x", { handleEvent: 42 });
+            target.addEventListener("x", () => calls.push(3));
+
+            if (target.dispatchEvent(new Event("x")) !== true) {
+                throw new Error("dispatchEvent should not throw or cancel the event");
+            }
+            if (calls.join() !== "1,3") {
+                throw new Error("Listeners after a throwing one were skipped: " + calls.join());
+            }
+        "#}),
+        TestAction::inspect_context(move |_| {
+            let reported = reported.borrow();
+            assert_eq!(reported.len(), 2);
+            assert!(reported[0].contains("first"), "{}", reported[0]);
+            assert!(reported[1].contains("handleEvent"), "{}", reported[1]);
+        }),
+    ]);
+}
@@ -48,6 +48,16 @@ impl RuntimeExtension for EncodingExtension {
     }
 }
 
+/// Register the `Blob`, `File` and `ReadableStream` classes.
+#[derive(Copy, Clone, Debug)]
+pub struct BlobExtension;
+
+impl RuntimeExtension for BlobExtension {
+    fn register(self, realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+        crate::blob::register(realm, context)
+    }
+}
+
 /// Register the `structuredClone` function.
 #[derive(Copy, Clone, Debug)]
 pub struct StructuredCloneExtension;
@@ -58,6 +68,26 @@ impl RuntimeExtension for StructuredCloneExtension {
     }
 }
 
+/// Register the `performance` object.
+#[derive(Copy, Clone, Debug)]
+pub struct PerformanceExtension;
+
+impl RuntimeExtension for PerformanceExtension {
+    fn register(self, _realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+        crate::performance::Performance::register(context)
+    }
+}
+
+/// Register the `crypto` object, using the default random source.
+#[derive(Copy, Clone, Debug)]
+pub struct CryptoExtension;
+
+impl RuntimeExtension for CryptoExtension {
+    fn register(self, _realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+        crate::crypto::Crypto::register(context)
+    }
+}
+
 /// Register the `atob` and `btoa` Base64 utility functions.
 #[derive(Copy, Clone, Debug)]
 pub struct Base64Extension;
@@ -121,6 +151,16 @@ impl<F: crate::fetch::Fetcher + Debug + 'static> RuntimeExtension for FetchExten
     }
 }
 
+/// `EventTarget` and `Event` extension.
+#[derive(Copy, Clone, Debug)]
+pub struct EventTargetExtension;
+
+impl RuntimeExtension for EventTargetExtension {
+    fn register(self, realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+        crate::event::register(realm, context)
+    }
+}
+
 /// `AbortController` and `AbortSignal` extension.
 #[cfg(feature = "fetch")]
 #[derive(Copy, Clone, Debug)]
@@ -146,6 +186,21 @@ impl<S: crate::message::MessageSender + Debug + 'static> RuntimeExtension
     }
 }
 
+impl RuntimeExtension for () {
+    fn register(self, _realm: Option<Realm>, _context: &mut Context) -> JsResult<()> {
+        Ok(())
+    }
+}
+
+impl<T: RuntimeExtension> RuntimeExtension for Option<T> {
+    fn register(self, realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+        match self {
+            Some(extension) => extension.register(realm, context),
+            None => Ok(()),
+        }
+    }
+}
+
 macro_rules! decl_runtime_ext_tuple {
     ($first_name: ident : $first_type: ident) => {
         impl<$first_type: RuntimeExtension> RuntimeExtension for ($first_type,) {
@@ -0,0 +1,266 @@
+//! The `FormData` JavaScript class, implemented as [`JsFormData`].
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [XHR specification][spec]
+//!
+//! [spec]: https://xhr.spec.whatwg.org/#interface-formdata
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/FormData
+#![allow(clippy::needless_pass_by_value)]
+
+use super::form_data_iterator::FormDataIterator;
+use super::headers_iterator::IterationKind;
+use crate::blob::{JsBlob, JsFile};
+use boa_engine::class::Class;
+use boa_engine::interop::JsClass;
+use boa_engine::object::builtins::TypedJsFunction;
+use boa_engine::value::{Convert, TryFromJs};
+use boa_engine::{
+    Context, Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace, boa_class, js_error,
+    js_string,
+};
+
+/// A callback function for the `forEach` method.
+type ForEachCallback = TypedJsFunction<(JsValue, JsString, JsObject), ()>;
+
+/// The value of a `FormData` entry, either a string or a `File`.
+///
+/// See <https://xhr.spec.whatwg.org/#formdataentryvalue>
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum FormDataEntryValue {
+    /// A string value.
+    String(JsString),
+    /// A `File` value. `Blob` values are converted to files when they are added.
+    File(JsObject<JsFile>),
+}
+
+impl FormDataEntryValue {
+    /// Returns this value as a JavaScript value.
+    #[must_use]
+    pub fn to_js_value(&self) -> JsValue {
+        match self {
+            Self::String(string) => string.clone().into(),
+            Self::File(file) => file.clone().upcast().into(),
+        }
+    }
+}
+
+/// The `FormData` class, an ordered list of name-value pairs, where values are either
+/// strings or files.
+#[derive(Debug, Default, Clone, JsData, Trace, Finalize)]
+pub struct JsFormData {
+    entries: Vec<(JsString, FormDataEntryValue)>,
+}
+
+impl JsFormData {
+    /// Returns the name-value pairs of this object.
+    #[must_use]
+    pub fn pairs(&self) -> &[(JsString, FormDataEntryValue)] {
+        &self.entries
+    }
+
+    /// Creates an entry from a name and a value, converting `Blob` values to `File`s
+    /// named after `filename`.
+    ///
+    /// See <https://xhr.spec.whatwg.org/#create-an-entry>
+    fn create_entry(
+        name: JsString,
+        value: JsValue,
+        filename: Option<Convert<JsString>>,
+        context: &mut Context,
+    ) -> JsResult<(JsString, FormDataEntryValue)> {
+        let Ok(blob) = JsBlob::try_from_js(&value, context) else {
+            if filename.is_some() {
+                return Err(js_error!(
+                    TypeError: "a filename can only be given with a Blob value"
+                ));
+            }
+            let value = value.to_string(context)?;
+            return Ok((name, FormDataEntryValue::String(value)));
+        };
+
+        // Files are stored as is, unless they need to be renamed.
+        let file = value
+            .as_object()
+            .and_then(|object| object.downcast::<JsFile>().ok());
+        if let Some(file) = &file
+            && filename.is_none()
+        {
+            return Ok((name, FormDataEntryValue::File(file.clone())));
+        }
+
+        let filename = match (filename, &file) {
+            (Some(filename), _) => filename.as_ref().clone(),
+            (None, Some(file)) => file.borrow().data().name(),
+            (None, None) => js_string!("blob"),
+        };
+        let last_modified = match &file {
+            Some(file) => file.borrow().data().last_modified(),
+            None => context.clock().system_time_millis(),
+        };
+
+        let file = JsFile::from_data(JsFile::new(blob, filename, last_modified), context)?
+            .downcast::<JsFile>()
+            .map_err(|_| js_error!(TypeError: "Invalid File object"))?;
+        Ok((name, FormDataEntryValue::File(file)))
+    }
+}
+
+#[boa_class(rename = "FormData")]
+#[boa(rename_all = "camelCase")]
+impl JsFormData {
+    /// Creates a new, empty `FormData` object.
+    ///
+    /// # Errors
+    /// If a form element is given, as there is no DOM to read it from.
+    #[boa(constructor)]
+    pub fn constructor(form: Option<JsValue>) -> JsResult<Self> {
+        if form.is_some_and(|form| !form.is_undefined()) {
+            return Err(js_error!(TypeError: "FormData does not support form elements"));
+        }
+        Ok(Self::default())
+    }
+
+    /// Appends a new entry to the list.
+    ///
+    /// # Errors
+    /// If a filename is given with a value that is not a `Blob`.
+    #[boa(method)]
+    fn append(
+        this: JsClass<Self>,
+        name: Convert<JsString>,
+        value: JsValue,
+        filename: Option<Convert<JsString>>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // The entry is created before borrowing the list, as converting the value
+        // can run user code.
+        let entry = Self::create_entry(name.as_ref().clone(), value, filename, context)?;
+        this.borrow_mut().entries.push(entry);
+        Ok(())
+    }
+
+    /// Removes all entries with the given name.
+    fn delete(&mut self, name: Convert<JsString>) {
+        self.entries.retain(|(n, _)| n != name.as_ref());
+    }
+
+    /// Returns the value of the first entry with the given name, or `null`.
+    fn get(&self, name: Convert<JsString>) -> JsValue {
+        self.entries
+            .iter()
+            .find(|(n, _)| n == name.as_ref())
+            .map_or_else(JsValue::null, |(_, v)| v.to_js_value())
+    }
+
+    /// Returns the values of all entries with the given name.
+    fn get_all(&self, name: Convert<JsString>) -> Vec<JsValue> {
+        self.entries
+            .iter()
+            .filter(|(n, _)| n == name.as_ref())
+            .map(|(_, v)| v.to_js_value())
+            .collect()
+    }
+
+    /// Returns whether an entry with the given name exists.
+    fn has(&self, name: Convert<JsString>) -> bool {
+        self.entries.iter().any(|(n, _)| n == name.as_ref())
+    }
+
+    /// Replaces the first entry with the given name and removes all the others, or
+    /// appends a new entry if none exists.
+    ///
+    /// # Errors
+    /// If a filename is given with a value that is not a `Blob`.
+    #[boa(method)]
+    fn set(
+        this: JsClass<Self>,
+        name: Convert<JsString>,
+        value: JsValue,
+        filename: Option<Convert<JsString>>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let (name, value) = Self::create_entry(name.as_ref().clone(), value, filename, context)?;
+        let mut value = Some(value);
+        let mut form_data = this.borrow_mut();
+        let entries = &mut form_data.entries;
+        entries.retain_mut(|(n, v)| {
+            if *n != name {
+                return true;
+            }
+            match value.take() {
+                Some(value) => {
+                    *v = value;
+                    true
+                }
+                None => false,
+            }
+        });
+        if let Some(value) = value {
+            entries.push((name, value));
+        }
+        Ok(())
+    }
+
+    /// Executes a provided function once for each entry, in list order.
+    ///
+    /// # Errors
+    /// If the callback function returns an error, it is returned.
+    #[boa(method)]
+    fn for_each(
+        this: JsClass<Self>,
+        callback: ForEachCallback,
+        this_arg: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let object = this.inner().upcast();
+        let this_arg = this_arg.unwrap_or_default();
+        let mut index = 0;
+        loop {
+            // The list is re-borrowed on each step, as the callback may mutate it.
+            let Some((name, value)) = this
+                .borrow()
+                .entries
+                .get(index)
+                .map(|(n, v)| (n.clone(), v.to_js_value()))
+            else {
+                return Ok(());
+            };
+            callback.call_with_this(&this_arg, context, (value, name, object.clone()))?;
+            index += 1;
+        }
+    }
+
+    /// Returns an iterator over the `[name, value]` pairs of this object.
+    ///
+    /// # Errors
+    /// Returns an error if the iterator cannot be created.
+    pub fn entries(this: JsClass<Self>, context: &mut Context) -> JsResult<JsValue> {
+        FormDataIterator::create(this.inner(), IterationKind::KeyAndValue, context)
+    }
+
+    /// Returns an iterator over the names of this object.
+    ///
+    /// # Errors
+    /// Returns an error if the iterator cannot be created.
+    fn keys(this: JsClass<Self>, context: &mut Context) -> JsResult<JsValue> {
+        FormDataIterator::create(this.inner(), IterationKind::Key, context)
+    }
+
+    /// Returns an iterator over the values of this object.
+    ///
+    /// # Errors
+    /// Returns an error if the iterator cannot be created.
+    fn values(this: JsClass<Self>, context: &mut Context) -> JsResult<JsValue> {
+        FormDataIterator::create(this.inner(), IterationKind::Value, context)
+    }
+
+    /// `[Symbol.iterator]()` is an alias for `entries()`.
+    ///
+    /// # Errors
+    /// Returns an error if the iterator cannot be created.
+    #[boa(symbol = "iterator")]
+    fn symbol_iterator(this: JsClass<Self>, context: &mut Context) -> JsResult<JsValue> {
+        FormDataIterator::create(this.inner(), IterationKind::KeyAndValue, context)
+    }
+}
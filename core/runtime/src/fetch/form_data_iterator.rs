@@ -0,0 +1,96 @@
+//! This module implements the `FormData Iterator` object.
+//!
+//! More information:
+//!  - [XHR specification][spec]
+//!
+//! [spec]: https://xhr.spec.whatwg.org/#interface-formdata
+
+use boa_engine::{
+    Context, JsData, JsResult, JsValue, boa_class, builtins::iterable::create_iter_result_object,
+    error::JsNativeError, interop::JsClass, object::JsObject, object::builtins::JsArray,
+};
+use boa_gc::{Finalize, Trace};
+
+use super::form_data::JsFormData;
+use super::headers_iterator::IterationKind;
+
+/// The `FormData Iterator` object represents an iteration over a `FormData` object.
+/// It implements the iterator protocol.
+#[derive(Debug, Finalize, Trace, JsData)]
+pub(crate) struct FormDataIterator {
+    iterated_form_data: JsObject<JsFormData>,
+    next_index: usize,
+    #[unsafe_ignore_trace]
+    iteration_kind: IterationKind,
+}
+
+#[boa_class(rename = "FormData Iterator")]
+impl FormDataIterator {
+    /// Prevent direct construction — `FormDataIterator` instances are only
+    /// created internally via [`FormDataIterator::create`].
+    #[boa(constructor)]
+    fn constructor() -> JsResult<Self> {
+        Err(JsNativeError::typ()
+            .with_message("Illegal constructor")
+            .into())
+    }
+
+    /// `%FormDataIteratorPrototype%.next()`
+    ///
+    /// Advances the iterator and returns the next `{ value, done }` result. Entries
+    /// added to the `FormData` during iteration are visited as well.
+    #[boa(method)]
+    fn next(&mut self, context: &mut Context) -> JsValue {
+        let element = self
+            .iterated_form_data
+            .borrow()
+            .data()
+            .pairs()
+            .get(self.next_index)
+            .map(|(name, value)| (JsValue::from(name.clone()), value.to_js_value()));
+
+        let Some((name, value)) = element else {
+            return create_iter_result_object(JsValue::undefined(), true, context);
+        };
+        self.next_index += 1;
+
+        match self.iteration_kind {
+            IterationKind::Key => create_iter_result_object(name, false, context),
+            IterationKind::Value => create_iter_result_object(value, false, context),
+            IterationKind::KeyAndValue => {
+                let result = JsArray::from_iter([name, value], context);
+                create_iter_result_object(result.into(), false, context)
+            }
+        }
+    }
+
+    /// Returns `this`, making the iterator itself iterable (`for...of` support).
+    #[boa(method)]
+    #[boa(symbol = "iterator")]
+    fn symbol_iterator(this: JsClass<Self>) -> JsValue {
+        this.inner().into()
+    }
+}
+
+impl FormDataIterator {
+    /// Creates a new iterator over the given `FormData` object.
+    pub(crate) fn create(
+        form_data: JsObject<JsFormData>,
+        kind: IterationKind,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let iter = Self {
+            iterated_form_data: form_data,
+            next_index: 0,
+            iteration_kind: kind,
+        };
+
+        let proto = context
+            .realm()
+            .get_class::<Self>()
+            .ok_or_else(|| boa_engine::js_error!(Error: "FormData Iterator not registered"))?
+            .prototype();
+
+        Ok(JsObject::from_proto_and_data(proto, iter).into())
+    }
+}
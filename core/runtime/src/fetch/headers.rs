@@ -46,6 +46,25 @@ fn to_header_value(value: impl AsRef<str>) -> JsResult<HeaderValue> {
         .map_err(|_| js_error!(TypeError: "Invalid header value."))
 }
 
+/// Returns all the values of the header `name` joined with `, `, or `None` if there
+/// is no such header.
+///
+/// See <https://fetch.spec.whatwg.org/#concept-header-list-get>
+fn combine_values(headers: &HttpHeaderMap, name: &HeaderName) -> Option<String> {
+    headers
+        .get_all(name)
+        .into_iter()
+        .map(|v| v.to_str().unwrap_or(""))
+        .fold(None, |mut acc, v| {
+            let str = acc.get_or_insert_with(String::new);
+            if !str.is_empty() {
+                str.push_str(", ");
+            }
+            str.push_str(v);
+            acc
+        })
+}
+
 /// A JavaScript wrapper for the `Headers` object.
 #[derive(Debug, Default, Clone, JsData, Trace, Finalize)]
 pub struct JsHeaders {
@@ -75,6 +94,32 @@ impl JsHeaders {
         self.headers.clone()
     }
 
+    /// Returns the headers as name-value pairs, sorted by name and with the values of
+    /// duplicate headers combined, except for `Set-Cookie` headers which are kept apart.
+    ///
+    /// See <https://fetch.spec.whatwg.org/#concept-header-list-sort-and-combine>
+    pub(crate) fn sort_and_combine(&self) -> Vec<(JsString, JsString)> {
+        let headers = self.headers.borrow();
+        let mut names = headers.keys().collect::<Vec<_>>();
+        names.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+
+        let mut pairs = Vec::with_capacity(names.len());
+        for name in names {
+            let key = JsString::from(name.as_str());
+            if name == http::header::SET_COOKIE {
+                pairs.extend(
+                    headers
+                        .get_all(name)
+                        .into_iter()
+                        .map(|v| (key.clone(), JsString::from(v.to_str().unwrap_or("")))),
+                );
+            } else if let Some(value) = combine_values(&headers, name) {
+                pairs.push((key, JsString::from(value)));
+            }
+        }
+        pairs
+    }
+
     pub(crate) fn deep_clone(&self) -> Self {
         Self {
             headers: Rc::new(RefCell::new((*self.headers.borrow()).clone())),
@@ -169,7 +214,8 @@ impl JsHeaders {
         HeadersIterator::create_headers_iterator(this.inner(), IterationKind::KeyAndValue, context)
     }
 
-    /// Executes a provided function once for each key/value pair in the Headers object.
+    /// Executes a provided function once for each key/value pair in the Headers object,
+    /// in the same order as the iterators.
     ///
     /// # Errors
     /// If the callback function returns an error, it is returned.
@@ -183,12 +229,15 @@ impl JsHeaders {
     ) -> JsResult<()> {
         let object = this.inner().upcast();
         let this_arg = this_arg.unwrap_or_default();
-        for (k, v) in this.clone_inner().headers.borrow().iter() {
-            let k = JsString::from(k.as_str());
-            let v = JsString::from(v.to_str().unwrap_or(""));
+        let mut index = 0;
+        loop {
+            // The pairs are recomputed on each step, as the callback may mutate the headers.
+            let Some((k, v)) = this.borrow().sort_and_combine().into_iter().nth(index) else {
+                return Ok(());
+            };
             callback.call_with_this(&this_arg, context, (v, k, object.clone()))?;
+            index += 1;
         }
-        Ok(())
     }
 
     /// Returns a byte string of all the values in a header within a Headers object
@@ -200,20 +249,7 @@ impl JsHeaders {
     pub fn get(&self, key: JsValue, context: &mut Context) -> JsResult<JsValue> {
         let key: Convert<String> = Convert::try_from_js(&key, context)?;
         let name = to_header_name(key.as_ref())?;
-        let value = self
-            .headers
-            .borrow()
-            .get_all(name.clone())
-            .into_iter()
-            .map(|v| v.to_str().unwrap_or(""))
-            .fold(None, |mut acc, v| {
-                let str = acc.get_or_insert_with(String::new);
-                if !str.is_empty() {
-                    str.push_str(", ");
-                }
-                str.push_str(v);
-                acc
-            });
+        let value = combine_values(&self.headers.borrow(), &name);
 
         Ok(value.map_or_else(JsValue::null, |v| JsString::from(v).into()))
     }
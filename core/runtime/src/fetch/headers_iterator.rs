@@ -6,9 +6,8 @@
 //! [spec]: https://fetch.spec.whatwg.org/#headers-class
 
 use boa_engine::{
-    Context, JsData, JsResult, JsString, JsValue, boa_class,
-    builtins::iterable::create_iter_result_object, error::JsNativeError, interop::JsClass,
-    object::JsObject, object::builtins::JsArray,
+    Context, JsData, JsResult, JsValue, boa_class, builtins::iterable::create_iter_result_object,
+    error::JsNativeError, interop::JsClass, object::JsObject, object::builtins::JsArray,
 };
 use boa_gc::{Finalize, Trace};
 
@@ -62,16 +61,10 @@ impl HeadersIterator {
             .iterated_headers
             .borrow()
             .data()
-            .headers
-            .borrow()
-            .iter()
+            .sort_and_combine()
+            .into_iter()
             .nth(self.next_index)
-            .map(|(k, v)| {
-                (
-                    JsValue::from(JsString::from(k.as_str())),
-                    JsValue::from(JsString::from(v.to_str().unwrap_or(""))),
-                )
-            });
+            .map(|(k, v)| (JsValue::from(k), JsValue::from(v)));
 
         if let Some((key, value)) = element {
             self.next_index += 1;
@@ -24,6 +24,8 @@ use http::{HeaderName, HeaderValue, Request as HttpRequest, Request};
 use std::cell::RefCell;
 use std::rc::Rc;
 
+pub mod form_data;
+pub mod form_data_iterator;
 pub mod headers;
 pub mod headers_iterator;
 pub mod request;
@@ -186,6 +188,8 @@ pub mod js_module {
     use boa_engine::{Context, JsObject, JsString};
     use either::Either;
 
+    type JsFormData = super::form_data::JsFormData;
+    type FormDataIterator = super::form_data_iterator::FormDataIterator;
     type JsHeaders = super::JsHeaders;
     type JsRequest = super::JsRequest;
     type JsResponse = super::JsResponse;
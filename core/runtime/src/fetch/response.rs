@@ -6,7 +6,8 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Response
 
 use crate::fetch::headers::JsHeaders;
-use boa_engine::object::builtins::{JsPromise, JsUint8Array};
+use boa_engine::builtins::array_buffer::AlignedVec;
+use boa_engine::object::builtins::{JsArrayBuffer, JsPromise, JsUint8Array};
 use boa_engine::value::{Convert, TryFromJs, TryIntoJs};
 use boa_engine::{
     Context, JsData, JsNativeError, JsResult, JsString, JsValue, boa_class, js_error, js_str,
@@ -458,6 +459,17 @@ impl JsResponse {
         }
     }
 
+    fn array_buffer(&self, context: &mut Context) -> JsPromise {
+        let body = self.body.clone();
+        JsPromise::from_async_fn(
+            async move |context| {
+                let data = AlignedVec::from_slice(0, body.as_ref());
+                JsArrayBuffer::from_byte_block(data, &mut context.borrow_mut()).map(Into::into)
+            },
+            context,
+        )
+    }
+
     fn bytes(&self, context: &mut Context) -> JsPromise {
         let body = self.body.clone();
         JsPromise::from_async_fn(
@@ -0,0 +1,102 @@
+use super::TestFetcher;
+use crate::test::{TestAction, run_test_actions};
+
+fn register(ctx: &mut boa_engine::Context) {
+    crate::fetch::register(TestFetcher::default(), None, ctx).expect("failed to register fetch");
+}
+
+#[test]
+fn form_data_append_and_set() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(register),
+        TestAction::run(
+            r#"
+                const data = new FormData();
+                data.append("a", "1");
+                data.append("b", "2");
+                data.append("a", 3);
+
+                assertArrayEqual(data.getAll("a"), ["1", "3"]);
+                assertEq(data.get("a"), "1");
+                assertEq(data.get("missing"), null);
+                assert(data.has("b"));
+
+                data.set("a", "4");
+                assertArrayEqual(data.getAll("a"), ["4"]);
+                assertArrayEqual([...data.keys()], ["a", "b"]);
+
+                data.set("c", "5");
+                data.delete("b");
+                assertArrayEqual([...data.values()], ["4", "5"]);
+                assert(!data.has("b"));
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn form_data_iteration() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(register),
+        TestAction::run(
+            r#"
+                const data = new FormData();
+                data.append("x", "1");
+                data.append("y", "2");
+
+                const entries = [...data];
+                assertEq(entries.length, 2);
+                assertArrayEqual(entries[1], ["y", "2"]);
+                assertArrayEqual([...data.entries()][0], ["x", "1"]);
+
+                const seen = [];
+                data.forEach(function (value, name, object) {
+                    assertEq(object, data);
+                    assertEq(this, seen);
+                    seen.push(name + "=" + value);
+                }, seen);
+                assertArrayEqual(seen, ["x=1", "y=2"]);
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn form_data_blob_values() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(register),
+        TestAction::run(
+            r#"
+                const data = new FormData();
+                const file = new File(["abc"], "a.txt", { lastModified: 1 });
+                data.append("blob", new Blob(["abc"], { type: "text/plain" }));
+                data.append("file", file);
+                data.append("renamed", file, "b.txt");
+
+                const blob = data.get("blob");
+                assert(blob instanceof File);
+                assertEq(blob.name, "blob");
+                assertEq(blob.type, "text/plain");
+                assertEq(blob.size, 3);
+
+                assertEq(data.get("file"), file);
+
+                const renamed = data.get("renamed");
+                assertEq(renamed.name, "b.txt");
+                assertEq(renamed.lastModified, 1);
+
+                try {
+                    data.append("text", "abc", "c.txt");
+                    throw Error("expected the call above to throw");
+                } catch (e) {
+                    if (!(e instanceof TypeError)) {
+                        throw e;
+                    }
+                }
+            "#,
+        ),
+    ]);
+}
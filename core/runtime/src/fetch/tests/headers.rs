@@ -146,3 +146,66 @@ fn headers_iterator_throws_on_invalid_this() {
         ),
     ]);
 }
+
+#[test]
+fn headers_names_are_case_insensitive() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(register),
+        TestAction::run(
+            r#"
+                const headers = new Headers({ "Content-Type": "text/plain" });
+                assertEq(headers.get("content-type"), "text/plain");
+                assertEq(headers.get("CONTENT-TYPE"), "text/plain");
+                assert(headers.has("Content-type"));
+
+                headers.append("ACCEPT", "a");
+                headers.append("accept", "b");
+                assertEq(headers.get("Accept"), "a, b");
+
+                headers.set("Accept", "c");
+                assertEq(headers.get("accept"), "c");
+
+                headers.delete("CONTENT-TYPE");
+                assert(!headers.has("content-type"));
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn headers_iteration_sorts_and_combines() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(register),
+        TestAction::run(
+            r#"
+                const headers = new Headers();
+                headers.append("X-B", "1");
+                headers.append("Set-Cookie", "a=1");
+                headers.append("x-a", "2");
+                headers.append("x-b", "3");
+                headers.append("set-cookie", "b=2");
+
+                const entries = [...headers].map(([name, value]) => `${name}: ${value}`);
+                assertArrayEqual(entries, [
+                    "set-cookie: a=1",
+                    "set-cookie: b=2",
+                    "x-a: 2",
+                    "x-b: 1, 3",
+                ]);
+                assertArrayEqual([...headers.keys()], ["set-cookie", "set-cookie", "x-a", "x-b"]);
+                assertArrayEqual(headers.getSetCookie(), ["a=1", "b=2"]);
+                assertEq(headers.get("set-cookie"), "a=1, b=2");
+
+                const seen = [];
+                headers.forEach((value, name) => {
+                    // Mutating the headers while iterating is allowed.
+                    headers.delete("x-b");
+                    seen.push(name);
+                });
+                assertArrayEqual(seen, ["set-cookie", "set-cookie", "x-a"]);
+            "#,
+        ),
+    ]);
+}
@@ -144,6 +144,55 @@ fn response_bytes() {
     ]);
 }
 
+#[test]
+fn response_array_buffer() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| {
+            register(
+                &[("http://unit.test", Response::new(b"Hello World".to_vec()))],
+                ctx,
+            );
+        }),
+        TestAction::run(
+            r#"
+                globalThis.response = (async () => {
+                    const response = await fetch("http://unit.test");
+                    const buffer = await response.arrayBuffer();
+                    assert(buffer instanceof ArrayBuffer);
+                    assertEq(buffer.byteLength, 11);
+                    assertEq(new TextDecoder().decode(buffer), "Hello World");
+                })();
+            "#,
+        ),
+        TestAction::inspect_context(|ctx| {
+            let response = ctx.global_object().get(js_str!("response"), ctx).unwrap();
+            response.as_promise().unwrap().await_blocking(ctx).unwrap();
+        }),
+    ]);
+}
+
+#[test]
+fn fetch_relative_uri() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| {
+            register(&[("x", Response::new(b"fixed body".to_vec()))], ctx);
+        }),
+        TestAction::run(
+            r#"
+                globalThis.response = (async () => {
+                    assertEq(await (await fetch("x")).text(), "fixed body");
+                })();
+            "#,
+        ),
+        TestAction::inspect_context(|ctx| {
+            let response = ctx.global_object().get(js_str!("response"), ctx).unwrap();
+            response.as_promise().unwrap().await_blocking(ctx).unwrap();
+        }),
+    ]);
+}
+
 #[test]
 fn response_getter() {
     run_test_actions([
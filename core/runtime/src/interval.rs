@@ -6,18 +6,52 @@ use boa_engine::job::{CancellationToken, IntervalJob, NativeJobFn};
 use boa_engine::job::{NativeJob, TimeoutJob};
 use boa_engine::object::builtins::JsFunction;
 
+use boa_engine::context::time::{JsDuration, JsInstant};
 use boa_engine::{Context, IntoJsFunctionCopied, JsResult, JsValue, js_error, js_string};
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::num::NonZeroU32;
 
 #[cfg(test)]
 mod tests;
 
+/// Timers scheduled while the current nesting level is deeper than this have
+/// their delay clamped to [`MIN_NESTED_DELAY`].
+///
+/// See the [HTML specification][spec].
+///
+/// [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#timer-initialisation-steps
+const MAX_UNCLAMPED_NESTING_LEVEL: u32 = 5;
+
+/// The minimum delay, in milliseconds, of timers nested deeper than
+/// [`MAX_UNCLAMPED_NESTING_LEVEL`].
+const MIN_NESTED_DELAY: u64 = 4;
+
+/// A timer or interval registered through this module.
+struct ActiveTimer {
+    token: CancellationToken,
+    /// The time at which the timer fires next.
+    deadline: JsInstant,
+}
+
+/// A timer or interval that has not fired (or, for intervals, not been cleared) yet,
+/// as returned by [`pending_timers`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingTimer {
+    /// The ID returned by `setTimeout` or `setInterval`.
+    pub id: u32,
+    /// The time left until the timer fires next.
+    pub remaining: JsDuration,
+}
+
 /// The internal state of the interval module. The value is whether the interval
 /// function is still active.
 struct IntervalInnerState {
-    active_map: HashMap<NonZeroU32, CancellationToken>,
+    active_map: HashMap<NonZeroU32, ActiveTimer>,
     id: NonZeroU32,
+    /// The nesting level of the timer callback currently running, or `0` if
+    /// no timer callback is running.
+    nesting_level: u32,
 }
 
 impl Default for IntervalInnerState {
@@ -25,6 +59,7 @@ impl Default for IntervalInnerState {
         Self {
             active_map: HashMap::new(),
             id: NonZeroU32::MIN,
+            nesting_level: 0,
         }
     }
 }
@@ -45,7 +80,7 @@ impl IntervalInnerState {
 
     /// Create an interval ID.
     fn next_id(&mut self) -> JsResult<NonZeroU32> {
-        self.active_map.retain(|_, v| !v.revoked());
+        self.active_map.retain(|_, v| !v.token.revoked());
         let id = self.id;
         self.id = id
             .checked_add(1)
@@ -53,16 +88,48 @@ impl IntervalInnerState {
         Ok(id)
     }
 
+    /// Returns the nesting level of a timer scheduled now, along with its delay
+    /// clamped according to that level.
+    fn nest(&self, delay: u64) -> (u32, u64) {
+        let delay = clamp_delay(self.nesting_level, delay);
+        (self.nesting_level.saturating_add(1), delay)
+    }
+
+    /// Calls a timer callback, tracking its nesting level while it runs.
+    fn call_nested(
+        function_ref: &JsFunction,
+        args: &[JsValue],
+        level: u32,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::from_context(context).nesting_level = level;
+        let result = function_ref.call(&JsValue::undefined(), args, context);
+        Self::from_context(context).nesting_level = 0;
+        result
+    }
+
     /// Delete an interval ID from the active map.
     fn clear_interval(&mut self, id: u32) -> Option<CancellationToken> {
-        self.active_map.retain(|_, v| !v.revoked());
+        self.active_map.retain(|_, v| !v.token.revoked());
         let id = NonZeroU32::new(id)?;
-        self.active_map.remove(&id)
+        self.active_map.remove(&id).map(|timer| timer.token)
     }
 
     /// Drains and returns every active timer/interval token.
     fn drain_tokens(&mut self) -> Vec<CancellationToken> {
-        std::mem::take(&mut self.active_map).into_values().collect()
+        std::mem::take(&mut self.active_map)
+            .into_values()
+            .map(|timer| timer.token)
+            .collect()
+    }
+}
+
+/// Clamps the delay of a timer scheduled while running at the given nesting level.
+fn clamp_delay(level: u32, delay: u64) -> u64 {
+    if level > MAX_UNCLAMPED_NESTING_LEVEL {
+        delay.max(MIN_NESTED_DELAY)
+    } else {
+        delay
     }
 }
 
@@ -91,19 +158,58 @@ pub fn set_timeout(
 
     let state = IntervalInnerState::from_context(context);
     let id = state.next_id()?;
+    let (level, delay) = state.nest(delay);
 
     // Get ownership of rest arguments.
     let rest = rest.to_vec();
 
+    schedule_timeout(
+        id,
+        move |context| IntervalInnerState::call_nested(&function_ref, &rest, level, context),
+        delay,
+        true,
+        context,
+    );
+
+    Ok(id.get())
+}
+
+/// Schedules `f` to run after `delay` milliseconds, like `setTimeout`, without keeping the
+/// event loop running until then.
+///
+/// The timer is still listed by [`pending_timers`] and cancelled by [`clear_all`].
+///
+/// # Errors
+/// Returns an error if no timer ID is left.
+#[cfg(feature = "fetch")]
+pub(crate) fn set_unref_timeout(
+    f: impl FnOnce(&mut Context) -> JsResult<JsValue> + 'static,
+    delay: u64,
+    context: &mut Context,
+) -> JsResult<()> {
+    let id = IntervalInnerState::from_context(context).next_id()?;
+    schedule_timeout(id, f, delay, false, context);
+    Ok(())
+}
+
+/// Schedules the timeout `id`, tracking it until it runs or is cancelled.
+fn schedule_timeout(
+    id: NonZeroU32,
+    f: impl FnOnce(&mut Context) -> JsResult<JsValue> + 'static,
+    delay: u64,
+    keep_alive: bool,
+    context: &mut Context,
+) {
     let job = TimeoutJob::new(
         NativeJob::new(move |context| {
-            let result = function_ref.call(&JsValue::undefined(), &rest, context);
+            let result = f(context);
             let state = IntervalInnerState::from_context(context);
             state.active_map.remove(&id);
             result
         }),
         delay,
-    );
+    )
+    .with_keep_alive(keep_alive);
     let token = job.cancellation_token().clone();
 
     token.push_callback(move |context| {
@@ -111,11 +217,12 @@ pub fn set_timeout(
         state.active_map.remove(&id);
     });
 
-    state.active_map.insert(id, token);
+    let deadline = context.clock().now() + JsDuration::from_millis(delay);
+    IntervalInnerState::from_context(context)
+        .active_map
+        .insert(id, ActiveTimer { token, deadline });
 
     context.enqueue_job(job.into());
-
-    Ok(id.get())
 }
 
 /// Call a given function on an interval with the given delay.
@@ -143,12 +250,62 @@ pub fn set_interval(
 
     let state = IntervalInnerState::from_context(context);
     let id = state.next_id()?;
+    let (level, delay) = state.nest(delay);
 
-    // Get ownership of rest arguments.
-    let rest = rest.to_vec();
+    schedule_interval(id, function_ref, rest.to_vec(), level, delay, context);
+
+    Ok(id.get())
+}
 
+/// Schedules the interval `id`, whose next run happens at the given nesting level.
+///
+/// Each repeat runs one level deeper than the previous one, so intervals with short
+/// delays get clamped to [`MIN_NESTED_DELAY`] once they are nested deep enough. Since
+/// the delay of an [`IntervalJob`] is fixed, the interval is rescheduled as a new job
+/// under the same ID when that happens.
+fn schedule_interval(
+    id: NonZeroU32,
+    function_ref: JsFunction,
+    rest: Vec<JsValue>,
+    level: u32,
+    delay: u64,
+    context: &mut Context,
+) {
+    let next_level = Cell::new(level);
     let job = IntervalJob::new(
-        NativeJobFn::new(move |context| function_ref.call(&JsValue::undefined(), &rest, context)),
+        NativeJobFn::new(move |context| {
+            let level = next_level.get();
+            next_level.set(level.saturating_add(1));
+
+            // The executor schedules the next run relative to the start of this one.
+            let deadline = context.clock().now() + JsDuration::from_millis(delay);
+            if let Some(timer) = IntervalInnerState::from_context(context)
+                .active_map
+                .get_mut(&id)
+            {
+                timer.deadline = deadline;
+            }
+            let result = IntervalInnerState::call_nested(&function_ref, &rest, level, context);
+
+            let clamped = clamp_delay(level, delay);
+            if clamped != delay
+                && let Some(timer) = IntervalInnerState::from_context(context)
+                    .active_map
+                    .remove(&id)
+            {
+                timer.token.cancel(context);
+                schedule_interval(
+                    id,
+                    function_ref.clone(),
+                    rest.clone(),
+                    level.saturating_add(1),
+                    clamped,
+                    context,
+                );
+            }
+
+            result
+        }),
         delay,
     );
     let token = job.cancellation_token().clone();
@@ -158,11 +315,12 @@ pub fn set_interval(
         state.active_map.remove(&id);
     });
 
-    state.active_map.insert(id, token);
+    let deadline = context.clock().now() + JsDuration::from_millis(delay);
+    IntervalInnerState::from_context(context)
+        .active_map
+        .insert(id, ActiveTimer { token, deadline });
 
     context.enqueue_job(job.into());
-
-    Ok(id.get())
 }
 
 /// Clears a timeout or interval currently running.
@@ -206,6 +364,28 @@ pub fn clear_all(context: &mut Context) {
     }
 }
 
+/// Returns every currently active timer and interval registered through this
+/// module's `setTimeout` / `setInterval`, ordered by the time they fire next.
+///
+/// Combined with [`clear_all`], this lets embedders inspect outstanding work
+/// before tearing down a context.
+#[must_use]
+pub fn pending_timers(context: &mut Context) -> Vec<PendingTimer> {
+    let now = context.clock().now();
+    let state = IntervalInnerState::from_context(context);
+    let mut timers: Vec<_> = state
+        .active_map
+        .iter()
+        .filter(|(_, timer)| !timer.token.revoked())
+        .map(|(id, timer)| PendingTimer {
+            id: id.get(),
+            remaining: timer.deadline - now,
+        })
+        .collect();
+    timers.sort_by_key(|timer| (timer.remaining, timer.id));
+    timers
+}
+
 /// Register the interval module into the given context.
 ///
 /// # Errors
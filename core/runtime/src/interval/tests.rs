@@ -1,12 +1,12 @@
 use crate::interval;
 use crate::test::{TestAction, run_test_actions_with};
-use boa_engine::context::time::FixedClock;
+use boa_engine::context::time::{FixedClock, JsInstant};
 use boa_engine::context::{Clock, ContextBuilder};
 use boa_engine::job::{JobExecutor, SimpleJobExecutor};
-use boa_engine::{Context, js_str};
+use boa_engine::{Context, Source, js_str};
 use futures_lite::future::poll_once;
 use indoc::indoc;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::pin::pin;
 use std::rc::Rc;
 
@@ -47,6 +47,31 @@ fn two_zero_delay_timeouts_both_fire() {
     );
 }
 
+#[test]
+fn zero_delay_timeouts_fire_in_insertion_order() {
+    let clock = Rc::new(FixedClock::default());
+    let context = &mut create_context(clock.clone());
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                order = [];
+                for (let i = 1; i <= 5; i++) {
+                    setTimeout(() => order.push(i), 0);
+                }
+            "#}),
+            TestAction::inspect_context(move |ctx| {
+                clock.forward(1);
+                ctx.run_jobs().unwrap();
+
+                let order = ctx.eval(Source::from_bytes("order.join()")).unwrap();
+                assert_eq!(order.as_string().unwrap(), js_str!("1,2,3,4,5"));
+            }),
+        ],
+        context,
+    );
+}
+
 #[test]
 fn set_timeout_basic() {
     let clock = Rc::new(FixedClock::default());
@@ -328,3 +353,392 @@ fn clear_all_cancels_pending_timers_and_intervals() {
         context,
     );
 }
+
+#[test]
+fn set_timeout_forwards_extra_arguments() {
+    let clock = Rc::new(FixedClock::default());
+    let context = &mut create_context(clock.clone());
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                received = [];
+                setTimeout((a, b) => { received.push(a, b); }, 0, 1, 2);
+                id = setInterval((c) => { received.push(c); clearInterval(id); }, 0, 3);
+            "#}),
+            TestAction::inspect_context(move |ctx| {
+                clock.forward(1);
+                ctx.run_jobs().unwrap();
+
+                let received = ctx.global_object().get(js_str!("received"), ctx).unwrap();
+                let received = received.as_object().unwrap();
+                assert_eq!(
+                    received.get(js_str!("length"), ctx).unwrap().as_i32(),
+                    Some(3)
+                );
+                assert_eq!(received.get(0usize, ctx).unwrap().as_i32(), Some(1));
+                assert_eq!(received.get(1usize, ctx).unwrap().as_i32(), Some(2));
+                assert_eq!(received.get(2usize, ctx).unwrap().as_i32(), Some(3));
+            }),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn clear_timeout_shared_id_space() {
+    let clock = Rc::new(FixedClock::default());
+    let context = &mut create_context(clock.clone());
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                fired = [];
+                timeout = setTimeout(() => fired.push("timeout"), 10);
+                interval = setInterval(() => fired.push("interval"), 10);
+                clearInterval(timeout);
+                clearTimeout(interval);
+
+                // Unknown, invalid and not-yet-allocated ids are silently ignored.
+                clearTimeout(0);
+                clearTimeout(-1);
+                clearTimeout(123456);
+                clearInterval("not an id");
+            "#}),
+            TestAction::inspect_context(move |ctx| {
+                clock.forward(100);
+                ctx.run_jobs().unwrap();
+
+                let fired = ctx.global_object().get(js_str!("fired"), ctx).unwrap();
+                let fired = fired.as_object().unwrap();
+                assert_eq!(fired.get(js_str!("length"), ctx).unwrap().as_i32(), Some(0));
+            }),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn clear_timeout_of_due_timer() {
+    let clock = Rc::new(FixedClock::default());
+    let context = &mut create_context(clock.clone());
+
+    run_test_actions_with(
+        [
+            // Both timers share the same deadline, so by the time the first one
+            // runs the second one is already due in the pending queue.
+            TestAction::run(indoc! {r#"
+                fired = [];
+                first = setTimeout(() => { fired.push("first"); clearTimeout(second); }, 10);
+                second = setTimeout(() => fired.push("second"), 10);
+            "#}),
+            TestAction::inspect_context(move |ctx| {
+                clock.forward(100);
+                ctx.run_jobs().unwrap();
+
+                let fired = ctx.global_object().get(js_str!("fired"), ctx).unwrap();
+                let fired = fired.as_object().unwrap();
+                assert_eq!(fired.get(js_str!("length"), ctx).unwrap().as_i32(), Some(1));
+            }),
+            // Clearing an already-fired id is a no-op.
+            TestAction::run("clearTimeout(first);"),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn nested_timeouts_are_clamped() {
+    let clock = Rc::new(FixedClock::default());
+    let context = &mut create_context(clock.clone());
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                depth = 0;
+                function nest() {
+                    depth++;
+                    if (depth < 10) {
+                        setTimeout(nest, 0);
+                    }
+                }
+                setTimeout(nest, 0);
+            "#}),
+            TestAction::inspect_context_async(async move |ctx| {
+                let job_executor = ctx.downcast_job_executor::<SimpleJobExecutor>().unwrap();
+                let global_object = ctx.global_object();
+                let ctx = &RefCell::new(ctx);
+                let mut event_loop = pin!(poll_once(job_executor.run_jobs_async(ctx)));
+                let depth = |ctx: &RefCell<&mut Context>| {
+                    global_object
+                        .get(js_str!("depth"), &mut ctx.borrow_mut())
+                        .unwrap()
+                        .as_i32()
+                };
+
+                // The first six levels honor the zero delay, firing once per
+                // millisecond.
+                for expected in 1..=6 {
+                    clock.forward(1);
+                    assert!(event_loop.as_mut().await.is_none());
+                    assert_eq!(depth(ctx), Some(expected));
+                }
+
+                // The timeout scheduled from the sixth level is clamped to 4ms.
+                for _ in 0..4 {
+                    clock.forward(1);
+                    assert!(event_loop.as_mut().await.is_none());
+                    assert_eq!(depth(ctx), Some(6));
+                }
+                clock.forward(1);
+                assert!(event_loop.as_mut().await.is_none());
+                assert_eq!(depth(ctx), Some(7));
+            }),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn repeating_intervals_are_clamped() {
+    let clock = Rc::new(FixedClock::default());
+    let context = &mut create_context(clock.clone());
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                count = 0;
+                interval = setInterval(() => {
+                    count++;
+                    if (count == 10) {
+                        clearInterval(interval);
+                    }
+                }, 0);
+            "#}),
+            TestAction::inspect_context_async(async move |ctx| {
+                let job_executor = ctx.downcast_job_executor::<SimpleJobExecutor>().unwrap();
+                let global_object = ctx.global_object();
+                let ctx = &RefCell::new(ctx);
+                let mut event_loop = pin!(poll_once(job_executor.run_jobs_async(ctx)));
+                let count = |ctx: &RefCell<&mut Context>| {
+                    global_object
+                        .get(js_str!("count"), &mut ctx.borrow_mut())
+                        .unwrap()
+                        .as_i32()
+                };
+
+                // Every repeat is nested one level deeper, so the first six runs
+                // honor the zero delay.
+                for expected in 1..=6 {
+                    clock.forward(1);
+                    assert!(event_loop.as_mut().await.is_none());
+                    assert_eq!(count(ctx), Some(expected));
+                }
+
+                // Later repeats are clamped to 4ms.
+                for expected in 7..=8 {
+                    for _ in 0..4 {
+                        clock.forward(1);
+                        assert!(event_loop.as_mut().await.is_none());
+                        assert_eq!(count(ctx), Some(expected - 1));
+                    }
+                    clock.forward(1);
+                    assert!(event_loop.as_mut().await.is_none());
+                    assert_eq!(count(ctx), Some(expected));
+                }
+            }),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn run_jobs_to_completion_drains_promises_and_timers() {
+    let context = &mut Context::default();
+    interval::register(context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                order = [];
+                Promise.resolve()
+                    .then(() => order.push("promise"))
+                    .then(() => new Promise((resolve) => setTimeout(resolve, 0)))
+                    .then(() => order.push("after timeout"));
+                setTimeout(() => {
+                    order.push("timeout");
+                    Promise.resolve().then(() => order.push("microtask in timeout"));
+                }, 0);
+            "#}),
+            TestAction::inspect_context(|ctx| {
+                assert!(!ctx.run_jobs_to_completion(100).unwrap());
+
+                let order = ctx
+                    .eval(Source::from_bytes("order.join()"))
+                    .unwrap()
+                    .to_string(ctx)
+                    .unwrap();
+                assert_eq!(
+                    order,
+                    js_str!("promise,timeout,microtask in timeout,after timeout")
+                );
+            }),
+        ],
+        context,
+    );
+}
+
+/// A user-defined virtual clock, to check that timers only depend on the [`Clock`] trait.
+struct VirtualClock(Cell<u64>);
+
+impl VirtualClock {
+    fn advance(&self, millis: u64) {
+        self.0.set(self.0.get() + millis);
+    }
+}
+
+impl Clock for VirtualClock {
+    fn now(&self) -> JsInstant {
+        let millis = self.0.get();
+        JsInstant::new(
+            millis / 1000,
+            u32::try_from(millis % 1000).unwrap() * 1_000_000,
+        )
+    }
+
+    fn system_time_millis(&self) -> i64 {
+        i64::try_from(self.0.get()).unwrap()
+    }
+}
+
+#[test]
+fn set_timeout_with_custom_clock_fires_once() {
+    let clock = Rc::new(VirtualClock(Cell::new(0)));
+    let context = &mut create_context(clock.clone());
+
+    run_test_actions_with(
+        [
+            TestAction::run("calls = 0; setTimeout(() => { calls++; }, 100);"),
+            TestAction::inspect_context_async(async move |ctx| {
+                let job_executor = ctx.downcast_job_executor::<SimpleJobExecutor>().unwrap();
+                let global_object = ctx.global_object();
+                let ctx = &RefCell::new(ctx);
+                let calls = |ctx: &RefCell<&mut Context>| {
+                    global_object
+                        .get(js_str!("calls"), &mut ctx.borrow_mut())
+                        .unwrap()
+                        .as_i32()
+                };
+                let mut event_loop = pin!(poll_once(job_executor.clone().run_jobs_async(ctx)));
+
+                clock.advance(99);
+                assert!(event_loop.as_mut().await.is_none());
+                assert_eq!(calls(ctx), Some(0));
+
+                clock.advance(50);
+                assert!(event_loop.as_mut().await.is_some());
+                assert_eq!(calls(ctx), Some(1));
+
+                clock.advance(1000);
+                assert!(poll_once(job_executor.run_jobs_async(ctx)).await.is_some());
+                assert_eq!(calls(ctx), Some(1));
+            }),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn pending_timers_lists_and_clear_all_cancels() {
+    let clock = Rc::new(FixedClock::default());
+    let context = &mut create_context(clock.clone());
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                fired = [];
+                setTimeout(() => fired.push("a"), 300);
+                setInterval(() => fired.push("b"), 100);
+                setTimeout(() => fired.push("c"), 200);
+            "#}),
+            TestAction::inspect_context(move |ctx| {
+                clock.forward(50);
+                let pending: Vec<_> = interval::pending_timers(ctx)
+                    .into_iter()
+                    .map(|timer| (timer.id, timer.remaining.as_millis()))
+                    .collect();
+                assert_eq!(pending, [(2, 50), (3, 150), (1, 250)]);
+
+                interval::clear_all(ctx);
+                assert!(interval::pending_timers(ctx).is_empty());
+
+                clock.forward(1000);
+                ctx.run_jobs().unwrap();
+                let fired = ctx
+                    .eval(Source::from_bytes("fired.length"))
+                    .unwrap()
+                    .as_number();
+                assert_eq!(fired, Some(0.0), "cancelled timers must not fire");
+            }),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn run_jobs_to_completion_stops_at_budget() {
+    let context = &mut Context::default();
+    interval::register(context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                ticks = 0;
+                const id = setInterval(() => {
+                    ticks++;
+                    if (ticks === 3) {
+                        clearInterval(id);
+                    }
+                }, 0);
+            "#}),
+            TestAction::inspect_context(|ctx| {
+                assert!(ctx.run_jobs_to_completion(2).unwrap());
+                let ticks = ctx.global_object().get(js_str!("ticks"), ctx).unwrap();
+                assert_eq!(ticks.as_i32(), Some(2));
+
+                assert!(!ctx.run_jobs_to_completion(2).unwrap());
+                let ticks = ctx.global_object().get(js_str!("ticks"), ctx).unwrap();
+                assert_eq!(ticks.as_i32(), Some(3));
+            }),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn run_jobs_to_completion_stops_requeuing_microtasks() {
+    let context = &mut Context::default();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                count = 0;
+                function spin() {
+                    count++;
+                    Promise.resolve().then(spin);
+                }
+                spin();
+            "#}),
+            TestAction::inspect_context(|ctx| {
+                assert!(ctx.run_jobs_to_completion(10).unwrap());
+                let count = ctx.global_object().get(js_str!("count"), ctx).unwrap();
+                assert_eq!(count.as_i32(), Some(11));
+
+                assert!(ctx.run_jobs_to_completion(5).unwrap());
+                let count = ctx.global_object().get(js_str!("count"), ctx).unwrap();
+                assert_eq!(count.as_i32(), Some(16));
+            }),
+        ],
+        context,
+    );
+}
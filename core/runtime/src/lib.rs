@@ -115,20 +115,26 @@ pub mod console;
 pub use boa_wintertc::base64;
 
 #[doc(inline)]
-pub use console::{Console, ConsoleState, DefaultLogger, Logger, NullLogger};
+pub use console::{Console, ConsoleState, DefaultLogger, Logger, NullLogger, StreamLogger};
 
 #[cfg(feature = "fetch")]
 pub mod abort;
+pub mod blob;
 pub mod clone;
+pub mod crypto;
+pub mod event;
 pub mod extensions;
 #[cfg(feature = "fetch")]
 pub mod fetch;
 pub mod interval;
+pub mod loader;
 pub mod message;
 pub mod microtask;
+pub mod performance;
 #[cfg(feature = "process")]
 pub mod process;
 pub mod store;
+pub mod stream;
 /// Support for the `$262` test262 harness object.
 #[cfg(feature = "test262")]
 pub mod test262;
@@ -139,14 +145,148 @@ pub mod url;
 #[cfg(feature = "process")]
 use crate::extensions::ProcessExtension;
 use crate::extensions::{
-    Base64Extension, EncodingExtension, MicrotaskExtension, StructuredCloneExtension,
+    Base64Extension, BlobExtension, ConsoleExtension, CryptoExtension, EncodingExtension,
+    EventTargetExtension, MicrotaskExtension, PerformanceExtension, StructuredCloneExtension,
     TimeoutExtension,
 };
 pub use extensions::RuntimeExtension;
+use std::rc::Rc;
+
+/// Options controlling which built-in APIs [`register_with_options`] installs.
+///
+/// All APIs are enabled by default. Embedders can opt out of individual APIs, for
+/// example a sandbox that deliberately withholds timers:
+///
+/// ```
+/// # use boa_engine::Context;
+/// # use boa_runtime::RegisterOptions;
+/// let mut context = Context::default();
+/// boa_runtime::register_with_options(
+///     RegisterOptions::new().without_timers(),
+///     (),
+///     None,
+///     &mut context,
+/// )
+/// .unwrap();
+/// ```
+#[derive(Clone)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct RegisterOptions {
+    console: bool,
+    text: bool,
+    url: bool,
+    timers: bool,
+    module_resolver: Option<Rc<dyn loader::ModuleResolver>>,
+}
+
+impl std::fmt::Debug for RegisterOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegisterOptions")
+            .field("console", &self.console)
+            .field("text", &self.text)
+            .field("url", &self.url)
+            .field("timers", &self.timers)
+            .field("module_resolver", &self.module_resolver.is_some())
+            .finish()
+    }
+}
+
+impl Default for RegisterOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegisterOptions {
+    /// Creates options with all built-in APIs enabled.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            console: true,
+            text: true,
+            url: true,
+            timers: true,
+            module_resolver: None,
+        }
+    }
+
+    /// Do not register the default `console` object, logging to stdout and stderr.
+    #[must_use]
+    pub const fn without_console(mut self) -> Self {
+        self.console = false;
+        self
+    }
+
+    /// Do not register the `TextEncoder` and `TextDecoder` classes.
+    #[must_use]
+    pub const fn without_text(mut self) -> Self {
+        self.text = false;
+        self
+    }
+
+    /// Do not register the `URL` and `URLSearchParams` classes.
+    #[must_use]
+    pub const fn without_url(mut self) -> Self {
+        self.url = false;
+        self
+    }
+
+    /// Do not register the `setTimeout`, `setInterval`, `clearTimeout` and
+    /// `clearInterval` functions.
+    #[must_use]
+    pub const fn without_timers(mut self) -> Self {
+        self.timers = false;
+        self
+    }
+
+    /// Resolve `import` statements through `resolver`.
+    ///
+    /// The context has to be built with a [`RuntimeModuleLoader`](loader::RuntimeModuleLoader)
+    /// for the resolver to be used; registering fails otherwise.
+    #[must_use]
+    pub fn with_module_resolver(mut self, resolver: impl loader::ModuleResolver) -> Self {
+        self.module_resolver = Some(Rc::new(resolver));
+        self
+    }
+
+    /// Returns whether the default `console` object is registered.
+    #[must_use]
+    pub const fn console(&self) -> bool {
+        self.console
+    }
+
+    /// Returns whether the text encoding classes are registered.
+    #[must_use]
+    pub const fn text(&self) -> bool {
+        self.text
+    }
+
+    /// Returns whether the URL classes are registered.
+    #[must_use]
+    pub const fn url(&self) -> bool {
+        self.url
+    }
+
+    /// Returns whether the timer functions are registered.
+    #[must_use]
+    pub const fn timers(&self) -> bool {
+        self.timers
+    }
+
+    /// Returns whether a module resolver is registered.
+    #[must_use]
+    pub const fn module_resolver(&self) -> bool {
+        self.module_resolver.is_some()
+    }
+}
 
 /// Register all the built-in objects and functions of the `WebAPI` runtime, plus
 /// any extensions defined.
 ///
+/// This is equivalent to calling [`register_with_options`] with the default
+/// [`RegisterOptions`] without the default `console`, which is usually registered
+/// through a [`ConsoleExtension`](extensions::ConsoleExtension) with a custom logger.
+///
 /// # Errors
 /// This will error if any of the built-in objects or functions cannot be registered.
 pub fn register(
@@ -154,14 +294,47 @@ pub fn register(
     realm: Option<boa_engine::realm::Realm>,
     ctx: &mut boa_engine::Context,
 ) -> boa_engine::JsResult<()> {
+    register_with_options(
+        RegisterOptions::new().without_console(),
+        extensions,
+        realm,
+        ctx,
+    )
+}
+
+/// Register the built-in objects and functions of the `WebAPI` runtime enabled in
+/// `options`, plus any extensions defined.
+///
+/// Extensions are registered last, so a [`ConsoleExtension`](extensions::ConsoleExtension)
+/// passed here replaces the default console.
+///
+/// # Errors
+/// This will error if any of the built-in objects or functions cannot be registered.
+pub fn register_with_options(
+    options: RegisterOptions,
+    extensions: impl RuntimeExtension,
+    realm: Option<boa_engine::realm::Realm>,
+    ctx: &mut boa_engine::Context,
+) -> boa_engine::JsResult<()> {
+    if let Some(resolver) = options.module_resolver {
+        loader::register(resolver, realm.as_ref(), ctx)?;
+    }
+
     (
+        (
+            options.console.then(ConsoleExtension::default),
+            options.timers.then_some(TimeoutExtension),
+            options.text.then_some(EncodingExtension),
+            #[cfg(feature = "url")]
+            options.url.then_some(extensions::UrlExtension),
+        ),
         Base64Extension,
-        TimeoutExtension,
-        EncodingExtension,
+        BlobExtension,
         MicrotaskExtension,
         StructuredCloneExtension,
-        #[cfg(feature = "url")]
-        extensions::UrlExtension,
+        PerformanceExtension,
+        CryptoExtension,
+        EventTargetExtension,
         #[cfg(feature = "process")]
         ProcessExtension,
         #[cfg(feature = "fetch")]
@@ -188,6 +361,9 @@ pub fn register_extensions(
     Ok(())
 }
 
+#[cfg(test)]
+mod tests;
+
 #[cfg(test)]
 pub(crate) mod test {
     use crate::extensions::ConsoleExtension;
@@ -0,0 +1,295 @@
+//! Module loaders resolving `import` statements through an embedder supplied resolver.
+//!
+//! A module loader is a property of the [`Context`] and has to be installed when building it
+//! through [`ContextBuilder::module_loader`]. The runtime provides [`RuntimeModuleLoader`] for
+//! this, which delegates resolving specifiers and fetching source text to the
+//! [`ModuleResolver`] registered through [`RegisterOptions::with_module_resolver`].
+//!
+//! Two resolvers are provided: [`StaticModuleResolver`], serving a preloaded map of sources for
+//! tests and platforms without a filesystem such as wasm, and `FsModuleResolver`, loading modules
+//! from a root directory, behind the `fs` feature.
+//!
+//! ```
+//! # use std::rc::Rc;
+//! # use boa_engine::{Context, Module, Source};
+//! use boa_runtime::RegisterOptions;
+//! use boa_runtime::loader::{RuntimeModuleLoader, StaticModuleResolver};
+//!
+//! let mut context = Context::builder()
+//!     .module_loader(Rc::new(RuntimeModuleLoader))
+//!     .build()
+//!     .unwrap();
+//! let resolver = StaticModuleResolver::new().with_module("math", "export const two = 2;");
+//! boa_runtime::register_with_options(
+//!     RegisterOptions::new().with_module_resolver(resolver),
+//!     boa_runtime::extensions::ConsoleExtension::default(),
+//!     None,
+//!     &mut context,
+//! )
+//! .unwrap();
+//!
+//! let module = Module::parse(
+//!     Source::from_bytes("import { two } from 'math'; console.log(two);"),
+//!     None,
+//!     &mut context,
+//! )
+//! .unwrap();
+//! module.load_link_evaluate(&mut context);
+//! context.run_jobs().unwrap();
+//! ```
+//!
+//! [`ContextBuilder::module_loader`]: boa_engine::context::ContextBuilder::module_loader
+//! [`RegisterOptions::with_module_resolver`]: crate::RegisterOptions::with_module_resolver
+
+#[cfg(test)]
+mod tests;
+
+use boa_engine::module::{ModuleLoader, ModuleRequest, Referrer};
+use boa_engine::realm::Realm;
+use boa_engine::{Context, JsData, JsResult, JsString, Module, Source, js_error};
+use boa_gc::{Finalize, Trace};
+use rustc_hash::FxHashMap;
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+/// Resolves module specifiers and fetches the source text of the modules they name.
+///
+/// Registered through [`RegisterOptions::with_module_resolver`](crate::RegisterOptions::with_module_resolver)
+/// and used by [`RuntimeModuleLoader`] to load imported modules.
+pub trait ModuleResolver: 'static {
+    /// Resolves `specifier`, imported from `referrer`, to the path identifying the module.
+    ///
+    /// Imports resolving to the same path within a realm share a single [`Module`].
+    ///
+    /// # Errors
+    /// Returns an error if the specifier cannot be resolved.
+    fn resolve(
+        &self,
+        specifier: &JsString,
+        referrer: &Referrer,
+        context: &mut Context,
+    ) -> JsResult<PathBuf>;
+
+    /// Returns the source text of the module at `path`, as returned by
+    /// [`resolve`](ModuleResolver::resolve).
+    ///
+    /// # Errors
+    /// Returns an error if the source cannot be read.
+    fn load(&self, path: &Path, context: &mut Context) -> JsResult<String>;
+}
+
+/// A reference counted [`ModuleResolver`], stored in the context or in a realm.
+#[derive(Trace, Finalize, JsData)]
+struct ModuleResolverRc(#[unsafe_ignore_trace] Rc<dyn ModuleResolver>);
+
+/// The modules loaded in a realm, keyed by their resolved path.
+#[derive(Default, Trace, Finalize, JsData)]
+struct ModuleMap(FxHashMap<PathBuf, Module>);
+
+impl ModuleMap {
+    fn get(realm: &Realm, path: &Path) -> Option<Module> {
+        realm
+            .host_defined()
+            .get::<Self>()
+            .and_then(|map| map.0.get(path).cloned())
+    }
+
+    fn insert(realm: &Realm, path: PathBuf, module: Module) {
+        let mut host_defined = realm.host_defined_mut();
+        if !host_defined.has::<Self>() {
+            host_defined.insert(Self::default());
+        }
+        host_defined
+            .get_mut::<Self>()
+            .expect("Should have inserted.")
+            .0
+            .insert(path, module);
+    }
+}
+
+/// Registers `resolver` for the modules imported in `realm`, or in the whole context if
+/// `realm` is `None`.
+///
+/// # Errors
+/// Returns an error if the context was not built with a [`RuntimeModuleLoader`].
+pub(crate) fn register(
+    resolver: Rc<dyn ModuleResolver>,
+    realm: Option<&Realm>,
+    context: &mut Context,
+) -> JsResult<()> {
+    if context
+        .downcast_module_loader::<RuntimeModuleLoader>()
+        .is_none()
+    {
+        return Err(js_error!(
+            Error: "a module resolver requires the context to be built with a `RuntimeModuleLoader`"
+        ));
+    }
+
+    if let Some(realm) = realm {
+        realm.host_defined_mut().insert(ModuleResolverRc(resolver));
+    } else {
+        context.insert_data(ModuleResolverRc(resolver));
+    }
+    Ok(())
+}
+
+/// Get the module resolver of the current realm, falling back to the one of the context.
+fn get_resolver(context: &Context) -> JsResult<Rc<dyn ModuleResolver>> {
+    context
+        .realm()
+        .host_defined()
+        .get::<ModuleResolverRc>()
+        .or_else(|| context.get_data::<ModuleResolverRc>())
+        .map(|resolver| resolver.0.clone())
+        .ok_or_else(|| js_error!(TypeError: "no module resolver registered in the context"))
+}
+
+/// A [`ModuleLoader`] delegating to the [`ModuleResolver`] registered in the context.
+///
+/// Loaded modules are cached in the realm importing them, so a loader shared between
+/// contexts never hands out a module belonging to another context.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RuntimeModuleLoader;
+
+impl ModuleLoader for RuntimeModuleLoader {
+    async fn load_imported_module(
+        self: Rc<Self>,
+        referrer: Referrer,
+        request: ModuleRequest,
+        context: &RefCell<&mut Context>,
+    ) -> JsResult<Module> {
+        let context = &mut **context.borrow_mut();
+        let resolver = get_resolver(context)?;
+        let path = resolver.resolve(request.specifier(), &referrer, context)?;
+
+        let realm = context.realm().clone();
+        if let Some(module) = ModuleMap::get(&realm, &path) {
+            return Ok(module);
+        }
+
+        let source = resolver.load(&path, context)?;
+        let module = Module::parse(
+            Source::from_bytes(source.as_str()).with_path(&path),
+            Some(realm.clone()),
+            context,
+        )?;
+        ModuleMap::insert(&realm, path, module.clone());
+
+        Ok(module)
+    }
+}
+
+/// A module resolver serving modules from a preloaded map of specifiers to source text.
+///
+/// Specifiers are matched exactly, without any path resolution. This is useful for tests and
+/// for platforms without a filesystem, such as wasm.
+#[derive(Debug, Default, Clone)]
+pub struct StaticModuleResolver {
+    sources: FxHashMap<String, String>,
+}
+
+impl StaticModuleResolver {
+    /// Creates an empty `StaticModuleResolver`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the module `source` under `specifier`, replacing any previous source.
+    #[must_use]
+    pub fn with_module(mut self, specifier: impl Into<String>, source: impl Into<String>) -> Self {
+        self.insert(specifier, source);
+        self
+    }
+
+    /// Adds the module `source` under `specifier`, replacing any previous source.
+    pub fn insert(&mut self, specifier: impl Into<String>, source: impl Into<String>) {
+        self.sources.insert(specifier.into(), source.into());
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for StaticModuleResolver {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut resolver = Self::new();
+        for (specifier, source) in iter {
+            resolver.insert(specifier, source);
+        }
+        resolver
+    }
+}
+
+impl ModuleResolver for StaticModuleResolver {
+    fn resolve(
+        &self,
+        specifier: &JsString,
+        _referrer: &Referrer,
+        _context: &mut Context,
+    ) -> JsResult<PathBuf> {
+        let specifier = specifier.to_std_string_escaped();
+        if !self.sources.contains_key(&specifier) {
+            return Err(js_error!(TypeError: "could not find module `{}`", specifier));
+        }
+        Ok(PathBuf::from(specifier))
+    }
+
+    fn load(&self, path: &Path, _context: &mut Context) -> JsResult<String> {
+        path.to_str()
+            .and_then(|specifier| self.sources.get(specifier))
+            .cloned()
+            .ok_or_else(|| js_error!(TypeError: "could not find module `{}`", path.display()))
+    }
+}
+
+/// A module resolver loading modules from the filesystem, below a root directory.
+///
+/// Relative specifiers are resolved against the path of the importing module, and every
+/// specifier has to resolve to a path inside the root.
+#[cfg(feature = "fs")]
+#[derive(Debug, Clone)]
+pub struct FsModuleResolver {
+    root: PathBuf,
+}
+
+#[cfg(feature = "fs")]
+impl FsModuleResolver {
+    /// Creates a `FsModuleResolver` loading modules below `root`.
+    ///
+    /// # Errors
+    /// Returns an error if `root` cannot be canonicalized.
+    pub fn new(root: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self {
+            root: root.as_ref().canonicalize()?,
+        })
+    }
+
+    /// Returns the root directory modules are loaded from.
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+}
+
+#[cfg(feature = "fs")]
+impl ModuleResolver for FsModuleResolver {
+    fn resolve(
+        &self,
+        specifier: &JsString,
+        referrer: &Referrer,
+        context: &mut Context,
+    ) -> JsResult<PathBuf> {
+        boa_engine::module::resolve_module_specifier(
+            Some(&self.root),
+            specifier,
+            referrer.path(),
+            context,
+        )
+    }
+
+    fn load(&self, path: &Path, _context: &mut Context) -> JsResult<String> {
+        std::fs::read_to_string(path).map_err(
+            |err| js_error!(TypeError: "could not open file `{}`: {}", path.display(), err),
+        )
+    }
+}
@@ -0,0 +1,142 @@
+use super::{RuntimeModuleLoader, StaticModuleResolver};
+use crate::{RegisterOptions, register_with_options};
+use boa_engine::builtins::promise::PromiseState;
+use boa_engine::{Context, JsValue, Module, Source, js_string};
+use std::rc::Rc;
+
+fn context_with(resolver: StaticModuleResolver) -> Context {
+    let mut context = Context::builder()
+        .module_loader(Rc::new(RuntimeModuleLoader))
+        .build()
+        .unwrap();
+    register_with_options(
+        RegisterOptions::new().with_module_resolver(resolver),
+        (),
+        None,
+        &mut context,
+    )
+    .unwrap();
+    context
+}
+
+fn evaluate(source: &str, context: &mut Context) -> (Module, PromiseState) {
+    let module = Module::parse(Source::from_bytes(source), None, context).unwrap();
+    let promise = module.load_link_evaluate(context);
+    context.run_jobs().unwrap();
+    (module, promise.state())
+}
+
+#[test]
+fn static_resolver_resolves_import() {
+    let resolver = StaticModuleResolver::new()
+        .with_module("mod", "export const x = 42;")
+        .with_module("other", "import { x } from 'mod'; export const y = x + 1;");
+    let context = &mut context_with(resolver);
+
+    let (module, state) = evaluate(
+        "import { x } from 'mod'; import { y } from 'other'; export const sum = x + y;",
+        context,
+    );
+    assert!(matches!(state, PromiseState::Fulfilled(_)));
+
+    let namespace = module.namespace(context);
+    assert_eq!(
+        namespace.get(js_string!("sum"), context).unwrap(),
+        JsValue::from(85)
+    );
+}
+
+#[test]
+fn static_resolver_missing_module() {
+    let resolver: StaticModuleResolver = [("mod", "export const x = 42;")].into_iter().collect();
+    let context = &mut context_with(resolver);
+
+    let (_, state) = evaluate("import { x } from 'missing';", context);
+    let PromiseState::Rejected(error) = state else {
+        panic!("importing an unknown module should fail");
+    };
+    assert_eq!(
+        error.to_string(context).unwrap(),
+        js_string!("TypeError: could not find module `missing`")
+    );
+}
+
+#[test]
+fn shared_loader_keeps_modules_per_context() {
+    let loader = Rc::new(RuntimeModuleLoader);
+    let resolver = StaticModuleResolver::new().with_module("mod", "export let x = 1; x++;");
+
+    for _ in 0..2 {
+        let context = &mut Context::builder()
+            .module_loader(loader.clone())
+            .build()
+            .unwrap();
+        register_with_options(
+            RegisterOptions::new().with_module_resolver(resolver.clone()),
+            (),
+            None,
+            context,
+        )
+        .unwrap();
+
+        let (module, state) = evaluate("export { x } from 'mod';", context);
+        assert!(matches!(state, PromiseState::Fulfilled(_)));
+        assert_eq!(
+            module
+                .namespace(context)
+                .get(js_string!("x"), context)
+                .unwrap(),
+            JsValue::from(2)
+        );
+    }
+}
+
+#[test]
+fn module_resolver_requires_runtime_loader() {
+    let context = &mut Context::default();
+    let result = register_with_options(
+        RegisterOptions::new().with_module_resolver(StaticModuleResolver::new()),
+        (),
+        None,
+        context,
+    );
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn fs_resolver_resolves_relative_import() {
+    use super::FsModuleResolver;
+
+    let root = std::env::temp_dir().join(format!("boa_runtime_fs_resolver_{}", std::process::id()));
+    std::fs::create_dir_all(root.join("lib")).unwrap();
+    std::fs::write(root.join("lib/mod.js"), "export const x = 42;").unwrap();
+    std::fs::write(
+        root.join("main.js"),
+        "import { x } from './lib/mod.js'; export const y = x + 1;",
+    )
+    .unwrap();
+
+    let context = &mut Context::builder()
+        .module_loader(Rc::new(RuntimeModuleLoader))
+        .build()
+        .unwrap();
+    register_with_options(
+        RegisterOptions::new().with_module_resolver(FsModuleResolver::new(&root).unwrap()),
+        (),
+        None,
+        context,
+    )
+    .unwrap();
+
+    let (module, state) = evaluate("export { y } from 'main.js';", context);
+    std::fs::remove_dir_all(&root).unwrap();
+    assert!(matches!(state, PromiseState::Fulfilled(_)));
+    assert_eq!(
+        module
+            .namespace(context)
+            .get(js_string!("y"), context)
+            .unwrap(),
+        JsValue::from(43)
+    );
+}
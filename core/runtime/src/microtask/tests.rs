@@ -53,3 +53,55 @@ fn queue_microtask() {
         "# }
     );
 }
+
+#[test]
+fn queue_microtask_runs_before_timeout() {
+    let context = &mut Context::default();
+    crate::microtask::register(None, context).unwrap();
+    crate::interval::register(context).unwrap();
+    let logger = RecordingLogger::default();
+    crate::extensions::ConsoleExtension(logger.clone())
+        .register(None, context)
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                setTimeout(() => console.log("timeout"), 0);
+                queueMicrotask(() => console.log("microtask"));
+            "#}),
+            TestAction::inspect_context(|context| {
+                context.run_jobs().unwrap();
+            }),
+        ],
+        context,
+    );
+
+    let logs = logger.log.borrow().clone();
+    assert_eq!(
+        logs,
+        indoc! { r#"
+            microtask
+            timeout
+        "# }
+    );
+}
+
+#[test]
+fn queue_microtask_throwing_callback() {
+    let context = &mut Context::default();
+    crate::microtask::register(None, context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                queueMicrotask(() => { throw new Error("microtask error"); });
+            "#}),
+            TestAction::inspect_context(|context| {
+                let err = context.run_jobs().unwrap_err();
+                assert!(err.to_string().contains("microtask error"));
+            }),
+        ],
+        context,
+    );
+}
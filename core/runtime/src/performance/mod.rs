@@ -0,0 +1,74 @@
+//! Boa's implementation of the `performance` Web API object.
+//!
+//! Only the `now()` method and the `timeOrigin` property of the [High Resolution Time][spec]
+//! specification are implemented. Time is measured using the context's
+//! [`Clock`][boa_engine::context::Clock], which defaults to a monotonic clock based on
+//! [`std::time::Instant`], and can be overridden with
+//! [`ContextBuilder::clock`][boa_engine::context::ContextBuilder::clock].
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://w3c.github.io/hr-time/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Performance
+
+#[cfg(test)]
+mod tests;
+
+use boa_engine::native_function::NativeFunction;
+use boa_engine::object::ObjectInitializer;
+use boa_engine::property::Attribute;
+use boa_engine::{Context, JsObject, JsResult, JsString, JsSymbol, JsValue, js_string};
+
+/// Boa's implementation of the `performance` object.
+#[derive(Debug, Clone, Copy)]
+pub struct Performance;
+
+impl Performance {
+    /// Name of the built-in `performance` property.
+    pub const NAME: JsString = js_string!("performance");
+
+    /// Initializes the `performance` object, using the current time of the context's
+    /// clock as its time origin.
+    ///
+    /// # Errors
+    /// Returns an error if the object cannot be created.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn init(context: &mut Context) -> JsResult<JsObject> {
+        let origin = context.clock().now();
+        let time_origin = context.clock().system_time_millis() as f64;
+
+        let now = NativeFunction::from_copy_closure(move |_, _, context| {
+            let elapsed = context.clock().now() - origin;
+            Ok(JsValue::from(elapsed.as_nanos() as f64 / 1_000_000.0))
+        });
+
+        Ok(ObjectInitializer::new(context)
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Performance"),
+                Attribute::CONFIGURABLE,
+            )
+            .property(
+                js_string!("timeOrigin"),
+                time_origin,
+                Attribute::READONLY | Attribute::ENUMERABLE,
+            )
+            .function(now, js_string!("now"), 0)
+            .build())
+    }
+
+    /// Register the `performance` object globally.
+    ///
+    /// # Errors
+    /// This function will return an error if the property cannot be defined on the global object.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        let performance = Self::init(context)?;
+        context.register_global_property(
+            Self::NAME,
+            performance,
+            Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::performance::Performance;
+use crate::test::{TestAction, run_test_actions, run_test_actions_with};
+use boa_engine::context::ContextBuilder;
+use boa_engine::context::time::FixedClock;
+use boa_engine::{JsValue, Source};
+use indoc::indoc;
+use std::rc::Rc;
+
+#[test]
+fn now_is_monotonic() {
+    run_test_actions([TestAction::run(indoc! {r#"
+        const a = performance.now();
+        const b = performance.now();
+        if (typeof a !== "number" || a < 0) {
+            throw new Error("performance.now() should be a non-negative number, got: " + a);
+        }
+        if (b < a) {
+            throw new Error("performance.now() went backwards: " + a + " > " + b);
+        }
+        if (typeof performance.timeOrigin !== "number") {
+            throw new Error("performance.timeOrigin should be a number");
+        }
+    "#})]);
+}
+
+#[test]
+fn now_uses_context_clock() {
+    let clock = Rc::new(FixedClock::from_millis(1_000));
+    let context = &mut ContextBuilder::default()
+        .clock(clock.clone())
+        .build()
+        .unwrap();
+    Performance::register(context).unwrap();
+
+    run_test_actions_with(
+        [TestAction::inspect_context(move |ctx| {
+            let mut eval = |src: &str| ctx.eval(Source::from_bytes(src)).unwrap();
+            assert_eq!(eval("performance.timeOrigin"), JsValue::from(1_000));
+            assert_eq!(eval("performance.now()"), JsValue::from(0));
+
+            clock.forward(42);
+            assert_eq!(eval("performance.now()"), JsValue::from(42));
+            assert_eq!(eval("performance.timeOrigin"), JsValue::from(1_000));
+        })],
+        context,
+    );
+}
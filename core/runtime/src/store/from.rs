@@ -144,9 +144,36 @@ fn clone_typed_array(
     context: &mut Context,
 ) -> JsResult<JsValueStore> {
     let kind = buffer.kind().ok_or_else(unsupported_type)?;
+    let byte_offset = buffer.byte_offset(context)? as u64;
+    let length = buffer.length(context)? as u64;
     let buffer = buffer.buffer(context)?;
     let buffer = try_from_js_value(&buffer, transfer, seen, context)?;
-    let dolly = JsValueStore::new(ValueStoreInner::TypedArray { kind, buffer });
+    let dolly = JsValueStore::new(ValueStoreInner::TypedArray {
+        kind,
+        buffer,
+        byte_offset,
+        length,
+    });
+    seen.insert(original, dolly.clone());
+    Ok(dolly)
+}
+
+fn clone_data_view(
+    original: &JsObject,
+    data_view: &JsDataView,
+    transfer: &FxHashSet<JsObject>,
+    seen: &mut SeenMap,
+    context: &mut Context,
+) -> JsResult<JsValueStore> {
+    let byte_offset = data_view.byte_offset(context)?;
+    let byte_length = data_view.byte_length(context)?;
+    let buffer = data_view.buffer(context)?;
+    let buffer = try_from_js_value(&buffer, transfer, seen, context)?;
+    let dolly = JsValueStore::new(ValueStoreInner::DataView {
+        buffer,
+        byte_length,
+        byte_offset,
+    });
     seen.insert(original, dolly.clone());
     Ok(dolly)
 }
@@ -262,8 +289,8 @@ fn try_from_js_object_clone(
         return Err(js_error!(TypeError: "Errors are not supported yet."));
     } else if let Ok(ref regexp) = JsRegExp::from_object(object.clone()) {
         return clone_regexp(object, regexp, seen, context);
-    } else if let Ok(_dataview) = JsDataView::from_object(object.clone()) {
-        return Err(js_error!(TypeError: "Data views are not supported yet."));
+    } else if let Ok(ref data_view) = JsDataView::from_object(object.clone()) {
+        return clone_data_view(object, data_view, transfer, seen, context);
     } else if object.is_callable() {
         // Functions are invalid.
         return Err(unsupported_type());
@@ -117,17 +117,18 @@ enum ValueStoreInner {
     SharedArrayBuffer(SharedArrayBuffer),
 
     /// Dataview.
-    #[expect(unused)]
     DataView {
         buffer: JsValueStore,
         byte_length: u64,
         byte_offset: u64,
     },
 
-    /// Typed Array, including its kind and data.
+    /// Typed Array, including its kind, data and the range of the data it views.
     TypedArray {
         kind: TypedArrayKind,
         buffer: JsValueStore,
+        byte_offset: u64,
+        length: u64,
     },
 }
 
@@ -4,7 +4,7 @@ use boa_engine::builtins::array_buffer::{AlignedVec, SharedArrayBuffer};
 use boa_engine::builtins::typed_array::TypedArrayKind;
 use boa_engine::object::builtins::{
     JsArray, JsArrayBuffer, JsDataView, JsDate, JsMap, JsRegExp, JsSet, JsSharedArrayBuffer,
-    js_typed_array_from_kind,
+    js_typed_array_from_kind_with_range,
 };
 use boa_engine::{Context, JsBigInt, JsObject, JsResult, JsString, JsValue, js_error};
 use rustc_hash::FxHashMap;
@@ -89,6 +89,8 @@ fn try_into_js_typed_array(
     store: &JsValueStore,
     kind: TypedArrayKind,
     buffer: &JsValueStore,
+    byte_offset: u64,
+    length: u64,
     seen: &mut ReverseSeenMap,
     context: &mut Context,
 ) -> JsResult<JsValue> {
@@ -97,7 +99,7 @@ fn try_into_js_typed_array(
         return Err(unsupported_type());
     };
     let buffer = JsArrayBuffer::from_object(buffer)?;
-    let array = js_typed_array_from_kind(kind, buffer, context)?;
+    let array = js_typed_array_from_kind_with_range(kind, buffer, byte_offset, length, context)?;
     if let Some(o) = array.as_object() {
         seen.insert(store, o);
     }
@@ -220,8 +222,11 @@ pub(super) fn try_value_into_js(
             byte_length,
             byte_offset,
         } => try_into_data_view(store, buffer, *byte_length, *byte_offset, seen, context),
-        ValueStoreInner::TypedArray { kind, buffer } => {
-            try_into_js_typed_array(store, *kind, buffer, seen, context)
-        }
+        ValueStoreInner::TypedArray {
+            kind,
+            buffer,
+            byte_offset,
+            length,
+        } => try_into_js_typed_array(store, *kind, buffer, *byte_offset, *length, seen, context),
     }
 }
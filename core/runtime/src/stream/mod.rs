@@ -0,0 +1,255 @@
+//! Boa's implementation of the `ReadableStream` Web API class.
+//!
+//! Only byte streams over the contents of a `Blob`, as returned by `Blob.prototype.stream()`,
+//! are supported. Constructing a stream from an underlying source is not supported yet.
+//!
+//! More information:
+//!  - [Streams specification][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://streams.spec.whatwg.org/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/ReadableStream
+
+#[cfg(test)]
+mod tests;
+
+use boa_engine::builtins::iterable::create_iter_result_object;
+use boa_engine::interop::JsClass;
+use boa_engine::object::builtins::{JsPromise, JsUint8Array};
+use boa_engine::realm::Realm;
+use boa_engine::{
+    Context, Finalize, JsData, JsObject, JsResult, JsValue, Trace, boa_class, boa_module,
+    js_error,
+};
+use std::cell::Cell;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// The largest chunk returned by a single read, in bytes.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// The JavaScript `ReadableStream` class.
+///
+/// See the [MDN documentation][mdn] for more information.
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/ReadableStream
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct JsReadableStream {
+    #[unsafe_ignore_trace]
+    data: Arc<[u8]>,
+    #[unsafe_ignore_trace]
+    position: Cell<usize>,
+    #[unsafe_ignore_trace]
+    end: usize,
+    #[unsafe_ignore_trace]
+    locked: Cell<bool>,
+}
+
+impl JsReadableStream {
+    /// Create a new stream reading the bytes of `data` in `range`.
+    #[must_use]
+    pub fn from_bytes(data: Arc<[u8]>, range: Range<usize>) -> Self {
+        Self {
+            data,
+            position: Cell::new(range.start),
+            end: range.end,
+            locked: Cell::new(false),
+        }
+    }
+
+    /// Creates a new `ReadableStream` object from this stream.
+    ///
+    /// # Errors
+    /// Returns an error if the `ReadableStream` class is not registered in the realm.
+    pub fn into_object(self, context: &mut Context) -> JsResult<JsObject> {
+        let proto = context
+            .realm()
+            .get_class::<Self>()
+            .ok_or_else(|| js_error!(Error: "ReadableStream not registered"))?
+            .prototype();
+
+        Ok(JsObject::from_proto_and_data(proto, self))
+    }
+
+    /// Returns `true` if this stream is locked to a reader.
+    #[must_use]
+    pub fn is_locked(&self) -> bool {
+        self.locked.get()
+    }
+
+    /// Takes the next chunk of bytes from the stream, or `None` once it is closed.
+    fn next_chunk(&self) -> Option<&[u8]> {
+        let start = self.position.get();
+        if start >= self.end {
+            return None;
+        }
+        let end = self.end.min(start + CHUNK_SIZE);
+        self.position.set(end);
+        Some(&self.data[start..end])
+    }
+
+    /// Closes the stream, discarding the bytes that weren't read.
+    fn close(&self) {
+        self.position.set(self.end);
+    }
+}
+
+#[boa_class(rename = "ReadableStream")]
+#[boa(rename_all = "camelCase")]
+impl JsReadableStream {
+    /// Streams can only be created by `Blob.prototype.stream()` for now.
+    #[boa(constructor)]
+    fn constructor() -> JsResult<Self> {
+        Err(js_error!(
+            TypeError: "constructing a ReadableStream from an underlying source is not supported"
+        ))
+    }
+
+    /// Returns whether the stream is locked to a reader.
+    ///
+    /// See <https://streams.spec.whatwg.org/#rs-locked>
+    #[boa(getter)]
+    fn locked(&self) -> bool {
+        self.locked.get()
+    }
+
+    /// Locks the stream to a new `ReadableStreamDefaultReader`.
+    ///
+    /// See <https://streams.spec.whatwg.org/#rs-get-reader>
+    #[boa(method)]
+    fn get_reader(this: JsClass<Self>, context: &mut Context) -> JsResult<JsValue> {
+        let reader = JsReadableStreamDefaultReader::acquire(this.inner())?;
+        let proto = context
+            .realm()
+            .get_class::<JsReadableStreamDefaultReader>()
+            .ok_or_else(|| js_error!(Error: "ReadableStreamDefaultReader not registered"))?
+            .prototype();
+
+        Ok(JsObject::from_proto_and_data(proto, reader).into())
+    }
+
+    /// Closes the stream, returning a promise resolving to `undefined`.
+    ///
+    /// See <https://streams.spec.whatwg.org/#rs-cancel>
+    #[boa(method)]
+    fn cancel(&self, context: &mut Context) -> JsResult<JsPromise> {
+        if self.locked.get() {
+            return JsPromise::reject(
+                js_error!(TypeError: "cannot cancel a ReadableStream locked to a reader"),
+                context,
+            );
+        }
+        self.close();
+        JsPromise::resolve(JsValue::undefined(), context)
+    }
+}
+
+/// The JavaScript `ReadableStreamDefaultReader` class.
+///
+/// See the [MDN documentation][mdn] for more information.
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamDefaultReader
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct JsReadableStreamDefaultReader {
+    stream: Option<JsObject<JsReadableStream>>,
+}
+
+impl JsReadableStreamDefaultReader {
+    /// Creates a reader for `stream`, locking it.
+    fn acquire(stream: JsObject<JsReadableStream>) -> JsResult<Self> {
+        {
+            let stream = stream.borrow();
+            let stream = stream.data();
+            if stream.locked.get() {
+                return Err(js_error!(TypeError: "ReadableStream is already locked to a reader"));
+            }
+            stream.locked.set(true);
+        }
+
+        Ok(Self {
+            stream: Some(stream),
+        })
+    }
+}
+
+#[boa_class(rename = "ReadableStreamDefaultReader")]
+#[boa(rename_all = "camelCase")]
+impl JsReadableStreamDefaultReader {
+    /// Creates a new reader for `stream`, locking it.
+    ///
+    /// See <https://streams.spec.whatwg.org/#default-reader-constructor>
+    #[boa(constructor)]
+    fn constructor(stream: JsValue) -> JsResult<Self> {
+        let stream = stream
+            .as_object()
+            .and_then(|o| o.downcast::<JsReadableStream>().ok())
+            .ok_or_else(|| js_error!(TypeError: "argument is not a ReadableStream"))?;
+
+        Self::acquire(stream)
+    }
+
+    /// Returns a promise resolving to the next chunk of the stream, as a `Uint8Array`.
+    ///
+    /// See <https://streams.spec.whatwg.org/#default-reader-read>
+    #[boa(method)]
+    fn read(&self, context: &mut Context) -> JsResult<JsPromise> {
+        let Some(stream) = &self.stream else {
+            return JsPromise::reject(
+                js_error!(TypeError: "the reader has been released"),
+                context,
+            );
+        };
+
+        let chunk = stream.borrow().data().next_chunk().map(<[u8]>::to_vec);
+        let result = match chunk {
+            Some(chunk) => {
+                let chunk = JsUint8Array::from_iter(chunk, context)?;
+                create_iter_result_object(chunk.into(), false, context)
+            }
+            None => create_iter_result_object(JsValue::undefined(), true, context),
+        };
+        JsPromise::resolve(result, context)
+    }
+
+    /// Closes the stream, returning a promise resolving to `undefined`.
+    ///
+    /// See <https://streams.spec.whatwg.org/#generic-reader-cancel>
+    #[boa(method)]
+    fn cancel(&self, context: &mut Context) -> JsResult<JsPromise> {
+        let Some(stream) = &self.stream else {
+            return JsPromise::reject(
+                js_error!(TypeError: "the reader has been released"),
+                context,
+            );
+        };
+
+        stream.borrow().data().close();
+        JsPromise::resolve(JsValue::undefined(), context)
+    }
+
+    /// Unlocks the stream, so that another reader can be acquired.
+    ///
+    /// See <https://streams.spec.whatwg.org/#default-reader-release-lock>
+    #[boa(method)]
+    fn release_lock(&mut self) {
+        if let Some(stream) = self.stream.take() {
+            stream.borrow().data().locked.set(false);
+        }
+    }
+}
+
+/// JavaScript module containing the `ReadableStream` classes.
+#[boa_module]
+pub mod js_module {
+    type ReadableStream = super::JsReadableStream;
+    type ReadableStreamDefaultReader = super::JsReadableStreamDefaultReader;
+}
+
+/// Register the `ReadableStream` and `ReadableStreamDefaultReader` classes into the
+/// realm/context.
+///
+/// # Errors
+/// This will error if the context or realm cannot register the classes.
+pub fn register(realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+    js_module::boa_register(realm, context)
+}
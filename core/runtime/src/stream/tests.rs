@@ -0,0 +1,76 @@
+use crate::test::{TestAction, run_test_actions};
+use boa_engine::js_str;
+
+/// Awaits the promise stored in the `result` global, panicking if it rejects.
+fn await_result() -> TestAction {
+    TestAction::inspect_context(|ctx| {
+        let result = ctx.global_object().get(js_str!("result"), ctx).unwrap();
+        result.as_promise().unwrap().await_blocking(ctx).unwrap();
+    })
+}
+
+#[test]
+fn reader_locks_stream() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                globalThis.result = (async () => {
+                    const stream = new Blob(["abc"]).stream();
+                    assert(!stream.locked);
+
+                    const reader = stream.getReader();
+                    assert(stream.locked);
+                    assertThrows(() => stream.getReader());
+                    assertThrows(() => new ReadableStreamDefaultReader(stream));
+
+                    let rejected = false;
+                    await stream.cancel().catch((e) => {
+                        rejected = e instanceof TypeError;
+                    });
+                    assert(rejected);
+
+                    reader.releaseLock();
+                    assert(!stream.locked);
+                    rejected = false;
+                    await reader.read().catch((e) => {
+                        rejected = e instanceof TypeError;
+                    });
+                    assert(rejected);
+
+                    const other = new ReadableStreamDefaultReader(stream);
+                    const { value, done } = await other.read();
+                    assert(!done);
+                    assertArrayEqual(Array.from(value), [0x61, 0x62, 0x63]);
+                    assert((await other.read()).done);
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
+
+#[test]
+fn cancel_closes_stream() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                globalThis.result = (async () => {
+                    const stream = new Blob(["abc"]).stream();
+                    assertEq(await stream.cancel(), undefined);
+                    assert((await stream.getReader().read()).done);
+
+                    const reader = new Blob(["abc"]).stream().getReader();
+                    await reader.cancel();
+                    const { value, done } = await reader.read();
+                    assert(done);
+                    assertEq(value, undefined);
+
+                    assertThrows(() => new ReadableStream());
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
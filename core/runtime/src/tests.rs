@@ -0,0 +1,85 @@
+use crate::test::{TestAction, run_test_actions_with};
+use crate::{RegisterOptions, register, register_with_options};
+use boa_engine::Context;
+use indoc::indoc;
+
+#[test]
+fn register_without_timers() {
+    let context = &mut Context::default();
+    register_with_options(RegisterOptions::new().without_timers(), (), None, context).unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            for (const name of ["setTimeout", "setInterval", "clearTimeout", "clearInterval"]) {
+                if (typeof globalThis[name] !== "undefined") {
+                    throw new Error(name + " should not be registered");
+                }
+            }
+            if (typeof console !== "object" || typeof console.log !== "function") {
+                throw new Error("console should be registered");
+            }
+            if (typeof TextEncoder !== "function") {
+                throw new Error("TextEncoder should be registered");
+            }
+        "#})],
+        context,
+    );
+}
+
+#[test]
+fn register_without_console_and_text() {
+    let context = &mut Context::default();
+    register_with_options(
+        RegisterOptions::new().without_console().without_text(),
+        (),
+        None,
+        context,
+    )
+    .unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            if (typeof console !== "undefined") {
+                throw new Error("console should not be registered");
+            }
+            if (typeof TextEncoder !== "undefined" || typeof TextDecoder !== "undefined") {
+                throw new Error("text encoding classes should not be registered");
+            }
+            if (typeof setTimeout !== "function") {
+                throw new Error("setTimeout should be registered");
+            }
+        "#})],
+        context,
+    );
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn register_without_url() {
+    let context = &mut Context::default();
+    register_with_options(RegisterOptions::new().without_url(), (), None, context).unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            if (typeof URL !== "undefined" || typeof URLSearchParams !== "undefined") {
+                throw new Error("URL classes should not be registered");
+            }
+        "#})],
+        context,
+    );
+}
+
+#[test]
+fn register_does_not_add_a_console() {
+    let context = &mut Context::default();
+    register((), None, context).unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            if (typeof console !== "undefined") {
+                throw new Error("console should not be registered");
+            }
+        "#})],
+        context,
+    );
+}
@@ -12,6 +12,11 @@ pub(crate) mod utf8 {
             .collect()
     }
 
+    /// Returns whether `input` is valid UTF-8.
+    pub(crate) fn is_valid(input: &[u8]) -> bool {
+        std::str::from_utf8(input).is_ok()
+    }
+
     pub(crate) fn decode(mut input: &[u8], strip_bom: bool) -> JsString {
         if strip_bom {
             input = input.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(input);
@@ -24,6 +29,17 @@ pub(crate) mod utf8 {
 pub(crate) mod utf16le {
     use boa_engine::{JsString, js_string};
 
+    /// Returns whether `input` is valid UTF-16LE, without dangling bytes or unpaired surrogates.
+    pub(crate) fn is_valid(input: &[u8]) -> bool {
+        input.len().is_multiple_of(2)
+            && char::decode_utf16(
+                input
+                    .chunks_exact(2)
+                    .map(|pair| u16::from_le_bytes([pair[0], pair[1]])),
+            )
+            .all(|c| c.is_ok())
+    }
+
     pub(crate) fn decode(mut input: &[u8], strip_bom: bool) -> JsString {
         if strip_bom {
             input = input.strip_prefix(&[0xFF, 0xFE]).unwrap_or(input);
@@ -50,6 +66,17 @@ pub(crate) mod utf16le {
 pub(crate) mod utf16be {
     use boa_engine::{JsString, js_string};
 
+    /// Returns whether `input` is valid UTF-16BE, without dangling bytes or unpaired surrogates.
+    pub(crate) fn is_valid(input: &[u8]) -> bool {
+        input.len().is_multiple_of(2)
+            && char::decode_utf16(
+                input
+                    .chunks_exact(2)
+                    .map(|pair| u16::from_be_bytes([pair[0], pair[1]])),
+            )
+            .all(|c| c.is_ok())
+    }
+
     pub(crate) fn decode(mut input: Vec<u8>, strip_bom: bool) -> JsString {
         if strip_bom && input.starts_with(&[0xFE, 0xFF]) {
             input.drain(..2);
@@ -13,11 +13,12 @@ use boa_engine::{
 #[cfg(test)]
 mod tests;
 
-mod encodings;
+pub(crate) mod encodings;
 
 /// Options for the [`TextDecoder`] constructor.
 #[derive(Debug, Default, Clone, Copy, TryFromJs)]
 pub struct TextDecoderOptions {
+    fatal: Option<bool>,
     #[boa(rename = "ignoreBOM")]
     ignore_bom: Option<bool>,
 }
@@ -72,6 +73,8 @@ pub struct TextDecoder {
     #[unsafe_ignore_trace]
     encoding: Encoding,
     #[unsafe_ignore_trace]
+    fatal: bool,
+    #[unsafe_ignore_trace]
     ignore_bom: bool,
 }
 
@@ -88,6 +91,7 @@ impl TextDecoder {
         encoding: Option<JsString>,
         options: Option<TextDecoderOptions>,
     ) -> JsResult<Self> {
+        let fatal = options.and_then(|o| o.fatal).unwrap_or(false);
         let ignore_bom = options.and_then(|o| o.ignore_bom).unwrap_or(false);
 
         let encoding = match encoding {
@@ -102,6 +106,7 @@ impl TextDecoder {
 
         Ok(Self {
             encoding,
+            fatal,
             ignore_bom,
         })
     }
@@ -120,6 +125,17 @@ impl TextDecoder {
         }
     }
 
+    /// The [`TextDecoder.fatal`][mdn] read-only property returns a `bool` indicating
+    /// whether decoding invalid data throws a `TypeError` instead of inserting
+    /// replacement characters.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/fatal
+    #[boa(getter)]
+    #[must_use]
+    pub fn fatal(&self) -> bool {
+        self.fatal
+    }
+
     /// The [`TextDecoder.ignoreBOM`][mdn] read-only property returns a `bool` indicating
     /// whether the BOM (byte order mark) is ignored.
     ///
@@ -139,7 +155,8 @@ impl TextDecoder {
     /// `buffer` can be an `ArrayBuffer`, a `TypedArray` or a `DataView`.
     ///
     /// # Errors
-    /// Any error that arises during decoding the specific encoding.
+    /// Any error that arises during decoding the specific encoding, or if the data is
+    /// invalid and the decoder was created with `fatal` set to `true`.
     ///
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/decode
     pub fn decode(&self, buffer: JsValue, context: &mut Context) -> JsResult<JsString> {
@@ -197,6 +214,20 @@ impl TextDecoder {
             &full_data
         };
 
+        if self.fatal {
+            let valid = match self.encoding {
+                Encoding::Utf8 => encodings::utf8::is_valid(data),
+                Encoding::Utf16Le => encodings::utf16le::is_valid(data),
+                Encoding::Utf16Be => encodings::utf16be::is_valid(data),
+            };
+            if !valid {
+                return Err(js_error!(
+                    TypeError: "The encoded data is not valid {}.",
+                    self.encoding().to_std_string_lossy()
+                ));
+            }
+        }
+
         Ok(match self.encoding {
             Encoding::Utf8 => encodings::utf8::decode(data, strip_bom),
             Encoding::Utf16Le => encodings::utf16le::decode(data, strip_bom),
@@ -476,3 +476,108 @@ fn decoder_handle_data_view_offset_and_length() {
         context,
     );
 }
+
+#[test]
+fn decoder_handle_typed_array_views() {
+    let context = &mut Context::default();
+    text::register(None, context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                const bytes = new TextEncoder().encode("abcdefgh");
+                var decoded = new TextDecoder().decode(new Uint8Array(bytes.buffer, 2, 3));
+
+                const utf16 = new Uint8Array([0x61, 0, 0x62, 0, 0x63, 0, 0x64, 0]);
+                var decodedUtf16 = new TextDecoder("utf-16le")
+                    .decode(new Uint16Array(utf16.buffer, 2, 2));
+
+                const resizable = new ArrayBuffer(2, { maxByteLength: 8 });
+                const tracking = new Uint8Array(resizable, 1);
+                resizable.resize(4);
+                tracking.set([0x78, 0x79, 0x7A]);
+                var decodedTracking = new TextDecoder().decode(tracking);
+            "#}),
+            TestAction::inspect_context(|context| {
+                let global = context.global_object();
+                let decoded = global.get(js_str!("decoded"), context).unwrap();
+                assert_eq!(decoded.as_string(), Some(js_string!("cde")));
+
+                let decoded = global.get(js_str!("decodedUtf16"), context).unwrap();
+                assert_eq!(decoded.as_string(), Some(js_string!("bc")));
+
+                let decoded = global.get(js_str!("decodedTracking"), context).unwrap();
+                assert_eq!(decoded.as_string(), Some(js_string!("xyz")));
+            }),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn encoding_getters() {
+    let context = &mut Context::default();
+    text::register(None, context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::harness(),
+            TestAction::run(indoc! {r#"
+                assertEq(new TextEncoder().encoding, "utf-8");
+
+                const d1 = new TextDecoder("utf-16le", { fatal: true });
+                assertEq(d1.encoding, "utf-16le");
+                assertEq(d1.fatal, true);
+                assertEq(d1.ignoreBOM, false);
+
+                const d2 = new TextDecoder();
+                assertEq(d2.encoding, "utf-8");
+                assertEq(d2.fatal, false);
+
+                for (const name of ["encoding", "fatal", "ignoreBOM"]) {
+                    const desc = Object.getOwnPropertyDescriptor(TextDecoder.prototype, name);
+                    assertEq(typeof desc.get, "function");
+                    assertEq(desc.set, undefined);
+                }
+                const desc = Object.getOwnPropertyDescriptor(TextEncoder.prototype, "encoding");
+                assertEq(typeof desc.get, "function");
+            "#}),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn decoder_fatal() {
+    let context = &mut Context::default();
+    text::register(None, context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::harness(),
+            TestAction::run(indoc! {r#"
+                function throwsTypeError(f) {
+                    try {
+                        f();
+                    } catch (e) {
+                        return e instanceof TypeError;
+                    }
+                    return false;
+                }
+
+                const invalidUtf8 = new Uint8Array([0x61, 0xFF, 0x62]);
+                assertEq(new TextDecoder().decode(invalidUtf8), "a\uFFFDb");
+                assert(throwsTypeError(() => new TextDecoder("utf-8", { fatal: true }).decode(invalidUtf8)));
+
+                const fatal16 = new TextDecoder("utf-16le", { fatal: true });
+                assertEq(fatal16.decode(new Uint8Array([0x61, 0x00])), "a");
+                assert(throwsTypeError(() => fatal16.decode(new Uint8Array([0x61, 0x00, 0x62]))));
+                assert(throwsTypeError(() => fatal16.decode(new Uint8Array([0x00, 0xD8]))));
+                assert(throwsTypeError(() =>
+                    new TextDecoder("utf-16be", { fatal: true }).decode(new Uint8Array([0xDC, 0x00]))
+                ));
+            "#}),
+        ],
+        context,
+    );
+}
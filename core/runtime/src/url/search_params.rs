@@ -0,0 +1,267 @@
+//! The `URLSearchParams` JavaScript class, implemented as [`UrlSearchParams`].
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `URLSearchParams` specification][spec]
+//!
+//! [spec]: https://url.spec.whatwg.org/#interface-urlsearchparams
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams
+#![allow(clippy::needless_pass_by_value)]
+
+use super::Url;
+use boa_engine::interop::JsClass;
+use boa_engine::object::builtins::TypedJsFunction;
+use boa_engine::property::PropertyKey;
+use boa_engine::value::{Convert, TryFromJs};
+use boa_engine::{
+    Context, Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace, boa_class, js_error,
+};
+
+/// A callback function for the `forEach` method.
+type ForEachCallback = TypedJsFunction<(JsString, JsString, JsObject), ()>;
+
+/// The `URLSearchParams` class, a list of name-value pairs representing the query
+/// of a URL.
+///
+/// A `URLSearchParams` object obtained through [`URL.searchParams`][Url] is associated
+/// with that URL: any mutation of the list is written back to the URL's query, and
+/// any change to the URL's query is reflected in the list.
+#[derive(Debug, Default, Clone, JsData, Trace, Finalize)]
+pub struct UrlSearchParams {
+    #[unsafe_ignore_trace]
+    list: Vec<(String, String)>,
+    url: Option<JsObject<Url>>,
+}
+
+impl UrlSearchParams {
+    /// Creates a list of name-value pairs from an `application/x-www-form-urlencoded`
+    /// string (without the leading `?`).
+    #[must_use]
+    pub fn from_query(query: &str) -> Self {
+        Self {
+            list: parse_query(query),
+            url: None,
+        }
+    }
+
+    /// Returns the name-value pairs of this object.
+    #[must_use]
+    pub fn pairs(&self) -> &[(String, String)] {
+        &self.list
+    }
+
+    /// Associates this object with the `URL` object it was obtained from.
+    pub(super) fn with_url(query: Option<&str>, url: JsObject<Url>) -> Self {
+        Self {
+            list: query.map(parse_query).unwrap_or_default(),
+            url: Some(url),
+        }
+    }
+
+    /// Replaces the list with the pairs parsed from `query`, without updating
+    /// the associated URL. Used when the URL's query changes.
+    pub(super) fn reset(&mut self, query: Option<&str>) {
+        self.list = query.map(parse_query).unwrap_or_default();
+    }
+
+    /// Serializes the list to an `application/x-www-form-urlencoded` string.
+    fn serialize(&self) -> String {
+        url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(&self.list)
+            .finish()
+    }
+
+    /// Runs the [update steps][spec], writing the list back into the associated URL.
+    ///
+    /// [spec]: https://url.spec.whatwg.org/#concept-urlsearchparams-update
+    fn update(&self) {
+        let Some(url) = &self.url else {
+            return;
+        };
+
+        let serialized = self.serialize();
+        let query = if serialized.is_empty() {
+            None
+        } else {
+            Some(serialized.as_str())
+        };
+        url.borrow_mut().data_mut().inner.set_query(query);
+    }
+}
+
+/// Parses an `application/x-www-form-urlencoded` string into a list of name-value pairs.
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    url::form_urlencoded::parse(query.as_bytes())
+        .into_owned()
+        .collect()
+}
+
+#[boa_class(rename = "URLSearchParams")]
+#[boa(rename_all = "camelCase")]
+impl UrlSearchParams {
+    /// Create a new `URLSearchParams` object. `init` can be a query string, a sequence
+    /// of name-value pairs, a record of names to values, or another `URLSearchParams`.
+    ///
+    /// # Errors
+    /// If `init` is a sequence containing an item that isn't a pair.
+    #[boa(constructor)]
+    pub fn constructor(init: Option<JsValue>, context: &mut Context) -> JsResult<Self> {
+        let Some(init) = init.filter(|v| !v.is_undefined()) else {
+            return Ok(Self::default());
+        };
+
+        let Some(object) = init.as_object() else {
+            let init = init.to_string(context)?.to_std_string_lossy();
+            return Ok(Self::from_query(init.strip_prefix('?').unwrap_or(&init)));
+        };
+
+        if let Some(other) = object.downcast_ref::<Self>() {
+            return Ok(Self {
+                list: other.list.clone(),
+                url: None,
+            });
+        }
+
+        if let Ok(sequence) = Vec::<Vec<Convert<String>>>::try_from_js(&init, context) {
+            let list = sequence
+                .into_iter()
+                .map(|pair| match <[_; 2]>::try_from(pair) {
+                    Ok([name, value]) => Ok((name.0.clone(), value.0.clone())),
+                    Err(_) => Err(js_error!(
+                        TypeError: "Each URLSearchParams init pair must have exactly two items"
+                    )),
+                })
+                .collect::<JsResult<_>>()?;
+            return Ok(Self { list, url: None });
+        }
+
+        let mut list = Vec::new();
+        for key in object.own_property_keys(context)? {
+            let name = match &key {
+                PropertyKey::String(name) => name.to_std_string_lossy(),
+                PropertyKey::Index(index) => index.get().to_string(),
+                PropertyKey::Symbol(_) => continue,
+            };
+            let value = object.get(key, context)?;
+            let value = Convert::<String>::try_from_js(&value, context)?;
+            list.push((name, value.0.clone()));
+        }
+        Ok(Self { list, url: None })
+    }
+
+    /// Returns the number of name-value pairs.
+    #[boa(getter)]
+    fn size(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Appends a new name-value pair to the list.
+    fn append(&mut self, name: Convert<String>, value: Convert<String>) {
+        self.list.push((name.0.clone(), value.0.clone()));
+        self.update();
+    }
+
+    /// Removes all pairs with the given name, and the given value if provided.
+    fn delete(&mut self, name: Convert<String>, value: Option<Convert<String>>) {
+        let name = name.as_ref();
+        match value {
+            Some(value) => self.list.retain(|(n, v)| n != name || v != value.as_ref()),
+            None => self.list.retain(|(n, _)| n != name),
+        }
+        self.update();
+    }
+
+    /// Executes a provided function once for each name-value pair, in list order.
+    ///
+    /// Pairs appended by the callback during iteration are visited as well.
+    ///
+    /// # Errors
+    /// If the callback function returns an error, it is returned.
+    #[boa(method)]
+    fn for_each(
+        this: JsClass<Self>,
+        callback: ForEachCallback,
+        this_arg: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let object = this.inner().upcast();
+        let this_arg = this_arg.unwrap_or_default();
+        let mut index = 0;
+        loop {
+            // The list is re-borrowed on each step, as the callback may mutate it.
+            let Some((k, v)) = this
+                .borrow()
+                .list
+                .get(index)
+                .map(|(k, v)| (JsString::from(k.as_str()), JsString::from(v.as_str())))
+            else {
+                return Ok(());
+            };
+            callback.call_with_this(&this_arg, context, (v, k, object.clone()))?;
+            index += 1;
+        }
+    }
+
+    /// Returns the value of the first pair with the given name, or `null`.
+    fn get(&self, name: Convert<String>) -> JsValue {
+        self.list
+            .iter()
+            .find(|(n, _)| n == name.as_ref())
+            .map_or_else(JsValue::null, |(_, v)| JsString::from(v.as_str()).into())
+    }
+
+    /// Returns the values of all pairs with the given name.
+    fn get_all(&self, name: Convert<String>) -> Vec<JsString> {
+        self.list
+            .iter()
+            .filter(|(n, _)| n == name.as_ref())
+            .map(|(_, v)| JsString::from(v.as_str()))
+            .collect()
+    }
+
+    /// Returns whether a pair with the given name, and the given value if provided, exists.
+    fn has(&self, name: Convert<String>, value: Option<Convert<String>>) -> bool {
+        let name = name.as_ref();
+        match value {
+            Some(value) => self
+                .list
+                .iter()
+                .any(|(n, v)| n == name && v == value.as_ref()),
+            None => self.list.iter().any(|(n, _)| n == name),
+        }
+    }
+
+    /// Sets the value of the first pair with the given name and removes all the others,
+    /// or appends a new pair if none exists.
+    fn set(&mut self, name: Convert<String>, value: Convert<String>) {
+        let (name, value) = (name.as_ref(), value.as_ref());
+        let mut found = false;
+        self.list.retain_mut(|(n, v)| {
+            if n != name {
+                return true;
+            }
+            if found {
+                return false;
+            }
+            found = true;
+            v.clone_from(value);
+            true
+        });
+        if !found {
+            self.list.push((name.clone(), value.clone()));
+        }
+        self.update();
+    }
+
+    /// Sorts all pairs by their names, preserving the relative order of pairs with
+    /// equal names. Names are compared by UTF-16 code units.
+    fn sort(&mut self) {
+        self.list
+            .sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+        self.update();
+    }
+
+    fn to_string(&self) -> JsString {
+        JsString::from(self.serialize())
+    }
+}
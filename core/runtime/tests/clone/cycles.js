@@ -0,0 +1,39 @@
+// Shared references and cycles must be preserved by the clone.
+{
+  const original = { name: "root" };
+  original.self = original;
+  original.children = [original, { parent: original }];
+
+  const dolly = structuredClone(original);
+  assert(dolly !== original);
+  assertEq(dolly.self, dolly);
+  assertEq(dolly.children[0], dolly);
+  assertEq(dolly.children[1].parent, dolly);
+}
+
+{
+  const shared = { value: 1 };
+  const map = new Map([
+    ["a", shared],
+    ["b", shared],
+  ]);
+  map.set("map", map);
+
+  const dolly = structuredClone(map);
+  assert(dolly instanceof Map);
+  assert(dolly !== map);
+  assertEq(dolly.get("a"), dolly.get("b"));
+  assertEq(dolly.get("a").value, 1);
+  assertEq(dolly.get("map"), dolly);
+}
+
+// Non-cloneable values throw a `DataCloneError`.
+for (const value of [function () {}, Symbol("s"), { nested: () => {} }]) {
+  let message = "";
+  try {
+    structuredClone(value);
+  } catch (e) {
+    message = String(e);
+  }
+  assert(message.includes("DataCloneError"), `expected a DataCloneError, got: ${message}`);
+}
@@ -20,3 +20,15 @@
     "Second and Third should NOT be the same object.",
   );
 }
+
+{
+  const date = new Date(2020, 1, 29, 12, 34, 56, 789);
+  const dolly = structuredClone(date);
+
+  assert(dolly instanceof Date, "A cloned date should be a Date");
+  assertNEq(dolly, date, "A cloned date should be a different object");
+  assertEq(dolly.getTime(), date.getTime(), "A cloned date should have the same time");
+
+  const invalid = structuredClone(new Date(NaN));
+  assert(Number.isNaN(invalid.getTime()), "An invalid date should stay invalid");
+}
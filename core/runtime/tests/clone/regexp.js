@@ -29,3 +29,14 @@
     "These should be have the same prototype.",
   );
 }
+
+{
+  const re = /a(b)c/gimsuy;
+  re.lastIndex = 3;
+  const dolly = structuredClone(re);
+
+  assertNEq(dolly, re, "A cloned regexp should be a different object");
+  assertEq(dolly.source, re.source);
+  assertEq(dolly.flags, re.flags);
+  assertEq(dolly.lastIndex, 0, "The lastIndex of a cloned regexp should be reset");
+}
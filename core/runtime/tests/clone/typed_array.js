@@ -0,0 +1,45 @@
+{
+  const array = new Uint8Array([1, 2, 3, 4]);
+  const dolly = structuredClone(array);
+
+  assert(dolly instanceof Uint8Array, "A cloned Uint8Array should be a Uint8Array");
+  assertNEq(dolly.buffer, array.buffer, "A cloned typed array should have its own buffer");
+  assertArrayEqual(dolly, array);
+
+  dolly[0] = 42;
+  assertEq(array[0], 1, "Mutating the clone should not change the original");
+  array[1] = 43;
+  assertEq(dolly[1], 2, "Mutating the original should not change the clone");
+}
+
+{
+  // Views keep their offset and length into the cloned buffer.
+  const buffer = new ArrayBuffer(16);
+  const view = new Int16Array(buffer, 4, 3);
+  view.set([-1, 2, -3]);
+  const dolly = structuredClone(view);
+
+  assert(dolly instanceof Int16Array);
+  assertEq(dolly.byteOffset, 4);
+  assertEq(dolly.length, 3);
+  assertEq(dolly.buffer.byteLength, 16);
+  assertArrayEqual(dolly, [-1, 2, -3]);
+}
+
+{
+  // Views on the same buffer still share a buffer after cloning.
+  const bytes = new Uint8Array(8);
+  const data = new DataView(bytes.buffer, 2, 4);
+  data.setUint16(0, 0xbeef);
+  const dolly = structuredClone({ bytes, data });
+
+  assert(dolly.data instanceof DataView, "A cloned DataView should be a DataView");
+  assertEq(dolly.data.byteOffset, 2);
+  assertEq(dolly.data.byteLength, 4);
+  assertEq(dolly.data.getUint16(0), 0xbeef);
+  assertEq(dolly.data.buffer, dolly.bytes.buffer);
+
+  dolly.bytes[2] = 0;
+  assertEq(dolly.data.getUint16(0), 0xef);
+  assertEq(data.getUint16(0), 0xbeef);
+}
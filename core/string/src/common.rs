@@ -134,6 +134,7 @@ impl StaticJsStrings {
         (DATE, "Date"),
         (ERROR, "Error"),
         (AGGREGATE_ERROR, "AggregateError"),
+        (SUPPRESSED_ERROR, "SuppressedError"),
         (EVAL_ERROR, "EvalError"),
         (RANGE_ERROR, "RangeError"),
         (REFERENCE_ERROR, "ReferenceError"),
@@ -171,6 +172,8 @@ impl StaticJsStrings {
         (STRING, "String"),
         (SYMBOL, "Symbol"),
         (FINALIZATION_REGISTRY, "FinalizationRegistry"),
+        (DISPOSABLE_STACK, "DisposableStack"),
+        (ASYNC_DISPOSABLE_STACK, "AsyncDisposableStack"),
         (TYPED_ARRAY, "TypedArray"),
         (INT8_ARRAY, "Int8Array"),
         (UINT8_ARRAY, "Uint8Array"),
@@ -282,6 +285,7 @@ const RAW_STATICS: &[StaticString] = &[
     StaticString::new(JsStr::latin1("Date".as_bytes())),
     StaticString::new(JsStr::latin1("Error".as_bytes())),
     StaticString::new(JsStr::latin1("AggregateError".as_bytes())),
+    StaticString::new(JsStr::latin1("SuppressedError".as_bytes())),
     StaticString::new(JsStr::latin1("EvalError".as_bytes())),
     StaticString::new(JsStr::latin1("RangeError".as_bytes())),
     StaticString::new(JsStr::latin1("ReferenceError".as_bytes())),
@@ -339,6 +343,8 @@ const RAW_STATICS: &[StaticString] = &[
     StaticString::new(JsStr::latin1("WeakMap".as_bytes())),
     StaticString::new(JsStr::latin1("WeakSet".as_bytes())),
     StaticString::new(JsStr::latin1("FinalizationRegistry".as_bytes())),
+    StaticString::new(JsStr::latin1("DisposableStack".as_bytes())),
+    StaticString::new(JsStr::latin1("AsyncDisposableStack".as_bytes())),
     StaticString::new(JsStr::latin1("Temporal".as_bytes())),
     StaticString::new(JsStr::latin1("Temporal.Now".as_bytes())),
     StaticString::new(JsStr::latin1("Temporal.Instant".as_bytes())),
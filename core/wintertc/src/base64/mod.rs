@@ -34,7 +34,7 @@ pub mod js_module {
     /// a byte of binary data).
     ///
     /// # Errors
-    /// Throws a `DOMException` (`InvalidCharacterError`) if the string
+    /// Throws an `InvalidCharacterError` `TypeError` if the string
     /// contains any character whose code point is greater than `0xFF`.
     ///
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Window/btoa
@@ -45,7 +45,7 @@ pub mod js_module {
             .map(|c| {
                 let cp = c as u32;
                 if cp > 0xFF {
-                    Err(js_error!("InvalidCharacterError: The string to be encoded contains characters outside of the Latin1 range."))
+                    Err(js_error!(TypeError: "InvalidCharacterError: The string to be encoded contains characters outside of the Latin1 range."))
                 } else {
                     Ok(cp as u8)
                 }
@@ -59,7 +59,7 @@ pub mod js_module {
     /// encoded using Base64 encoding.
     ///
     /// # Errors
-    /// Throws a `DOMException` (`InvalidCharacterError`) if the input is
+    /// Throws an `InvalidCharacterError` `TypeError` if the input is
     /// not valid Base64.
     ///
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Window/atob
@@ -70,7 +70,7 @@ pub mod js_module {
             .collect();
 
         let bytes = FORGIVING.decode(cleaned.as_bytes()).map_err(|_| {
-            js_error!("InvalidCharacterError: The string to be decoded is not correctly encoded.")
+            js_error!(TypeError: "InvalidCharacterError: The string to be decoded is not correctly encoded.")
         })?;
 
         Ok(bytes.into_iter().map(char::from).collect())
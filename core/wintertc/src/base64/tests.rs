@@ -152,3 +152,29 @@ fn atob_ignores_whitespace() {
         context,
     );
 }
+
+#[test]
+fn invalid_character_errors_are_type_errors() {
+    let context = &mut Context::default();
+    crate::base64::register(None, context).unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            if (atob(btoa("Hello")) !== "Hello") {
+                throw new Error("roundtrip of 'Hello' failed");
+            }
+
+            for (const f of [() => btoa("✓"), () => atob("!!!!")]) {
+                try {
+                    f();
+                    throw new Error("expected " + f + " to throw");
+                } catch (e) {
+                    if (!(e instanceof TypeError)) {
+                        throw new Error("expected a TypeError, got: " + e);
+                    }
+                }
+            }
+        "#})],
+        context,
+    );
+}
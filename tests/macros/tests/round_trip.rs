@@ -0,0 +1,97 @@
+//! Tests for round-tripping structs through `TryFromJs` and `TryIntoJs` derives.
+
+#![allow(unused_crate_dependencies)]
+
+use boa_engine::value::{TryFromJs, TryIntoJs};
+use boa_engine::{Context, JsValue, Source, js_string};
+
+#[derive(Debug, PartialEq, TryFromJs, TryIntoJs)]
+struct Settings {
+    name: String,
+    #[boa(rename = "maxSize")]
+    max_size: f64,
+    enabled: Option<bool>,
+}
+
+#[test]
+fn round_trip() {
+    let mut context = Context::default();
+    let value = context
+        .eval(Source::from_bytes(
+            r"({ name: 'boa', maxSize: 42.5, enabled: true })",
+        ))
+        .unwrap();
+
+    let settings = Settings::try_from_js(&value, &mut context).unwrap();
+    assert_eq!(
+        settings,
+        Settings {
+            name: "boa".to_owned(),
+            max_size: 42.5,
+            enabled: Some(true),
+        }
+    );
+
+    let value = settings.try_into_js(&mut context).unwrap();
+    let object = value.as_object().unwrap();
+    assert_eq!(
+        object.get(js_string!("maxSize"), &mut context).unwrap(),
+        JsValue::from(42.5)
+    );
+    assert!(
+        !object
+            .has_property(js_string!("max_size"), &mut context)
+            .unwrap()
+    );
+    assert_eq!(
+        Settings::try_from_js(&value, &mut context).unwrap(),
+        settings
+    );
+}
+
+#[test]
+fn round_trip_optional_missing() {
+    let mut context = Context::default();
+    let value = context
+        .eval(Source::from_bytes(r"({ name: 'boa', maxSize: 1 })"))
+        .unwrap();
+
+    let settings = Settings::try_from_js(&value, &mut context).unwrap();
+    assert_eq!(settings.enabled, None);
+
+    let value = settings.try_into_js(&mut context).unwrap();
+    let object = value.as_object().unwrap();
+    assert!(
+        object
+            .get(js_string!("enabled"), &mut context)
+            .unwrap()
+            .is_undefined()
+    );
+    assert_eq!(
+        Settings::try_from_js(&value, &mut context).unwrap(),
+        settings
+    );
+}
+
+#[test]
+fn missing_property_error() {
+    let mut context = Context::default();
+    let value = context
+        .eval(Source::from_bytes(r"({ maxSize: 1 })"))
+        .unwrap();
+
+    let error = Settings::try_from_js(&value, &mut context).unwrap_err();
+    assert_eq!(
+        error.as_native().unwrap().message(),
+        "missing required property `name`"
+    );
+
+    let value = context
+        .eval(Source::from_bytes(r"({ name: 'boa' })"))
+        .unwrap();
+    let error = Settings::try_from_js(&value, &mut context).unwrap_err();
+    assert_eq!(
+        error.as_native().unwrap().message(),
+        "missing required property `maxSize`"
+    );
+}